@@ -1,14 +1,12 @@
 //! # DS3231 RTC Alarm Example for ESP32
 //!
 //! This example demonstrates how to use the DS3231 Real-Time Clock (RTC) with alarm functionality
-//! on an ESP32 microcontroller. The example showcases:
+//! on an ESP32 microcontroller, using the async driver. The example showcases:
 //!
 //! ## Features
 //! - Setting a known date and time on the DS3231
 //! - Configuring Alarm 1 to trigger 1 minute after the set time
-//! - Real-time monitoring of the current time every 100ms
-//! - Displaying time changes along with alarm status
-//! - Monitoring the SQW/INT pin level for interrupt detection
+//! - Awaiting the SQW/INT pin instead of polling the status register
 //! - Automatic clearing of alarm flags when triggered
 //!
 //! ## Hardware Connections
@@ -20,17 +18,15 @@
 //! 1. Initializes the DS3231 in interrupt mode (not square wave mode)
 //! 2. Sets the initial time to: 2024-12-20 14:30:00
 //! 3. Configures Alarm 1 to trigger at: 14:31:00 (1 minute later)
-//! 4. Continuously monitors and displays:
-//!    - Current date and time
-//!    - Alarm 1 and Alarm 2 status flags
-//!    - SQW/INT pin level (HIGH/LOW)
-//! 5. When the alarm triggers, it displays a notification and clears the flag
+//! 4. Awaits the alarm with `wait_for_alarm_timeout`, which sleeps until SQW/INT goes low.
+//!    Every 10 seconds without an alarm the current time is displayed.
+//! 5. When the alarm triggers, it displays a notification; the flag is already cleared
 //!
 //! ## Expected Output
 //! ```
-//! Time: 2024-12-20 14:30:45 | Alarm1: clear | Alarm2: clear | SQW/INT: HIGH
-//! Time: 2024-12-20 14:31:00 | Alarm1: TRIGGERED | Alarm2: clear | SQW/INT: LOW
-//! 🚨 ALARM 1 TRIGGERED! Clearing flag...
+//! Waiting... time: 2024-12-20 14:30:50
+//! 🚨 ALARM 1 TRIGGERED (flag cleared)
+//! Alarm time: 2024-12-20 14:31:00
 //! ```
 //!
 //! ## Target Hardware
@@ -46,28 +42,48 @@
 
 use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
 use ds3231::{
-    Alarm1Config, Config, InterruptControl, Oscillator, SquareWaveFrequency, TimeRepresentation,
-    DS3231,
+    Alarm1Config, AlarmId, Config, DS3231Async, InterruptControl, Oscillator, SquareWaveFrequency,
+    TimeRepresentation,
 };
+use embassy_executor::Spawner;
+use embassy_time::Delay;
 use esp_backtrace as _;
 use esp_hal::time::Rate;
 use esp_hal::{
     clock::CpuClock,
     gpio::Input,
     i2c::master::{Config as I2cConfig, I2c},
-    main,
-    time::{Duration, Instant},
+    timer::timg::TimerGroup,
 };
 use log::info;
 
-#[main]
-fn main() -> ! {
+/// How long to wait for an alarm before displaying the time, in milliseconds
+const WAIT_TIMEOUT_MS: u32 = 10_000;
+
+fn log_datetime(prefix: &str, datetime: &NaiveDateTime) {
+    info!(
+        "{}{}-{:02}-{:02} {:02}:{:02}:{:02}",
+        prefix,
+        datetime.year(),
+        datetime.month(),
+        datetime.day(),
+        datetime.hour(),
+        datetime.minute(),
+        datetime.second()
+    );
+}
+
+#[esp_hal_embassy::main]
+async fn main(_spawner: Spawner) {
     // Initialize logger
     esp_println::logger::init_logger_from_env();
 
     let config = esp_hal::Config::default().with_cpu_clock(CpuClock::max());
     let peripherals = esp_hal::init(config);
 
+    let timg0 = TimerGroup::new(peripherals.TIMG0);
+    esp_hal_embassy::init(timg0.timer0);
+
     info!("DS3231 Alarm Example Starting...");
 
     // Configure I2C pins
@@ -75,17 +91,18 @@ fn main() -> ! {
     let scl = peripherals.GPIO15; // SCL pin
 
     // Configure interrupt pin (SQW/INT)
-    let sqw_int_pin = Input::new(peripherals.GPIO22, Default::default());
+    let mut sqw_int_pin = Input::new(peripherals.GPIO22, Default::default());
 
     // Initialize I2C
     let i2c_config = I2cConfig::default().with_frequency(Rate::from_khz(100));
     let i2c = I2c::new(peripherals.I2C0, i2c_config)
         .unwrap()
         .with_sda(sda)
-        .with_scl(scl);
+        .with_scl(scl)
+        .into_async();
 
     // Initialize DS3231
-    let mut rtc = DS3231::new(i2c, 0x68);
+    let mut rtc = DS3231Async::new(i2c, 0x68);
 
     // Configure DS3231 for interrupt mode
     let rtc_config = Config {
@@ -96,7 +113,7 @@ fn main() -> ! {
         oscillator_enable: Oscillator::Enabled,
     };
 
-    match rtc.configure(&rtc_config) {
+    match rtc.configure(&rtc_config).await {
         Ok(_) => info!("DS3231 configured successfully"),
         Err(e) => {
             info!("Failed to configure DS3231: {:?}", e);
@@ -110,16 +127,8 @@ fn main() -> ! {
         .and_hms_opt(14, 30, 0)
         .unwrap();
 
-    match rtc.set_datetime(&initial_datetime) {
-        Ok(_) => info!(
-            "Initial time set to: {}-{:02}-{:02} {:02}:{:02}:{:02}",
-            initial_datetime.year(),
-            initial_datetime.month(),
-            initial_datetime.day(),
-            initial_datetime.hour(),
-            initial_datetime.minute(),
-            initial_datetime.second()
-        ),
+    match rtc.set_datetime(&initial_datetime).await {
+        Ok(_) => log_datetime("Initial time set to: ", &initial_datetime),
         Err(e) => {
             info!("Failed to set initial time: {:?}", e);
             panic!("Failed to set initial time");
@@ -134,111 +143,44 @@ fn main() -> ! {
         is_pm: None, // 24-hour mode
     };
 
-    match rtc.set_alarm1(&alarm_config) {
-        Ok(_) => info!("Alarm set for 14:31:00"),
+    // Program the alarm, clear a stale A1F and enable the Alarm 1 interrupt
+    match rtc.arm_alarm1(&alarm_config).await {
+        Ok(_) => info!("Alarm set for 14:31:00, interrupt enabled"),
         Err(e) => {
             info!("Failed to set alarm: {:?}", e);
             panic!("Failed to set alarm");
         }
     }
 
-    // Clear any existing alarm flags
-    match rtc.status() {
-        Ok(mut status) => {
-            status.set_alarm1_flag(false);
-            status.set_alarm2_flag(false);
-            match rtc.set_status(status) {
-                Ok(_) => info!("Alarm flags cleared"),
-                Err(e) => info!("Failed to clear alarm flags: {:?}", e),
-            }
-        }
-        Err(e) => info!("Failed to read status: {:?}", e),
+    // Alarm 2 is not used
+    if let Err(e) = rtc.enable_alarm_interrupt(AlarmId::Alarm2, false).await {
+        info!("Failed to disable the alarm 2 interrupt: {:?}", e);
     }
 
-    // Enable Alarm 1 interrupt
-    match rtc.control() {
-        Ok(mut control) => {
-            control.set_alarm1_interrupt_enable(true);
-            control.set_alarm2_interrupt_enable(false);
-            match rtc.set_control(control) {
-                Ok(_) => info!("Alarm 1 interrupt enabled"),
-                Err(e) => info!("Failed to enable alarm interrupt: {:?}", e),
-            }
-        }
-        Err(e) => info!("Failed to read control register: {:?}", e),
-    }
-
-    info!("Starting time monitoring...");
-    info!("Current time will be displayed every 100ms when it changes");
-    info!("Alarm status will be shown alongside the time");
-    info!("SQW/INT pin level will also be monitored");
-
-    let mut last_datetime: Option<NaiveDateTime> = None;
-    let mut last_alarm_status = false;
-    let mut last_pin_level = sqw_int_pin.is_high();
+    info!("Waiting for the alarm on SQW/INT...");
 
     loop {
-        let loop_start = Instant::now();
-
-        // Read current time
-        match rtc.datetime() {
-            Ok(current_time) => {
-                // Read alarm status
-                let (alarm1_flag, alarm2_flag) = match rtc.status() {
-                    Ok(status) => (status.alarm1_flag(), status.alarm2_flag()),
-                    Err(_) => (false, false),
-                };
-
-                // Check pin level
-                let current_pin_level = sqw_int_pin.is_high();
-
-                // Display time if it changed or if alarm status changed or pin level changed
-                let time_changed = last_datetime != Some(current_time);
-                let alarm_status_changed = last_alarm_status != alarm1_flag;
-                let pin_level_changed = last_pin_level != current_pin_level;
-
-                if time_changed || alarm_status_changed || pin_level_changed {
-                    info!(
-                        "Time: {}-{:02}-{:02} {:02}:{:02}:{:02} | Alarm1: {} | Alarm2: {} | SQW/INT: {}",
-                        current_time.year(),
-                        current_time.month(),
-                        current_time.day(),
-                        current_time.hour(),
-                        current_time.minute(),
-                        current_time.second(),
-                        if alarm1_flag { "TRIGGERED" } else { "clear" },
-                        if alarm2_flag { "TRIGGERED" } else { "clear" },
-                        if current_pin_level { "HIGH" } else { "LOW" }
-                    );
-
-                    // If alarm triggered, clear the flag
-                    if alarm1_flag && !last_alarm_status {
-                        info!("🚨 ALARM 1 TRIGGERED! Clearing flag...");
-                        match rtc.status() {
-                            Ok(mut status) => {
-                                status.set_alarm1_flag(false);
-                                match rtc.set_status(status) {
-                                    Ok(_) => info!("Alarm 1 flag cleared"),
-                                    Err(e) => info!("Failed to clear alarm 1 flag: {:?}", e),
-                                }
-                            }
-                            Err(e) => info!("Failed to read status for clearing: {:?}", e),
-                        }
-                    }
-
-                    last_datetime = Some(current_time);
-                    last_alarm_status = alarm1_flag;
-                    last_pin_level = current_pin_level;
+        match rtc
+            .wait_for_alarm_timeout(&mut sqw_int_pin, &mut Delay, WAIT_TIMEOUT_MS)
+            .await
+        {
+            Ok(Some(event)) => {
+                let now = rtc.datetime().await.ok();
+                if event.includes(AlarmId::Alarm1) {
+                    info!("🚨 ALARM 1 TRIGGERED (flag cleared)");
+                }
+                if event.includes(AlarmId::Alarm2) {
+                    info!("ALARM 2 TRIGGERED (flag cleared)");
+                }
+                if let Some(now) = now {
+                    log_datetime("Alarm time: ", &now);
                 }
             }
-            Err(e) => {
-                info!("Failed to read time: {:?}", e);
-            }
-        }
-
-        // Wait for the remainder of 100ms
-        while loop_start.elapsed() < Duration::from_millis(100) {
-            // Busy wait
+            Ok(None) => match rtc.datetime().await {
+                Ok(now) => log_datetime("Waiting... time: ", &now),
+                Err(e) => info!("Failed to read time: {:?}", e),
+            },
+            Err(e) => info!("Failed to wait for alarm: {:?}", e),
         }
     }
 }
@@ -11,6 +11,7 @@
 //! - Clear separation between time specification and recurrence patterns
 //! - Support for both 12-hour and 24-hour time formats
 //! - Day-of-week and date-of-month matching
+//! - `const` builders ([`Alarm1`], [`Alarm2`]) that reject invalid alarms at compile time
 //!
 //! # Alarm Types
 //!
@@ -21,6 +22,7 @@
 //! - `AtTime` - Triggers when hours:minutes:seconds match (daily)
 //! - `AtTimeOnDate` - Triggers at specific time on specific date of month
 //! - `AtTimeOnDay` - Triggers at specific time on specific day of week
+//! - `Raw` - Register contents with any other combination of mask bits
 //!
 //! ## Alarm 2 Configurations
 //! - `EveryMinute` - Triggers every minute (at 00 seconds)
@@ -28,10 +30,19 @@
 //! - `AtTime` - Triggers when hours:minutes match (at 00 seconds, daily)
 //! - `AtTimeOnDate` - Triggers at specific time on specific date of month (at 00 seconds)
 //! - `AtTimeOnDay` - Triggers at specific time on specific day of week (at 00 seconds)
+//! - `Raw` - Register contents with any other combination of mask bits
 
+use core::fmt;
+
+#[cfg(feature = "chrono")]
+use chrono::{NaiveTime, Timelike, Weekday};
+
+#[cfg(feature = "chrono")]
+use crate::trigger::field;
 use crate::{
-    datetime::{DS3231DateTime, DS3231DateTimeError},
-    AlarmDayDate, AlarmHours, AlarmMinutes, AlarmSeconds, DayDateSelect, TimeRepresentation,
+    datetime::{DS3231DateTime, DS3231DateTimeError, DayOfWeek, WeekdayNumbering},
+    AlarmDayDate, AlarmHours, AlarmMinutes, AlarmSeconds, DayDateSelect, Status,
+    TimeRepresentation,
 };
 
 /// Error type for alarm configuration operations.
@@ -85,7 +96,8 @@ pub enum Alarm1Config {
         minutes: u8,
         /// Seconds value (0-59)
         seconds: u8,
-        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour)
+        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour); the
+        /// driver converts the hours to the clock's format
         is_pm: Option<bool>,
     },
 
@@ -99,7 +111,8 @@ pub enum Alarm1Config {
         seconds: u8,
         /// Date of month (1-31)
         date: u8,
-        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour)
+        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour); the
+        /// driver converts the hours to the clock's format
         is_pm: Option<bool>,
     },
 
@@ -111,11 +124,18 @@ pub enum Alarm1Config {
         minutes: u8,
         /// Seconds value (0-59)
         seconds: u8,
-        /// Day of week (1-7, where 1=Sunday)
+        /// Day of week (1-7, where 1=Sunday), whatever the driver's `WeekdayNumbering`
         day: u8,
-        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour)
+        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour); the
+        /// driver converts the hours to the clock's format
         is_pm: Option<bool>,
     },
+
+    /// Register contents with a combination of mask bits other than the ones above, written
+    /// back unchanged. [`DS3231Alarm1::alarm_match`] describes what the device compares.
+    /// With DY/DT set, the driver reads and writes the Day value as 1 = Sunday, like `day`
+    /// above, whatever its `WeekdayNumbering`.
+    Raw(DS3231Alarm1),
 }
 
 /// Alarm 2 specific configurations.
@@ -140,7 +160,8 @@ pub enum Alarm2Config {
         hours: u8,
         /// Minutes value (0-59)
         minutes: u8,
-        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour)
+        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour); the
+        /// driver converts the hours to the clock's format
         is_pm: Option<bool>,
     },
 
@@ -152,7 +173,8 @@ pub enum Alarm2Config {
         minutes: u8,
         /// Date of month (1-31)
         date: u8,
-        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour)
+        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour); the
+        /// driver converts the hours to the clock's format
         is_pm: Option<bool>,
     },
 
@@ -162,14 +184,261 @@ pub enum Alarm2Config {
         hours: u8,
         /// Minutes value (0-59)
         minutes: u8,
-        /// Day of week (1-7, where 1=Sunday)
+        /// Day of week (1-7, where 1=Sunday), whatever the driver's `WeekdayNumbering`
         day: u8,
-        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour)
+        /// PM flag for 12-hour mode (None for 24-hour, Some(true/false) for 12-hour); the
+        /// driver converts the hours to the clock's format
         is_pm: Option<bool>,
     },
+
+    /// Register contents with a combination of mask bits other than the ones above, written
+    /// back unchanged. [`DS3231Alarm2::alarm_match`] describes what the device compares.
+    /// With DY/DT set, the driver reads and writes the Day value as 1 = Sunday, like `day`
+    /// above, whatever its `WeekdayNumbering`.
+    Raw(DS3231Alarm2),
+}
+
+/// How an alarm update treats the alarm's interrupt.
+///
+/// The alarm registers are always written in a single burst, but the device checks for a
+/// match once per second, so a burst that straddles a second boundary can still match a
+/// partially written alarm.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlarmUpdate {
+    /// Write the alarm registers without touching the control or status registers
+    #[default]
+    Direct,
+    /// Disable the alarm interrupt while the registers are written and restore it afterwards.
+    /// An alarm flag raised during the update is cleared before the interrupt is restored, so
+    /// a partially programmed alarm cannot assert INT. A flag that was already set before the
+    /// update is left untouched.
+    MaskInterrupt,
+}
+
+/// Selects one of the two hardware alarms.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlarmId {
+    /// Alarm 1 (registers 0x07-0x0A, seconds precision)
+    Alarm1,
+    /// Alarm 2 (registers 0x0B-0x0D, minute precision)
+    Alarm2,
+}
+
+/// The alarms that fired, as reported by `wait_for_alarm`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AlarmEvent {
+    /// Only Alarm 1 fired (A1F)
+    Alarm1,
+    /// Only Alarm 2 fired (A2F)
+    Alarm2,
+    /// Both alarms fired
+    Both,
+}
+
+impl AlarmEvent {
+    /// Gets the event for the alarm flags of a status register value.
+    ///
+    /// Returns `None` if neither alarm flag is set.
+    #[must_use]
+    pub fn from_status(status: Status) -> Option<Self> {
+        FiredAlarms::from(status).event()
+    }
+
+    /// Returns `true` if `alarm` fired.
+    #[must_use]
+    pub fn includes(self, alarm: AlarmId) -> bool {
+        matches!(
+            (self, alarm),
+            (AlarmEvent::Both, _)
+                | (AlarmEvent::Alarm1, AlarmId::Alarm1)
+                | (AlarmEvent::Alarm2, AlarmId::Alarm2)
+        )
+    }
+}
+
+/// The alarm flags (A1F, A2F) of the status register, as read by `alarms_fired`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FiredAlarms {
+    /// Alarm 1 fired (A1F is set)
+    pub alarm1: bool,
+    /// Alarm 2 fired (A2F is set)
+    pub alarm2: bool,
+}
+
+impl FiredAlarms {
+    /// Returns `true` if `alarm` fired.
+    #[must_use]
+    pub fn fired(self, alarm: AlarmId) -> bool {
+        match alarm {
+            AlarmId::Alarm1 => self.alarm1,
+            AlarmId::Alarm2 => self.alarm2,
+        }
+    }
+
+    /// Returns `true` if either alarm fired.
+    #[must_use]
+    pub fn any(self) -> bool {
+        self.alarm1 || self.alarm2
+    }
+
+    /// Gets the alarms that fired as an `AlarmEvent`, or `None` if neither did.
+    #[must_use]
+    pub fn event(self) -> Option<AlarmEvent> {
+        match (self.alarm1, self.alarm2) {
+            (true, true) => Some(AlarmEvent::Both),
+            (true, false) => Some(AlarmEvent::Alarm1),
+            (false, true) => Some(AlarmEvent::Alarm2),
+            (false, false) => None,
+        }
+    }
+}
+
+impl From<Status> for FiredAlarms {
+    fn from(status: Status) -> Self {
+        Self {
+            alarm1: status.alarm1_flag(),
+            alarm2: status.alarm2_flag(),
+        }
+    }
 }
 
 impl Alarm1Config {
+    /// Creates an alarm that triggers every day at `time`.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn daily(time: NaiveTime) -> Self {
+        Alarm1Config::AtTime {
+            hours: field(time.hour()),
+            minutes: field(time.minute()),
+            seconds: field(time.second()),
+            is_pm: None,
+        }
+    }
+
+    /// Creates an alarm at `time` on a date of the month (1-31).
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn on_date(date: u8, time: NaiveTime) -> Self {
+        Alarm1Config::AtTimeOnDate {
+            hours: field(time.hour()),
+            minutes: field(time.minute()),
+            seconds: field(time.second()),
+            date,
+            is_pm: None,
+        }
+    }
+
+    /// Creates an alarm at `time` on a day of the week.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn on_weekday(weekday: Weekday, time: NaiveTime) -> Self {
+        Alarm1Config::AtTimeOnDay {
+            hours: field(time.hour()),
+            minutes: field(time.minute()),
+            seconds: field(time.second()),
+            day: WeekdayNumbering::SundayIsOne.number(weekday),
+            is_pm: None,
+        }
+    }
+
+    /// Gets the time of day of an `AtTime`, `AtTimeOnDate` or `AtTimeOnDay` alarm, whether its
+    /// hours are in 12- or 24-hour form.
+    ///
+    /// Returns `None` for the other configurations and for an invalid time.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn time(&self) -> Option<NaiveTime> {
+        match *self {
+            Alarm1Config::AtTime {
+                hours,
+                minutes,
+                seconds,
+                is_pm,
+            }
+            | Alarm1Config::AtTimeOnDate {
+                hours,
+                minutes,
+                seconds,
+                is_pm,
+                ..
+            }
+            | Alarm1Config::AtTimeOnDay {
+                hours,
+                minutes,
+                seconds,
+                is_pm,
+                ..
+            } => NaiveTime::from_hms_opt(
+                u32::from(hour_24(hours, is_pm)?),
+                u32::from(minutes),
+                u32::from(seconds),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Gets the day of the week of an `AtTimeOnDay` alarm.
+    ///
+    /// Returns `None` for the other configurations and for a day outside 1-7.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn weekday(&self) -> Option<Weekday> {
+        match self {
+            Alarm1Config::AtTimeOnDay { day, .. } => WeekdayNumbering::SundayIsOne.weekday(*day),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the alarm matches the hours, so that its encoding depends on the
+    /// 12/24 hour format.
+    pub(crate) fn uses_hours(&self) -> bool {
+        matches!(
+            self,
+            Alarm1Config::AtTime { .. }
+                | Alarm1Config::AtTimeOnDate { .. }
+                | Alarm1Config::AtTimeOnDay { .. }
+        )
+    }
+
+    /// Converts the hours to `time_representation`: 24-hour form with `is_pm: None`, 12-hour
+    /// form with `Some`.
+    pub(crate) fn in_time_representation(&self, time_representation: TimeRepresentation) -> Self {
+        let mut config = self.clone();
+        if let Alarm1Config::AtTime { hours, is_pm, .. }
+        | Alarm1Config::AtTimeOnDate { hours, is_pm, .. }
+        | Alarm1Config::AtTimeOnDay { hours, is_pm, .. } = &mut config
+        {
+            (*hours, *is_pm) = convert_hours(*hours, *is_pm, time_representation);
+        }
+        config
+    }
+
+    /// Applies `f` to the day of the week of an `AtTimeOnDay` alarm or of a raw alarm that
+    /// selects the day of the week.
+    fn map_day(&self, f: impl FnOnce(u8) -> u8) -> Self {
+        match self {
+            Alarm1Config::AtTimeOnDay {
+                hours,
+                minutes,
+                seconds,
+                day,
+                is_pm,
+            } => Alarm1Config::AtTimeOnDay {
+                hours: *hours,
+                minutes: *minutes,
+                seconds: *seconds,
+                day: f(*day),
+                is_pm: *is_pm,
+            },
+            Alarm1Config::Raw(alarm) => Alarm1Config::Raw(alarm.map_day(f)),
+            other => other.clone(),
+        }
+    }
+
     /// Validates the alarm configuration and returns any errors.
     ///
     /// # Errors
@@ -177,7 +446,7 @@ impl Alarm1Config {
     /// Returns an error if any time component is out of valid range.
     pub fn validate(&self) -> Result<(), AlarmError> {
         match self {
-            Alarm1Config::EverySecond => Ok(()),
+            Alarm1Config::EverySecond | Alarm1Config::Raw(_) => Ok(()),
 
             Alarm1Config::AtSeconds { seconds } => {
                 if *seconds > 59 {
@@ -275,6 +544,132 @@ impl Alarm1Config {
 }
 
 impl Alarm2Config {
+    /// Creates an alarm that triggers every day at `time`.
+    ///
+    /// The seconds of `time` are dropped; Alarm 2 triggers at 00 seconds.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn daily(time: NaiveTime) -> Self {
+        Alarm2Config::AtTime {
+            hours: field(time.hour()),
+            minutes: field(time.minute()),
+            is_pm: None,
+        }
+    }
+
+    /// Creates an alarm at `time` on a date of the month (1-31).
+    ///
+    /// The seconds of `time` are dropped; Alarm 2 triggers at 00 seconds.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn on_date(date: u8, time: NaiveTime) -> Self {
+        Alarm2Config::AtTimeOnDate {
+            hours: field(time.hour()),
+            minutes: field(time.minute()),
+            date,
+            is_pm: None,
+        }
+    }
+
+    /// Creates an alarm at `time` on a day of the week.
+    ///
+    /// The seconds of `time` are dropped; Alarm 2 triggers at 00 seconds.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn on_weekday(weekday: Weekday, time: NaiveTime) -> Self {
+        Alarm2Config::AtTimeOnDay {
+            hours: field(time.hour()),
+            minutes: field(time.minute()),
+            day: WeekdayNumbering::SundayIsOne.number(weekday),
+            is_pm: None,
+        }
+    }
+
+    /// Gets the time of day of an `AtTime`, `AtTimeOnDate` or `AtTimeOnDay` alarm, whether its
+    /// hours are in 12- or 24-hour form.
+    ///
+    /// Returns `None` for the other configurations and for an invalid time.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn time(&self) -> Option<NaiveTime> {
+        match *self {
+            Alarm2Config::AtTime {
+                hours,
+                minutes,
+                is_pm,
+            }
+            | Alarm2Config::AtTimeOnDate {
+                hours,
+                minutes,
+                is_pm,
+                ..
+            }
+            | Alarm2Config::AtTimeOnDay {
+                hours,
+                minutes,
+                is_pm,
+                ..
+            } => NaiveTime::from_hms_opt(u32::from(hour_24(hours, is_pm)?), u32::from(minutes), 0),
+            _ => None,
+        }
+    }
+
+    /// Gets the day of the week of an `AtTimeOnDay` alarm.
+    ///
+    /// Returns `None` for the other configurations and for a day outside 1-7.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn weekday(&self) -> Option<Weekday> {
+        match self {
+            Alarm2Config::AtTimeOnDay { day, .. } => WeekdayNumbering::SundayIsOne.weekday(*day),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the alarm matches the hours, so that its encoding depends on the
+    /// 12/24 hour format.
+    pub(crate) fn uses_hours(&self) -> bool {
+        matches!(
+            self,
+            Alarm2Config::AtTime { .. }
+                | Alarm2Config::AtTimeOnDate { .. }
+                | Alarm2Config::AtTimeOnDay { .. }
+        )
+    }
+
+    /// Converts the hours to `time_representation`: 24-hour form with `is_pm: None`, 12-hour
+    /// form with `Some`.
+    pub(crate) fn in_time_representation(&self, time_representation: TimeRepresentation) -> Self {
+        let mut config = self.clone();
+        if let Alarm2Config::AtTime { hours, is_pm, .. }
+        | Alarm2Config::AtTimeOnDate { hours, is_pm, .. }
+        | Alarm2Config::AtTimeOnDay { hours, is_pm, .. } = &mut config
+        {
+            (*hours, *is_pm) = convert_hours(*hours, *is_pm, time_representation);
+        }
+        config
+    }
+
+    /// Applies `f` to the day of the week of an `AtTimeOnDay` alarm or of a raw alarm that
+    /// selects the day of the week.
+    fn map_day(&self, f: impl FnOnce(u8) -> u8) -> Self {
+        match self {
+            Alarm2Config::AtTimeOnDay {
+                hours,
+                minutes,
+                day,
+                is_pm,
+            } => Alarm2Config::AtTimeOnDay {
+                hours: *hours,
+                minutes: *minutes,
+                day: f(*day),
+                is_pm: *is_pm,
+            },
+            Alarm2Config::Raw(alarm) => Alarm2Config::Raw(alarm.map_day(f)),
+            other => other.clone(),
+        }
+    }
+
     /// Validates the alarm configuration and returns any errors.
     ///
     /// # Errors
@@ -282,7 +677,7 @@ impl Alarm2Config {
     /// Returns an error if any time component is out of valid range.
     pub fn validate(&self) -> Result<(), AlarmError> {
         match self {
-            Alarm2Config::EveryMinute => Ok(()),
+            Alarm2Config::EveryMinute | Alarm2Config::Raw(_) => Ok(()),
 
             Alarm2Config::AtMinutes { minutes } => {
                 if *minutes > 59 {
@@ -358,6 +753,412 @@ impl Alarm2Config {
     }
 }
 
+/// How often an alarm built with [`Alarm1`] or [`Alarm2`] repeats.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+enum Recurrence {
+    /// Every day
+    Daily,
+    /// On a date of the month (1-31)
+    Monthly(u8),
+    /// On a day of the week (1-7, where 1=Sunday)
+    Weekly(u8),
+}
+
+impl Recurrence {
+    const fn monthly(date: u8) -> Self {
+        assert!(date >= 1 && date <= 31, "date must be 1-31");
+        Recurrence::Monthly(date)
+    }
+
+    const fn weekly(day: DayOfWeek) -> Self {
+        Recurrence::Weekly(day.number(WeekdayNumbering::SundayIsOne))
+    }
+}
+
+const fn check_minutes_seconds(minutes: u8, seconds: u8) {
+    assert!(minutes <= 59, "minutes must be 0-59");
+    assert!(seconds <= 59, "seconds must be 0-59");
+}
+
+const fn check_hours(hours: u8) {
+    assert!(hours <= 23, "hours must be 0-23");
+}
+
+/// Builder for [`Alarm1Config`].
+///
+/// Pick how often the alarm repeats with [`daily`](Self::daily), [`monthly`](Self::monthly)
+/// or `weekly` (with chrono), then give the time of day with [`at_hms`](Self::at_hms) or
+/// `at`. Alarms that do not need the hours come straight from [`every_second`],
+/// [`every_minute_at`] and [`hourly_at`].
+///
+/// The builder works in 24-hour form; the driver converts the hours to the clock's format.
+/// Everything except `at` is a `const fn` that panics on an out of range value, so an alarm
+/// held in a `const` is checked when it is compiled:
+///
+/// ```rust
+/// use ds3231::{Alarm1, Alarm1Config, DayOfWeek};
+///
+/// const WAKE_UP: Alarm1Config = Alarm1::daily().at_hms(6, 30, 0);
+/// const REPORT: Alarm1Config = Alarm1::monthly(1).at_hms(9, 0, 0);
+/// const STANDUP: Alarm1Config = Alarm1::weekly(DayOfWeek::Monday).at_hms(9, 15, 0);
+/// const CHIME: Alarm1Config = Alarm1::hourly_at(0, 0);
+/// ```
+///
+/// ```compile_fail
+/// use ds3231::{Alarm1, Alarm1Config};
+///
+/// const WAKE_UP: Alarm1Config = Alarm1::daily().at_hms(24, 30, 0);
+/// ```
+///
+/// [`every_second`]: Self::every_second
+/// [`every_minute_at`]: Self::every_minute_at
+/// [`hourly_at`]: Self::hourly_at
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Alarm1 {
+    recurrence: Recurrence,
+}
+
+impl Alarm1 {
+    /// An alarm that triggers every second.
+    #[must_use]
+    pub const fn every_second() -> Alarm1Config {
+        Alarm1Config::EverySecond
+    }
+
+    /// An alarm that triggers once a minute, when the seconds match.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seconds` is not 0-59.
+    #[must_use]
+    pub const fn every_minute_at(seconds: u8) -> Alarm1Config {
+        check_minutes_seconds(0, seconds);
+        Alarm1Config::AtSeconds { seconds }
+    }
+
+    /// An alarm that triggers once an hour, when the minutes and seconds match.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `minutes` or `seconds` is not 0-59.
+    #[must_use]
+    pub const fn hourly_at(minutes: u8, seconds: u8) -> Alarm1Config {
+        check_minutes_seconds(minutes, seconds);
+        Alarm1Config::AtMinutesSeconds { minutes, seconds }
+    }
+
+    /// An alarm that triggers every day.
+    #[must_use]
+    pub const fn daily() -> Self {
+        Self {
+            recurrence: Recurrence::Daily,
+        }
+    }
+
+    /// An alarm that triggers on a date of the month.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `date` is not 1-31.
+    #[must_use]
+    pub const fn monthly(date: u8) -> Self {
+        Self {
+            recurrence: Recurrence::monthly(date),
+        }
+    }
+
+    /// An alarm that triggers on a day of the week. With chrono, a `Weekday` converts with
+    /// `into()`.
+    #[must_use]
+    pub const fn weekly(day: DayOfWeek) -> Self {
+        Self {
+            recurrence: Recurrence::weekly(day),
+        }
+    }
+
+    /// Sets the time of day, with `hours` 0-23.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hours` is not 0-23, or `minutes` or `seconds` is not 0-59.
+    #[must_use]
+    pub const fn at_hms(self, hours: u8, minutes: u8, seconds: u8) -> Alarm1Config {
+        check_hours(hours);
+        check_minutes_seconds(minutes, seconds);
+        match self.recurrence {
+            Recurrence::Daily => Alarm1Config::AtTime {
+                hours,
+                minutes,
+                seconds,
+                is_pm: None,
+            },
+            Recurrence::Monthly(date) => Alarm1Config::AtTimeOnDate {
+                hours,
+                minutes,
+                seconds,
+                date,
+                is_pm: None,
+            },
+            Recurrence::Weekly(day) => Alarm1Config::AtTimeOnDay {
+                hours,
+                minutes,
+                seconds,
+                day,
+                is_pm: None,
+            },
+        }
+    }
+
+    /// Sets the time of day.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn at(self, time: NaiveTime) -> Alarm1Config {
+        self.at_hms(
+            field(time.hour()),
+            field(time.minute()),
+            field(time.second()),
+        )
+    }
+}
+
+/// Builder for [`Alarm2Config`].
+///
+/// Works like [`Alarm1`] without the seconds: Alarm 2 always triggers at 00 seconds.
+///
+/// ```rust
+/// use ds3231::{Alarm2, Alarm2Config};
+///
+/// const LIGHTS_OFF: Alarm2Config = Alarm2::daily().at_hm(22, 30);
+/// const RENT: Alarm2Config = Alarm2::monthly(28).at_hm(12, 0);
+/// const LOG: Alarm2Config = Alarm2::hourly_at(15);
+/// ```
+///
+/// ```compile_fail
+/// use ds3231::{Alarm2, Alarm2Config};
+///
+/// const RENT: Alarm2Config = Alarm2::monthly(32).at_hm(12, 0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Alarm2 {
+    recurrence: Recurrence,
+}
+
+impl Alarm2 {
+    /// An alarm that triggers every minute.
+    #[must_use]
+    pub const fn every_minute() -> Alarm2Config {
+        Alarm2Config::EveryMinute
+    }
+
+    /// An alarm that triggers once an hour, when the minutes match.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `minutes` is not 0-59.
+    #[must_use]
+    pub const fn hourly_at(minutes: u8) -> Alarm2Config {
+        check_minutes_seconds(minutes, 0);
+        Alarm2Config::AtMinutes { minutes }
+    }
+
+    /// An alarm that triggers every day.
+    #[must_use]
+    pub const fn daily() -> Self {
+        Self {
+            recurrence: Recurrence::Daily,
+        }
+    }
+
+    /// An alarm that triggers on a date of the month.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `date` is not 1-31.
+    #[must_use]
+    pub const fn monthly(date: u8) -> Self {
+        Self {
+            recurrence: Recurrence::monthly(date),
+        }
+    }
+
+    /// An alarm that triggers on a day of the week. With chrono, a `Weekday` converts with
+    /// `into()`.
+    #[must_use]
+    pub const fn weekly(day: DayOfWeek) -> Self {
+        Self {
+            recurrence: Recurrence::weekly(day),
+        }
+    }
+
+    /// Sets the time of day, with `hours` 0-23.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hours` is not 0-23 or `minutes` is not 0-59.
+    #[must_use]
+    pub const fn at_hm(self, hours: u8, minutes: u8) -> Alarm2Config {
+        check_hours(hours);
+        check_minutes_seconds(minutes, 0);
+        match self.recurrence {
+            Recurrence::Daily => Alarm2Config::AtTime {
+                hours,
+                minutes,
+                is_pm: None,
+            },
+            Recurrence::Monthly(date) => Alarm2Config::AtTimeOnDate {
+                hours,
+                minutes,
+                date,
+                is_pm: None,
+            },
+            Recurrence::Weekly(day) => Alarm2Config::AtTimeOnDay {
+                hours,
+                minutes,
+                day,
+                is_pm: None,
+            },
+        }
+    }
+
+    /// Sets the time of day. The seconds of `time` are dropped.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn at(self, time: NaiveTime) -> Alarm2Config {
+        self.at_hm(field(time.hour()), field(time.minute()))
+    }
+}
+
+/// Converts alarm hours to 0-23, or `None` if they are out of range.
+fn hour_24(hours: u8, is_pm: Option<bool>) -> Option<u8> {
+    match is_pm {
+        None => (hours <= 23).then_some(hours),
+        Some(pm) => (1..=12)
+            .contains(&hours)
+            .then_some(hours % 12 + if pm { 12 } else { 0 }),
+    }
+}
+
+/// Converts alarm hours to `time_representation`. Out of range hours are left as they are
+/// for validation to reject.
+fn convert_hours(
+    hours: u8,
+    is_pm: Option<bool>,
+    time_representation: TimeRepresentation,
+) -> (u8, Option<bool>) {
+    let Some(hour) = hour_24(hours, is_pm) else {
+        return (hours, is_pm);
+    };
+    match time_representation {
+        TimeRepresentation::TwentyFourHour => (hour, None),
+        TimeRepresentation::TwelveHour => match hour % 12 {
+            0 => (12, Some(hour >= 12)),
+            h => (h, Some(hour >= 12)),
+        },
+    }
+}
+
+/// What the device compares one alarm register with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FieldMatch {
+    /// The register is masked and matches any value
+    Any,
+    /// The register matches this value
+    Value(u8),
+    /// The register holds a value the clock never reaches
+    Never,
+}
+
+impl FieldMatch {
+    /// Decodes an alarm register holding a BCD value in `min..=max` below the mask bit.
+    fn bcd(register: u8, min: u8, max: u8) -> Self {
+        if register & 0x80 != 0 {
+            return FieldMatch::Any;
+        }
+        let ones = register & 0x0F;
+        let value = (register >> 4 & 0x07) * 10 + ones;
+        if ones > 9 || value < min || value > max {
+            FieldMatch::Never
+        } else {
+            FieldMatch::Value(value)
+        }
+    }
+}
+
+impl fmt::Display for FieldMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldMatch::Any => f.write_str("any"),
+            FieldMatch::Value(value) => write!(f, "{value}"),
+            FieldMatch::Never => f.write_str("never"),
+        }
+    }
+}
+
+/// What an alarm matches, decoded from its registers whatever the mask bits.
+///
+/// Created by [`DS3231Alarm1::alarm_match`] and [`DS3231Alarm2::alarm_match`]. The device
+/// sets the alarm flag when every field matches, so an alarm with a `Never` field does not
+/// fire. `Display` gives a one line summary such as
+/// `seconds 30, minutes any, hours never, day any, date 15`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AlarmMatch {
+    /// Seconds (0-59); always 0 for Alarm 2
+    pub seconds: FieldMatch,
+    /// Minutes (0-59)
+    pub minutes: FieldMatch,
+    /// Hours in 24-hour form (0-23)
+    pub hours: FieldMatch,
+    /// Day register value (1-7); 1 = Sunday for a raw alarm read through the driver
+    pub day: FieldMatch,
+    /// Date of the month (1-31)
+    pub date: FieldMatch,
+}
+
+impl AlarmMatch {
+    fn decode(seconds: FieldMatch, minutes: u8, hours: u8, day_date: u8) -> Self {
+        let hours = if hours & 0x80 != 0 {
+            FieldMatch::Any
+        } else {
+            DS3231DateTime::decode_hour(hours)
+                .and_then(|hour| u8::try_from(hour).ok())
+                .map_or(FieldMatch::Never, FieldMatch::Value)
+        };
+        let (day, date) = if AlarmDayDate(day_date).day_date_select() == DayDateSelect::Day {
+            (FieldMatch::bcd(day_date & 0x8F, 1, 7), FieldMatch::Any)
+        } else {
+            (FieldMatch::Any, FieldMatch::bcd(day_date & 0xBF, 1, 31))
+        };
+        Self {
+            seconds,
+            minutes: FieldMatch::bcd(minutes, 0, 59),
+            hours,
+            day,
+            date,
+        }
+    }
+
+    /// Returns `true` unless a field holds a value the clock never reaches.
+    #[must_use]
+    pub fn can_fire(&self) -> bool {
+        ![self.seconds, self.minutes, self.hours, self.day, self.date].contains(&FieldMatch::Never)
+    }
+}
+
+impl fmt::Display for AlarmMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "seconds {}, minutes {}, hours {}, day {}, date {}",
+            self.seconds, self.minutes, self.hours, self.day, self.date
+        )
+    }
+}
+
 /// Internal representation of DS3231 Alarm 1 registers.
 ///
 /// This struct models the 4 alarm 1 registers of the DS3231, using strongly-typed bitfield wrappers for each field.
@@ -448,6 +1249,8 @@ impl DS3231Alarm1 {
                 Self::configure_every_second(&mut alarm);
             }
 
+            Alarm1Config::Raw(raw) => return Ok(*raw),
+
             Alarm1Config::AtSeconds { seconds: sec } => {
                 Self::configure_at_seconds(&mut alarm, *sec)?;
             }
@@ -496,13 +1299,17 @@ impl DS3231Alarm1 {
     ///
     /// # Returns
     ///
-    /// The `Alarm1Config` that corresponds to the current register values.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the register values don't form a valid configuration
-    /// or contain invalid BCD values.
-    pub fn to_config(&self) -> Result<Alarm1Config, AlarmError> {
+    /// The `Alarm1Config` that corresponds to the current register values, or
+    /// `Alarm1Config::Raw` if the mask bits are not one of the documented combinations
+    /// or a matched register holds a value the clock never reaches (invalid BCD, an hour
+    /// out of range, a date outside 1-31 or a day outside 1-7).
+    #[must_use]
+    pub fn to_config(&self) -> Alarm1Config {
+        self.decode_documented().unwrap_or(Alarm1Config::Raw(*self))
+    }
+
+    /// Decodes a documented combination of mask bits, failing on an unreachable value.
+    fn decode_documented(self) -> Result<Alarm1Config, AlarmError> {
         // Check mask bit pattern to determine alarm type
         let mask1 = self.seconds.alarm_mask1();
         let mask2 = self.minutes.alarm_mask2();
@@ -547,7 +1354,7 @@ impl DS3231Alarm1 {
 
                 if self.day_date.day_date_select() == DayDateSelect::Day {
                     // Day of week alarm
-                    let day = self.day_date.day_or_date();
+                    let day = self.decode_day()?;
                     Ok(Alarm1Config::AtTimeOnDay {
                         hours,
                         minutes,
@@ -568,10 +1375,8 @@ impl DS3231Alarm1 {
                 }
             }
 
-            // Invalid mask combination
-            _ => Err(AlarmError::InvalidTime(
-                "Invalid alarm mask bit combination",
-            )),
+            // Any other mask combination is kept as it is
+            _ => Ok(Alarm1Config::Raw(self)),
         }
     }
 
@@ -621,6 +1426,14 @@ impl DS3231Alarm1 {
         }
     }
 
+    fn decode_day(self) -> Result<u8, AlarmError> {
+        let day = self.day_date.day_or_date();
+        if !(1..=7).contains(&day) {
+            return Err(AlarmError::InvalidDayOfWeek);
+        }
+        Ok(day)
+    }
+
     fn decode_bcd_day_date(self) -> Result<u8, AlarmError> {
         let ones = self.day_date.day_or_date();
         let tens = self.day_date.ten_date();
@@ -803,6 +1616,54 @@ impl DS3231Alarm1 {
             day_date,
         }
     }
+
+    /// Encodes a configuration with its hours in `time_representation` and its day of the
+    /// week in `numbering`.
+    pub(crate) fn encode(
+        config: &Alarm1Config,
+        numbering: WeekdayNumbering,
+        time_representation: TimeRepresentation,
+    ) -> Result<Self, AlarmError> {
+        Self::from_config(
+            &config
+                .in_time_representation(time_representation)
+                .map_day(|day| numbering.encode_day(day)),
+        )
+    }
+
+    /// Applies `f` to the Day value if DY/DT selects the day of the week.
+    fn map_day(mut self, f: impl FnOnce(u8) -> u8) -> Self {
+        if self.day_date.day_date_select() == DayDateSelect::Day {
+            self.day_date
+                .set_day_or_date(f(self.day_date.day_or_date()));
+        }
+        self
+    }
+
+    /// Decodes the registers to a configuration with 24-hour hours, reading the day of the
+    /// week in `numbering`.
+    pub(crate) fn decode(self, numbering: WeekdayNumbering) -> Alarm1Config {
+        self.to_config()
+            .in_time_representation(TimeRepresentation::TwentyFourHour)
+            .map_day(|value| numbering.decode_day(value))
+    }
+
+    /// Decodes what the device compares, whatever the combination of mask bits.
+    #[must_use]
+    pub fn alarm_match(&self) -> AlarmMatch {
+        let [seconds, minutes, hours, day_date] = self.to_registers();
+        AlarmMatch::decode(FieldMatch::bcd(seconds, 0, 59), minutes, hours, day_date)
+    }
+
+    /// Gets the register values in address order (0x07-0x0A).
+    pub(crate) fn to_registers(self) -> [u8; 4] {
+        [
+            self.seconds.into(),
+            self.minutes.into(),
+            self.hours.into(),
+            self.day_date.into(),
+        ]
+    }
 }
 
 #[cfg(feature = "defmt")]
@@ -848,6 +1709,8 @@ impl DS3231Alarm2 {
                 day_date.set_alarm_mask4(true);
             }
 
+            Alarm2Config::Raw(raw) => return Ok(*raw),
+
             Alarm2Config::AtMinutes { minutes: min } => {
                 let (min_ones, min_tens) = DS3231DateTime::make_bcd(u32::from(*min), 59)?;
                 minutes.set_minutes(min_ones);
@@ -908,13 +1771,17 @@ impl DS3231Alarm2 {
     ///
     /// # Returns
     ///
-    /// The `Alarm2Config` that corresponds to the current register values.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the register values don't form a valid configuration
-    /// or contain invalid BCD values.
-    pub fn to_config(&self) -> Result<Alarm2Config, AlarmError> {
+    /// The `Alarm2Config` that corresponds to the current register values, or
+    /// `Alarm2Config::Raw` if the mask bits are not one of the documented combinations
+    /// or a matched register holds a value the clock never reaches (invalid BCD, an hour
+    /// out of range, a date outside 1-31 or a day outside 1-7).
+    #[must_use]
+    pub fn to_config(&self) -> Alarm2Config {
+        self.decode_documented().unwrap_or(Alarm2Config::Raw(*self))
+    }
+
+    /// Decodes a documented combination of mask bits, failing on an unreachable value.
+    fn decode_documented(self) -> Result<Alarm2Config, AlarmError> {
         // Check mask bit pattern to determine alarm type
         let mask2 = self.minutes.alarm_mask2();
         let mask3 = self.hours.alarm_mask3();
@@ -948,7 +1815,7 @@ impl DS3231Alarm2 {
 
                 if self.day_date.day_date_select() == DayDateSelect::Day {
                     // Day of week alarm
-                    let day = self.day_date.day_or_date();
+                    let day = self.decode_day()?;
                     Ok(Alarm2Config::AtTimeOnDay {
                         hours,
                         minutes,
@@ -967,10 +1834,8 @@ impl DS3231Alarm2 {
                 }
             }
 
-            // Invalid mask combination
-            _ => Err(AlarmError::InvalidTime(
-                "Invalid alarm mask bit combination",
-            )),
+            // Any other mask combination is kept as it is
+            _ => Ok(Alarm2Config::Raw(self)),
         }
     }
 
@@ -1011,6 +1876,14 @@ impl DS3231Alarm2 {
         }
     }
 
+    fn decode_day(self) -> Result<u8, AlarmError> {
+        let day = self.day_date.day_or_date();
+        if !(1..=7).contains(&day) {
+            return Err(AlarmError::InvalidDayOfWeek);
+        }
+        Ok(day)
+    }
+
     fn decode_bcd_day_date(self) -> Result<u8, AlarmError> {
         let ones = self.day_date.day_or_date();
         let tens = self.day_date.ten_date();
@@ -1069,6 +1942,50 @@ impl DS3231Alarm2 {
             day_date,
         }
     }
+
+    /// Encodes a configuration with its hours in `time_representation` and its day of the
+    /// week in `numbering`.
+    pub(crate) fn encode(
+        config: &Alarm2Config,
+        numbering: WeekdayNumbering,
+        time_representation: TimeRepresentation,
+    ) -> Result<Self, AlarmError> {
+        Self::from_config(
+            &config
+                .in_time_representation(time_representation)
+                .map_day(|day| numbering.encode_day(day)),
+        )
+    }
+
+    /// Applies `f` to the Day value if DY/DT selects the day of the week.
+    fn map_day(mut self, f: impl FnOnce(u8) -> u8) -> Self {
+        if self.day_date.day_date_select() == DayDateSelect::Day {
+            self.day_date
+                .set_day_or_date(f(self.day_date.day_or_date()));
+        }
+        self
+    }
+
+    /// Decodes the registers to a configuration with 24-hour hours, reading the day of the
+    /// week in `numbering`.
+    pub(crate) fn decode(self, numbering: WeekdayNumbering) -> Alarm2Config {
+        self.to_config()
+            .in_time_representation(TimeRepresentation::TwentyFourHour)
+            .map_day(|value| numbering.decode_day(value))
+    }
+
+    /// Decodes what the device compares, whatever the combination of mask bits. Alarm 2
+    /// always matches at 00 seconds.
+    #[must_use]
+    pub fn alarm_match(&self) -> AlarmMatch {
+        let [minutes, hours, day_date] = self.to_registers();
+        AlarmMatch::decode(FieldMatch::Value(0), minutes, hours, day_date)
+    }
+
+    /// Gets the register values in address order (0x0B-0x0D).
+    pub(crate) fn to_registers(self) -> [u8; 3] {
+        [self.minutes.into(), self.hours.into(), self.day_date.into()]
+    }
 }
 
 #[cfg(feature = "defmt")]
@@ -1086,6 +2003,7 @@ impl defmt::Format for DS3231Alarm2 {
 mod tests {
     extern crate alloc;
     use super::*;
+    use alloc::string::ToString;
     use alloc::vec;
 
     #[test]
@@ -1133,7 +2051,7 @@ mod tests {
 
         for config in configs {
             let alarm = DS3231Alarm1::from_config(&config).unwrap();
-            let converted_back = alarm.to_config().unwrap();
+            let converted_back = alarm.to_config();
             assert_eq!(config, converted_back);
         }
     }
@@ -1165,7 +2083,7 @@ mod tests {
 
         for config in configs {
             let alarm = DS3231Alarm2::from_config(&config).unwrap();
-            let converted_back = alarm.to_config().unwrap();
+            let converted_back = alarm.to_config();
             assert_eq!(config, converted_back);
         }
     }
@@ -1179,7 +2097,7 @@ mod tests {
         let day_date = AlarmDayDate(0x15); // 15 date with no mask bit
 
         let alarm = DS3231Alarm1::from_registers(seconds, minutes, hours, day_date);
-        let config = alarm.to_config().unwrap();
+        let config = alarm.to_config();
 
         match config {
             Alarm1Config::AtTimeOnDate {
@@ -1207,7 +2125,7 @@ mod tests {
         let day_date = AlarmDayDate(0x15); // 15 date with no mask bit
 
         let alarm = DS3231Alarm2::from_registers(minutes, hours, day_date);
-        let config = alarm.to_config().unwrap();
+        let config = alarm.to_config();
 
         match config {
             Alarm2Config::AtTimeOnDate {
@@ -2169,27 +3087,85 @@ mod tests {
     }
 
     #[test]
-    fn test_to_config_invalid_mask_combinations() {
-        // Test Alarm1 invalid mask combination
+    fn test_to_config_raw_mask_combinations() {
+        // Alarm1 with only mask2 set
         let mut alarm1 = DS3231Alarm1::from_config(&Alarm1Config::EverySecond).unwrap();
-        // Create an invalid mask pattern (e.g., only mask2 set)
         alarm1.seconds.set_alarm_mask1(false);
         alarm1.minutes.set_alarm_mask2(true);
         alarm1.hours.set_alarm_mask3(false);
         alarm1.day_date.set_alarm_mask4(false);
 
-        let result = alarm1.to_config();
-        assert!(matches!(result, Err(AlarmError::InvalidTime(_))));
+        let config = alarm1.to_config();
+        assert_eq!(config, Alarm1Config::Raw(alarm1));
+        assert_eq!(DS3231Alarm1::from_config(&config).unwrap(), alarm1);
 
-        // Test Alarm2 invalid mask combination
+        // Alarm2 with only mask3 set
         let mut alarm2 = DS3231Alarm2::from_config(&Alarm2Config::EveryMinute).unwrap();
-        // Create an invalid mask pattern (e.g., only mask3 set)
         alarm2.minutes.set_alarm_mask2(false);
         alarm2.hours.set_alarm_mask3(true);
         alarm2.day_date.set_alarm_mask4(false);
 
-        let result = alarm2.to_config();
-        assert!(matches!(result, Err(AlarmError::InvalidTime(_))));
+        let config = alarm2.to_config();
+        assert_eq!(config, Alarm2Config::Raw(alarm2));
+        assert_eq!(DS3231Alarm2::from_config(&config).unwrap(), alarm2);
+    }
+
+    #[test]
+    fn test_alarm_match() {
+        // Seconds 30 and date 15 matched, minutes masked, hours left at an invalid value
+        let alarm1 = DS3231Alarm1::from_registers(
+            AlarmSeconds(0x30),
+            AlarmMinutes(0x80),
+            AlarmHours(0x3F),
+            AlarmDayDate(0x15),
+        );
+        let alarm_match = alarm1.alarm_match();
+        assert_eq!(
+            alarm_match,
+            AlarmMatch {
+                seconds: FieldMatch::Value(30),
+                minutes: FieldMatch::Any,
+                hours: FieldMatch::Never,
+                day: FieldMatch::Any,
+                date: FieldMatch::Value(15),
+            }
+        );
+        assert!(!alarm_match.can_fire());
+        assert!(matches!(alarm1.to_config(), Alarm1Config::Raw(_)));
+
+        assert_eq!(
+            alarm_match.to_string(),
+            "seconds 30, minutes any, hours never, day any, date 15"
+        );
+
+        // Alarm 2 on Tuesday at 9 PM in 12-hour form, minutes masked
+        let alarm2 =
+            DS3231Alarm2::from_registers(AlarmMinutes(0x80), AlarmHours(0x69), AlarmDayDate(0x43));
+        let alarm_match = alarm2.alarm_match();
+        assert_eq!(
+            alarm_match,
+            AlarmMatch {
+                seconds: FieldMatch::Value(0),
+                minutes: FieldMatch::Any,
+                hours: FieldMatch::Value(21),
+                day: FieldMatch::Value(3),
+                date: FieldMatch::Any,
+            }
+        );
+        assert!(alarm_match.can_fire());
+
+        // A documented combination decodes the same way
+        let alarm2 = DS3231Alarm2::from_config(&Alarm2Config::AtMinutes { minutes: 45 }).unwrap();
+        assert_eq!(
+            alarm2.alarm_match(),
+            AlarmMatch {
+                seconds: FieldMatch::Value(0),
+                minutes: FieldMatch::Value(45),
+                hours: FieldMatch::Any,
+                day: FieldMatch::Any,
+                date: FieldMatch::Any,
+            }
+        );
     }
 
     #[test]
@@ -2205,7 +3181,7 @@ mod tests {
         .unwrap();
 
         // Convert and verify it's detected as day mode
-        let config = alarm1.to_config().unwrap();
+        let config = alarm1.to_config();
         match config {
             Alarm1Config::AtTimeOnDay { day, .. } => assert_eq!(day, 5),
             _ => panic!("Expected AtTimeOnDay"),
@@ -2215,7 +3191,7 @@ mod tests {
         alarm1.day_date.set_day_date_select(DayDateSelect::Date);
         alarm1.day_date.set_ten_date(0);
         alarm1.day_date.set_day_or_date(5); // Date 5
-        let config = alarm1.to_config().unwrap();
+        let config = alarm1.to_config();
         match config {
             Alarm1Config::AtTimeOnDate { date, .. } => assert_eq!(date, 5),
             _ => panic!("Expected AtTimeOnDate"),
@@ -2230,7 +3206,7 @@ mod tests {
         })
         .unwrap();
 
-        let config = alarm2.to_config().unwrap();
+        let config = alarm2.to_config();
         match config {
             Alarm2Config::AtTimeOnDay { day, .. } => assert_eq!(day, 3),
             _ => panic!("Expected AtTimeOnDay"),
@@ -2240,7 +3216,7 @@ mod tests {
         alarm2.day_date.set_day_date_select(DayDateSelect::Date);
         alarm2.day_date.set_ten_date(0);
         alarm2.day_date.set_day_or_date(8); // Date 8
-        let config = alarm2.to_config().unwrap();
+        let config = alarm2.to_config();
         match config {
             Alarm2Config::AtTimeOnDate { date, .. } => assert_eq!(date, 8),
             _ => panic!("Expected AtTimeOnDate"),
@@ -2258,7 +3234,7 @@ mod tests {
         })
         .unwrap();
 
-        let config = alarm1.to_config().unwrap();
+        let config = alarm1.to_config();
         match config {
             Alarm1Config::AtTime {
                 is_pm: Some(true), ..
@@ -2268,7 +3244,7 @@ mod tests {
 
         // Test AM flag
         alarm1.hours.set_pm_or_twenty_hours(0); // Set AM
-        let config = alarm1.to_config().unwrap();
+        let config = alarm1.to_config();
         match config {
             Alarm1Config::AtTime {
                 is_pm: Some(false), ..
@@ -2284,7 +3260,7 @@ mod tests {
         })
         .unwrap();
 
-        let config = alarm2.to_config().unwrap();
+        let config = alarm2.to_config();
         match config {
             Alarm2Config::AtTime {
                 is_pm: Some(true), ..
@@ -2294,7 +3270,7 @@ mod tests {
 
         // Test AM flag for Alarm2
         alarm2.hours.set_pm_or_twenty_hours(0); // Set AM
-        let config = alarm2.to_config().unwrap();
+        let config = alarm2.to_config();
         match config {
             Alarm2Config::AtTime {
                 is_pm: Some(false), ..
@@ -2509,519 +3485,400 @@ mod tests {
 
     #[test]
     fn test_to_config_with_invalid_bcd_valid_flags() {
-        // Goal: Test that to_config fails if BCD is invalid, even if flags are valid.
-        // We set up flags for a mode that decodes all fields (e.g., AtTimeOnDate for Alarm1,
-        // AtTimeOnDate for Alarm2: all relevant mask bits false, DY/DT=0 for date mode).
-
-        // --- Common valid components (can be overridden for specific tests) ---
-        let mut default_seconds = AlarmSeconds::default(); // 0x00
-        default_seconds.set_alarm_mask1(false);
-
-        let mut default_minutes = AlarmMinutes::default(); // 0x00
-        default_minutes.set_alarm_mask2(false);
-        default_minutes.set_minutes(5); // 05 min
-        default_minutes.set_ten_minutes(3); // 30 min -> 35 min
-
-        let mut default_hours_24h = AlarmHours::default(); // 0x00
-        default_hours_24h.set_alarm_mask3(false);
-        default_hours_24h.set_time_representation(TimeRepresentation::TwentyFourHour);
-        default_hours_24h.set_hours(2); // 2 hr
-        default_hours_24h.set_ten_hours(1); // 10 hr -> 12 hr
-
-        let mut default_hours_12h = AlarmHours::default(); // 0x00
-        default_hours_12h.set_alarm_mask3(false);
-        default_hours_12h.set_time_representation(TimeRepresentation::TwelveHour);
-        default_hours_12h.set_hours(1); // 1 hr
-        default_hours_12h.set_ten_hours(0); // 00 hr -> 1 hr
-        default_hours_12h.set_pm_or_twenty_hours(1); // PM
-
-        let mut default_day_date = AlarmDayDate::default(); // 0x00
-        default_day_date.set_alarm_mask4(false);
-        default_day_date.set_day_date_select(DayDateSelect::Date);
-        default_day_date.set_day_or_date(5); // 5th
-        default_day_date.set_ten_date(1); // 10th -> 15th
-
-        // --- DS3231Alarm1 Tests ---
-
-        // Invalid BCD seconds (ones > 9)
-        let alarm1_s1 = DS3231Alarm1::from_registers(
-            AlarmSeconds(0x0A),
-            default_minutes,
-            default_hours_24h,
-            default_day_date,
-        );
-        let res_s1 = alarm1_s1.to_config();
-        assert!(
-            matches!(
-                res_s1,
-                Err(AlarmError::InvalidTime("Invalid BCD seconds value"))
-            ),
-            "Alarm1 SecOnes: {:?}",
-            res_s1
-        );
-
-        // Invalid BCD seconds (tens > 5)
-        let alarm1_s2 = DS3231Alarm1::from_registers(
-            AlarmSeconds(0x60),
-            default_minutes,
-            default_hours_24h,
-            default_day_date,
-        );
-        let res_s2 = alarm1_s2.to_config();
-        assert!(
-            matches!(
-                res_s2,
-                Err(AlarmError::InvalidTime("Invalid BCD seconds value"))
-            ),
-            "Alarm1 SecTens: {:?}",
-            res_s2
-        );
-
-        // Invalid BCD minutes (ones > 9)
-        let alarm1_m1 = DS3231Alarm1::from_registers(
-            default_seconds,
-            AlarmMinutes(0x0A),
-            default_hours_24h,
-            default_day_date,
-        );
-        let res_m1 = alarm1_m1.to_config();
-        assert!(
-            matches!(
-                res_m1,
-                Err(AlarmError::InvalidTime("Invalid BCD minutes value"))
-            ),
-            "Alarm1 MinOnes: {:?}",
-            res_m1
-        );
-
-        // Invalid BCD minutes (tens > 5)
-        let alarm1_m2 = DS3231Alarm1::from_registers(
-            default_seconds,
-            AlarmMinutes(0x60),
-            default_hours_24h,
-            default_day_date,
-        );
-        let res_m2 = alarm1_m2.to_config();
-        assert!(
-            matches!(
-                res_m2,
-                Err(AlarmError::InvalidTime("Invalid BCD minutes value"))
-            ),
-            "Alarm1 MinTens: {:?}",
-            res_m2
-        );
-
-        // Invalid BCD hours (ones > 9, 24h mode)
-        let mut hours_24_invalid_ones = AlarmHours(0x0A); // Raw BCD for 0 tens, 10 ones
-        hours_24_invalid_ones.set_alarm_mask3(false);
-        hours_24_invalid_ones.set_time_representation(TimeRepresentation::TwentyFourHour);
-        let alarm1_h1 = DS3231Alarm1::from_registers(
-            default_seconds,
-            default_minutes,
-            hours_24_invalid_ones,
-            default_day_date,
-        );
-        let res_h1 = alarm1_h1.to_config();
-        assert!(
-            matches!(
-                res_h1,
-                Err(AlarmError::InvalidTime("Invalid BCD hours value"))
-            ),
-            "Alarm1 Hr24Ones: {:?}",
-            res_h1
-        );
+        // All mask bits clear with DY/DT clear (AtTimeOnDate): 12:35:00 on the 15th, in
+        // register order seconds, minutes, hours, day/date
+        let valid = [0x00, 0x35, 0x12, 0x15];
+        let alarm1 = |regs: [u8; 4]| {
+            DS3231Alarm1::from_registers(
+                AlarmSeconds(regs[0]),
+                AlarmMinutes(regs[1]),
+                AlarmHours(regs[2]),
+                AlarmDayDate(regs[3]),
+            )
+        };
+        let alarm2 = |regs: [u8; 4]| {
+            DS3231Alarm2::from_registers(
+                AlarmMinutes(regs[1]),
+                AlarmHours(regs[2]),
+                AlarmDayDate(regs[3]),
+            )
+        };
+        assert!(matches!(
+            alarm1(valid).to_config(),
+            Alarm1Config::AtTimeOnDate { .. }
+        ));
+        assert!(matches!(
+            alarm2(valid).to_config(),
+            Alarm2Config::AtTimeOnDate { .. }
+        ));
 
-        // Invalid BCD hours (tens > 2, 24h mode e.g. 0x30)
-        let mut hours_24_invalid_tens = AlarmHours(0x30); // Raw BCD for 3 tens, 0 ones
-        hours_24_invalid_tens.set_alarm_mask3(false);
-        hours_24_invalid_tens.set_time_representation(TimeRepresentation::TwentyFourHour);
-        let alarm1_h2 = DS3231Alarm1::from_registers(
-            default_seconds,
-            default_minutes,
-            hours_24_invalid_tens,
-            default_day_date,
-        );
-        let res_h2 = alarm1_h2.to_config();
-        assert!(
-            matches!(
-                res_h2,
-                Err(AlarmError::InvalidTime("Invalid 24-hour value"))
-            ),
-            "Alarm1 Hr24Tens: {:?}",
-            res_h2
-        );
+        // An unreachable value in any matched field falls back to Raw
+        let cases = [
+            (0, 0x0A, "seconds ones > 9"),
+            (0, 0x60, "seconds tens > 5"),
+            (1, 0x0A, "minutes ones > 9"),
+            (1, 0x60, "minutes tens > 5"),
+            (2, 0x0A, "24-hour ones > 9"),
+            (2, 0x30, "24-hour tens > 2"),
+            (2, 0x24, "24-hour value 24"),
+            (2, 0x5A, "12-hour ones > 9"),
+            (2, 0x40, "12-hour value 0"),
+            (2, 0x53, "12-hour value 13"),
+            (3, 0x0A, "date ones > 9"),
+            (3, 0x00, "date 0"),
+            (3, 0x32, "date 32"),
+        ];
+        for (field, value, what) in cases {
+            let mut regs = valid;
+            regs[field] = value;
+            assert!(
+                matches!(alarm1(regs).to_config(), Alarm1Config::Raw(_)),
+                "Alarm1 {what}"
+            );
+            // Alarm 2 has no seconds register
+            if field > 0 {
+                assert!(
+                    matches!(alarm2(regs).to_config(), Alarm2Config::Raw(_)),
+                    "Alarm2 {what}"
+                );
+            }
+        }
+    }
 
-        // Invalid 24-hour value (e.g. 24:00 which is 0x24 BCD - should be caught by value check)
-        let mut hours_24_val_error = AlarmHours(0x24);
-        hours_24_val_error.set_alarm_mask3(false);
-        hours_24_val_error.set_time_representation(TimeRepresentation::TwentyFourHour);
-        let alarm1_h3 = DS3231Alarm1::from_registers(
-            default_seconds,
-            default_minutes,
-            hours_24_val_error,
-            default_day_date,
-        );
-        let res_h3 = alarm1_h3.to_config();
-        assert!(
-            matches!(
-                res_h3,
-                Err(AlarmError::InvalidTime("Invalid 24-hour value"))
-            ),
-            "Alarm1 Hr24Val: {:?}",
-            res_h3
-        );
+    #[test]
+    fn test_to_config_day_out_of_range_is_raw() {
+        for day in [0x40, 0x48, 0x4F] {
+            let alarm1 = DS3231Alarm1::from_registers(
+                AlarmSeconds(0x00),
+                AlarmMinutes(0x30),
+                AlarmHours(0x12),
+                AlarmDayDate(day),
+            );
+            assert!(
+                matches!(alarm1.to_config(), Alarm1Config::Raw(_)),
+                "Alarm1 day {day:#04x}"
+            );
+
+            let alarm2 = DS3231Alarm2::from_registers(
+                AlarmMinutes(0x30),
+                AlarmHours(0x12),
+                AlarmDayDate(day),
+            );
+            assert!(
+                matches!(alarm2.to_config(), Alarm2Config::Raw(_)),
+                "Alarm2 day {day:#04x}"
+            );
+        }
+    }
 
-        // Invalid BCD hours (ones > 9, 12h mode e.g. 0x1A for 1 tens, 10 ones)
-        let mut hours_12_invalid_ones = AlarmHours(0x1A);
-        hours_12_invalid_ones.set_alarm_mask3(false);
-        hours_12_invalid_ones.set_time_representation(TimeRepresentation::TwelveHour);
-        hours_12_invalid_ones.set_pm_or_twenty_hours(0); // AM
-        let alarm1_h4 = DS3231Alarm1::from_registers(
-            default_seconds,
-            default_minutes,
-            hours_12_invalid_ones,
-            default_day_date,
-        );
-        let res_h4 = alarm1_h4.to_config();
-        assert!(
-            matches!(
-                res_h4,
-                Err(AlarmError::InvalidTime("Invalid BCD hours value"))
-            ),
-            "Alarm1 Hr12Ones: {:?}",
-            res_h4
-        );
+    #[test]
+    fn test_create_alarm_time_components_errors() {
+        // Invalid hour
+        assert!(matches!(
+            create_alarm_time_components(24, 0, None),
+            Err(AlarmError::DateTime(DS3231DateTimeError::InvalidDateTime))
+        ));
 
-        // Invalid 12-hour value (00:xx AM which is BCD 0x00 with 12h bit)
-        let mut hours_12_val_zero = AlarmHours(0x00);
-        hours_12_val_zero.set_alarm_mask3(false);
-        hours_12_val_zero.set_time_representation(TimeRepresentation::TwelveHour);
-        hours_12_val_zero.set_pm_or_twenty_hours(0); // AM
-        let alarm1_h5 = DS3231Alarm1::from_registers(
-            default_seconds,
-            default_minutes,
-            hours_12_val_zero,
-            default_day_date,
-        );
-        let res_h5 = alarm1_h5.to_config();
-        assert!(
-            matches!(
-                res_h5,
-                Err(AlarmError::InvalidTime("Invalid 12-hour value"))
-            ),
-            "Alarm1 Hr12ValZero: {:?}",
-            res_h5
-        );
+        // Invalid minute
+        assert!(matches!(
+            create_alarm_time_components(0, 60, None),
+            Err(AlarmError::DateTime(DS3231DateTimeError::InvalidDateTime))
+        ));
+    }
 
-        // Invalid 12-hour value (13:xx AM which is BCD 0x13 with 12h bit)
-        let mut hours_12_val_thirteen = AlarmHours(0x13);
-        hours_12_val_thirteen.set_alarm_mask3(false);
-        hours_12_val_thirteen.set_time_representation(TimeRepresentation::TwelveHour);
-        hours_12_val_thirteen.set_pm_or_twenty_hours(0); // AM
-        let alarm1_h6 = DS3231Alarm1::from_registers(
-            default_seconds,
-            default_minutes,
-            hours_12_val_thirteen,
-            default_day_date,
-        );
-        let res_h6 = alarm1_h6.to_config();
-        assert!(
-            matches!(
-                res_h6,
-                Err(AlarmError::InvalidTime("Invalid 12-hour value"))
-            ),
-            "Alarm1 Hr12ValThirteen: {:?}",
-            res_h6
-        );
+    #[test]
+    fn test_create_alarm_day_date_component_errors() {
+        // Invalid date
+        assert!(matches!(
+            create_alarm_day_date_component(32, false), // Date 32
+            Err(AlarmError::DateTime(DS3231DateTimeError::InvalidDateTime))
+        ));
+    }
 
-        // Invalid BCD day/date (ones > 9)
-        let mut daydate_d1 = AlarmDayDate(0x0A);
-        daydate_d1.set_alarm_mask4(false);
-        daydate_d1.set_day_date_select(DayDateSelect::Date);
-        let alarm1_d1 = DS3231Alarm1::from_registers(
-            default_seconds,
-            default_minutes,
-            default_hours_24h,
-            daydate_d1,
-        );
-        let res_d1 = alarm1_d1.to_config();
-        assert!(
-            matches!(
-                res_d1,
-                Err(AlarmError::InvalidTime("Invalid BCD date value"))
-            ),
-            "Alarm1 DateOnes: {:?}",
-            res_d1
+    #[test]
+    fn test_alarm_event_from_status() {
+        assert_eq!(AlarmEvent::from_status(Status(0x88)), None);
+        assert_eq!(
+            AlarmEvent::from_status(Status(0x01)),
+            Some(AlarmEvent::Alarm1)
         );
-
-        // Invalid BCD day/date (tens > 3, e.g. 0x40 for date)
-        let mut daydate_d2 = AlarmDayDate(0x40);
-        daydate_d2.set_alarm_mask4(false);
-        daydate_d2.set_day_date_select(DayDateSelect::Date);
-        let alarm1_d2 = DS3231Alarm1::from_registers(
-            default_seconds,
-            default_minutes,
-            default_hours_24h,
-            daydate_d2,
+        assert_eq!(
+            AlarmEvent::from_status(Status(0x02)),
+            Some(AlarmEvent::Alarm2)
         );
-        let res_d2 = alarm1_d2.to_config();
-        assert!(
-            matches!(res_d2, Err(AlarmError::InvalidTime("Invalid date value"))),
-            "Alarm1 DateTens (effectively date 0): {:?}",
-            res_d2
+        assert_eq!(
+            AlarmEvent::from_status(Status(0x83)),
+            Some(AlarmEvent::Both)
         );
 
-        // Invalid date value (00)
-        let mut daydate_d3 = AlarmDayDate(0x00); // BCD for 0
-        daydate_d3.set_alarm_mask4(false);
-        daydate_d3.set_day_date_select(DayDateSelect::Date);
-        let alarm1_d3 = DS3231Alarm1::from_registers(
-            default_seconds,
-            default_minutes,
-            default_hours_24h,
-            daydate_d3,
-        );
-        let res_d3 = alarm1_d3.to_config();
-        assert!(
-            matches!(res_d3, Err(AlarmError::InvalidTime("Invalid date value"))),
-            "Alarm1 DateValZero: {:?}",
-            res_d3
-        );
+        assert!(AlarmEvent::Alarm1.includes(AlarmId::Alarm1));
+        assert!(!AlarmEvent::Alarm1.includes(AlarmId::Alarm2));
+        assert!(AlarmEvent::Both.includes(AlarmId::Alarm2));
+    }
 
-        // Invalid date value (32 -> BCD 0x32)
-        let mut daydate_d4 = AlarmDayDate(0x32); // BCD for 32
-        daydate_d4.set_alarm_mask4(false);
-        daydate_d4.set_day_date_select(DayDateSelect::Date);
-        let alarm1_d4 = DS3231Alarm1::from_registers(
-            default_seconds,
-            default_minutes,
-            default_hours_24h,
-            daydate_d4,
-        );
-        let res_d4 = alarm1_d4.to_config();
-        assert!(
-            matches!(res_d4, Err(AlarmError::InvalidTime("Invalid date value"))),
-            "Alarm1 DateVal32: {:?}",
-            res_d4
+    #[test]
+    fn test_fired_alarms() {
+        let fired = FiredAlarms::from(Status(0x8A));
+        assert_eq!(
+            fired,
+            FiredAlarms {
+                alarm1: false,
+                alarm2: true
+            }
         );
+        assert!(fired.any());
+        assert!(fired.fired(AlarmId::Alarm2));
+        assert!(!fired.fired(AlarmId::Alarm1));
+        assert_eq!(fired.event(), Some(AlarmEvent::Alarm2));
+        assert!(!FiredAlarms::from(Status(0x88)).any());
+    }
 
-        // --- DS3231Alarm2 Tests ---
-        // For Alarm2, seconds are not used/decoded from AlarmSeconds.
-
-        // Invalid BCD minutes (ones > 9)
-        let alarm2_m1 =
-            DS3231Alarm2::from_registers(AlarmMinutes(0x0A), default_hours_24h, default_day_date);
-        let res_a2m1 = alarm2_m1.to_config();
-        assert!(
-            matches!(
-                res_a2m1,
-                Err(AlarmError::InvalidTime("Invalid BCD minutes value"))
-            ),
-            "Alarm2 MinOnes: {:?}",
-            res_a2m1
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_alarm_weekday() {
+        let alarm1 =
+            Alarm1Config::on_weekday(Weekday::Mon, NaiveTime::from_hms_opt(7, 15, 0).unwrap());
+        assert_eq!(
+            alarm1,
+            Alarm1Config::AtTimeOnDay {
+                hours: 7,
+                minutes: 15,
+                seconds: 0,
+                day: 2,
+                is_pm: None,
+            }
         );
+        assert_eq!(alarm1.weekday(), Some(Weekday::Mon));
+        assert_eq!(Alarm1Config::EverySecond.weekday(), None);
 
-        // Invalid BCD minutes (tens > 5)
-        let alarm2_m2 =
-            DS3231Alarm2::from_registers(AlarmMinutes(0x60), default_hours_24h, default_day_date);
-        let res_a2m2 = alarm2_m2.to_config();
-        assert!(
-            matches!(
-                res_a2m2,
-                Err(AlarmError::InvalidTime("Invalid BCD minutes value"))
-            ),
-            "Alarm2 MinTens: {:?}",
-            res_a2m2
+        let alarm2 =
+            Alarm2Config::on_weekday(Weekday::Sun, NaiveTime::from_hms_opt(21, 0, 30).unwrap());
+        assert_eq!(
+            alarm2,
+            Alarm2Config::AtTimeOnDay {
+                hours: 21,
+                minutes: 0,
+                day: 1,
+                is_pm: None,
+            }
         );
+        assert_eq!(alarm2.weekday(), Some(Weekday::Sun));
+    }
 
-        // Invalid BCD hours (ones > 9, 24h mode)
-        let mut hours_24_invalid_ones_a2 = AlarmHours(0x0A);
-        hours_24_invalid_ones_a2.set_alarm_mask3(false);
-        hours_24_invalid_ones_a2.set_time_representation(TimeRepresentation::TwentyFourHour);
-        let alarm2_h1 = DS3231Alarm2::from_registers(
-            default_minutes,
-            hours_24_invalid_ones_a2,
-            default_day_date,
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_alarm_time() {
+        let time = NaiveTime::from_hms_opt(18, 45, 30).unwrap();
+        assert_eq!(
+            Alarm1Config::daily(time),
+            Alarm1Config::AtTime {
+                hours: 18,
+                minutes: 45,
+                seconds: 30,
+                is_pm: None,
+            }
         );
-        let res_a2h1 = alarm2_h1.to_config();
-        assert!(
-            matches!(
-                res_a2h1,
-                Err(AlarmError::InvalidTime("Invalid BCD hours value"))
-            ),
-            "Alarm2 Hr24Ones: {:?}",
-            res_a2h1
+        assert_eq!(Alarm1Config::on_date(15, time).time(), Some(time));
+        assert_eq!(
+            Alarm2Config::on_date(15, time),
+            Alarm2Config::AtTimeOnDate {
+                hours: 18,
+                minutes: 45,
+                date: 15,
+                is_pm: None,
+            }
         );
+        // The 12-hour form gives the same time
+        let twelve_hour = Alarm2Config::AtTime {
+            hours: 6,
+            minutes: 45,
+            is_pm: Some(true),
+        };
+        assert_eq!(twelve_hour.time(), NaiveTime::from_hms_opt(18, 45, 0));
+        assert_eq!(Alarm2Config::daily(time).time(), twelve_hour.time());
+        assert_eq!(Alarm2Config::EveryMinute.time(), None);
+        let invalid = Alarm1Config::AtTime {
+            hours: 13,
+            minutes: 0,
+            seconds: 0,
+            is_pm: Some(false),
+        };
+        assert_eq!(invalid.time(), None);
+    }
 
-        // Invalid BCD hours (tens > 2, 24h mode e.g. 0x30)
-        let mut hours_24_invalid_tens_a2 = AlarmHours(0x30);
-        hours_24_invalid_tens_a2.set_alarm_mask3(false);
-        hours_24_invalid_tens_a2.set_time_representation(TimeRepresentation::TwentyFourHour);
-        let alarm2_h2 = DS3231Alarm2::from_registers(
-            default_minutes,
-            hours_24_invalid_tens_a2,
-            default_day_date,
-        );
-        let res_a2h2 = alarm2_h2.to_config();
-        assert!(
-            matches!(
-                res_a2h2,
-                Err(AlarmError::InvalidTime("Invalid 24-hour value"))
-            ),
-            "Alarm2 Hr24Tens: {:?}",
-            res_a2h2
+    #[test]
+    fn test_alarm_builders() {
+        const WAKE_UP: Alarm1Config = Alarm1::daily().at_hms(6, 30, 15);
+        const REPORT: Alarm2Config = Alarm2::monthly(31).at_hm(23, 59);
+        assert_eq!(
+            WAKE_UP,
+            Alarm1Config::AtTime {
+                hours: 6,
+                minutes: 30,
+                seconds: 15,
+                is_pm: None,
+            }
         );
-
-        // Invalid 24-hour value (e.g. 24:00 which is 0x24 BCD)
-        let mut hours_24_val_error_a2 = AlarmHours(0x24);
-        hours_24_val_error_a2.set_alarm_mask3(false);
-        hours_24_val_error_a2.set_time_representation(TimeRepresentation::TwentyFourHour);
-        let alarm2_h3 =
-            DS3231Alarm2::from_registers(default_minutes, hours_24_val_error_a2, default_day_date);
-        let res_a2h3 = alarm2_h3.to_config();
-        assert!(
-            matches!(
-                res_a2h3,
-                Err(AlarmError::InvalidTime("Invalid 24-hour value"))
-            ),
-            "Alarm2 Hr24Val: {:?}",
-            res_a2h3
+        assert_eq!(
+            REPORT,
+            Alarm2Config::AtTimeOnDate {
+                hours: 23,
+                minutes: 59,
+                date: 31,
+                is_pm: None,
+            }
         );
-
-        // Invalid BCD hours (ones > 9, 12h mode e.g. 0x1A)
-        let mut hours_12_invalid_ones_a2 = AlarmHours(0x1A);
-        hours_12_invalid_ones_a2.set_alarm_mask3(false);
-        hours_12_invalid_ones_a2.set_time_representation(TimeRepresentation::TwelveHour);
-        hours_12_invalid_ones_a2.set_pm_or_twenty_hours(0); // AM
-        let alarm2_h4 = DS3231Alarm2::from_registers(
-            default_minutes,
-            hours_12_invalid_ones_a2,
-            default_day_date,
+        assert_eq!(
+            Alarm1::monthly(1).at_hms(0, 0, 0),
+            Alarm1Config::AtTimeOnDate {
+                hours: 0,
+                minutes: 0,
+                seconds: 0,
+                date: 1,
+                is_pm: None,
+            }
         );
-        let res_a2h4 = alarm2_h4.to_config();
-        assert!(
-            matches!(
-                res_a2h4,
-                Err(AlarmError::InvalidTime("Invalid BCD hours value"))
-            ),
-            "Alarm2 Hr12Ones: {:?}",
-            res_a2h4
+        assert_eq!(Alarm1::every_second(), Alarm1Config::EverySecond);
+        assert_eq!(
+            Alarm1::every_minute_at(45),
+            Alarm1Config::AtSeconds { seconds: 45 }
         );
-
-        // Invalid 12-hour value (00:xx AM BCD 0x00 with 12h bit)
-        let mut hours_12_val_zero_a2 = AlarmHours(0x00);
-        hours_12_val_zero_a2.set_alarm_mask3(false);
-        hours_12_val_zero_a2.set_time_representation(TimeRepresentation::TwelveHour);
-        hours_12_val_zero_a2.set_pm_or_twenty_hours(0); // AM
-        let alarm2_h5 =
-            DS3231Alarm2::from_registers(default_minutes, hours_12_val_zero_a2, default_day_date);
-        let res_a2h5 = alarm2_h5.to_config();
-        assert!(
-            matches!(
-                res_a2h5,
-                Err(AlarmError::InvalidTime("Invalid 12-hour value"))
-            ),
-            "Alarm2 Hr12ValZero: {:?}",
-            res_a2h5
+        assert_eq!(
+            Alarm1::hourly_at(15, 30),
+            Alarm1Config::AtMinutesSeconds {
+                minutes: 15,
+                seconds: 30,
+            }
         );
-
-        // Invalid 12-hour value (13:xx AM BCD 0x13 with 12h bit)
-        let mut hours_12_val_thirteen_a2 = AlarmHours(0x13);
-        hours_12_val_thirteen_a2.set_alarm_mask3(false);
-        hours_12_val_thirteen_a2.set_time_representation(TimeRepresentation::TwelveHour);
-        hours_12_val_thirteen_a2.set_pm_or_twenty_hours(0); // AM
-        let alarm2_h6 = DS3231Alarm2::from_registers(
-            default_minutes,
-            hours_12_val_thirteen_a2,
-            default_day_date,
+        assert_eq!(Alarm2::every_minute(), Alarm2Config::EveryMinute);
+        assert_eq!(
+            Alarm2::hourly_at(15),
+            Alarm2Config::AtMinutes { minutes: 15 }
         );
-        let res_a2h6 = alarm2_h6.to_config();
-        assert!(
-            matches!(
-                res_a2h6,
-                Err(AlarmError::InvalidTime("Invalid 12-hour value"))
-            ),
-            "Alarm2 Hr12ValThirteen: {:?}",
-            res_a2h6
+        assert!(Alarm2::daily().at_hm(12, 0).validate().is_ok());
+        assert_eq!(
+            Alarm2::weekly(DayOfWeek::Sunday).at_hm(8, 0),
+            Alarm2Config::AtTimeOnDay {
+                hours: 8,
+                minutes: 0,
+                day: 1,
+                is_pm: None,
+            }
         );
+    }
 
-        // Invalid BCD day/date (ones > 9)
-        let mut daydate_a2d1 = AlarmDayDate(0x0A);
-        daydate_a2d1.set_alarm_mask4(false);
-        daydate_a2d1.set_day_date_select(DayDateSelect::Date);
-        let alarm2_d1 =
-            DS3231Alarm2::from_registers(default_minutes, default_hours_24h, daydate_a2d1);
-        let res_a2d1 = alarm2_d1.to_config();
-        assert!(
-            matches!(
-                res_a2d1,
-                Err(AlarmError::InvalidTime("Invalid BCD date value"))
-            ),
-            "Alarm2 DateOnes: {:?}",
-            res_a2d1
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_alarm_builders_chrono() {
+        const MONDAY: Alarm1Config = Alarm1::weekly(DayOfWeek::Monday).at_hms(7, 0, 0);
+        assert_eq!(MONDAY.weekday(), Some(Weekday::Mon));
+        let time = NaiveTime::from_hms_opt(18, 45, 30).unwrap();
+        assert_eq!(
+            Alarm1::weekly(Weekday::Sun.into()).at(time),
+            Alarm1Config::on_weekday(Weekday::Sun, time)
         );
-
-        // Invalid BCD day/date (tens > 3, e.g. 0x40 for date)
-        let mut daydate_a2d2 = AlarmDayDate(0x40);
-        daydate_a2d2.set_alarm_mask4(false);
-        daydate_a2d2.set_day_date_select(DayDateSelect::Date);
-        let alarm2_d2 =
-            DS3231Alarm2::from_registers(default_minutes, default_hours_24h, daydate_a2d2);
-        let res_a2d2 = alarm2_d2.to_config();
-        assert!(
-            matches!(res_a2d2, Err(AlarmError::InvalidTime("Invalid date value"))),
-            "Alarm2 DateTens (effectively date 0): {:?}",
-            res_a2d2
+        assert_eq!(Alarm1::daily().at(time), Alarm1Config::daily(time));
+        assert_eq!(
+            Alarm2::weekly(Weekday::Sat.into()).at(time),
+            Alarm2Config::AtTimeOnDay {
+                hours: 18,
+                minutes: 45,
+                day: 7,
+                is_pm: None,
+            }
         );
-
-        // Invalid date value (00)
-        let mut daydate_a2d3 = AlarmDayDate(0x00);
-        daydate_a2d3.set_alarm_mask4(false);
-        daydate_a2d3.set_day_date_select(DayDateSelect::Date);
-        let alarm2_d3 =
-            DS3231Alarm2::from_registers(default_minutes, default_hours_24h, daydate_a2d3);
-        let res_a2d3 = alarm2_d3.to_config();
-        assert!(
-            matches!(res_a2d3, Err(AlarmError::InvalidTime("Invalid date value"))),
-            "Alarm2 DateValZero: {:?}",
-            res_a2d3
+        assert_eq!(
+            Alarm2::monthly(15).at(time),
+            Alarm2Config::on_date(15, time)
         );
+    }
 
-        // Invalid date value (32 -> BCD 0x32)
-        let mut daydate_a2d4 = AlarmDayDate(0x32);
-        daydate_a2d4.set_alarm_mask4(false);
-        daydate_a2d4.set_day_date_select(DayDateSelect::Date);
-        let alarm2_d4 =
-            DS3231Alarm2::from_registers(default_minutes, default_hours_24h, daydate_a2d4);
-        let res_a2d4 = alarm2_d4.to_config();
-        assert!(
-            matches!(res_a2d4, Err(AlarmError::InvalidTime("Invalid date value"))),
-            "Alarm2 DateVal32: {:?}",
-            res_a2d4
-        );
+    #[test]
+    #[should_panic(expected = "hours must be 0-23")]
+    fn test_alarm_builder_rejects_hours() {
+        let _ = Alarm1::daily().at_hms(24, 0, 0);
     }
 
     #[test]
-    fn test_create_alarm_time_components_errors() {
-        // Invalid hour
-        assert!(matches!(
-            create_alarm_time_components(24, 0, None),
-            Err(AlarmError::DateTime(DS3231DateTimeError::InvalidDateTime))
-        ));
+    #[should_panic(expected = "date must be 1-31")]
+    fn test_alarm_builder_rejects_date() {
+        let _ = Alarm2::monthly(0);
+    }
 
-        // Invalid minute
-        assert!(matches!(
-            create_alarm_time_components(0, 60, None),
-            Err(AlarmError::DateTime(DS3231DateTimeError::InvalidDateTime))
-        ));
+    #[test]
+    fn test_alarm_in_time_representation() {
+        let twelve = TimeRepresentation::TwelveHour;
+        let twenty_four = TimeRepresentation::TwentyFourHour;
+        for (hours, twelve_hour) in [(0, (12, false)), (12, (12, true)), (23, (11, true))] {
+            let config = Alarm2Config::AtTime {
+                hours,
+                minutes: 0,
+                is_pm: None,
+            };
+            let converted = Alarm2Config::AtTime {
+                hours: twelve_hour.0,
+                minutes: 0,
+                is_pm: Some(twelve_hour.1),
+            };
+            assert_eq!(config.in_time_representation(twelve), converted);
+            assert_eq!(converted.in_time_representation(twenty_four), config);
+        }
+        // Invalid hours are left for validation
+        let invalid = Alarm1Config::AtTimeOnDay {
+            hours: 24,
+            minutes: 0,
+            seconds: 0,
+            day: 1,
+            is_pm: None,
+        };
+        assert_eq!(invalid.in_time_representation(twelve), invalid);
+        assert!(!Alarm1Config::EverySecond.uses_hours());
+        assert!(invalid.uses_hours());
     }
 
     #[test]
-    fn test_create_alarm_day_date_component_errors() {
-        // Invalid date
-        assert!(matches!(
-            create_alarm_day_date_component(32, false), // Date 32
-            Err(AlarmError::DateTime(DS3231DateTimeError::InvalidDateTime))
-        ));
+    fn test_alarm_weekday_numbering() {
+        let config = Alarm2Config::AtTimeOnDay {
+            hours: 9,
+            minutes: 0,
+            day: 1,
+            is_pm: None,
+        };
+        // Sunday is written as 7 with 1 = Monday
+        let alarm = DS3231Alarm2::encode(
+            &config,
+            WeekdayNumbering::MondayIsOne,
+            TimeRepresentation::TwentyFourHour,
+        )
+        .unwrap();
+        assert_eq!(alarm.day_date().day_or_date(), 7);
+        assert_eq!(alarm.decode(WeekdayNumbering::MondayIsOne), config);
+        // Other configurations are left alone
+        let config = Alarm2Config::AtMinutes { minutes: 5 };
+        let alarm = DS3231Alarm2::encode(
+            &config,
+            WeekdayNumbering::MondayIsOne,
+            TimeRepresentation::TwentyFourHour,
+        )
+        .unwrap();
+        assert_eq!(alarm.decode(WeekdayNumbering::MondayIsOne), config);
+        // A raw alarm that selects the day of the week reads Day 1 (Monday) as 2 and writes
+        // it back unchanged
+        let alarm =
+            DS3231Alarm2::from_registers(AlarmMinutes(0x80), AlarmHours(0x07), AlarmDayDate(0x41));
+        let config = alarm.decode(WeekdayNumbering::MondayIsOne);
+        let Alarm2Config::Raw(raw) = config else {
+            panic!("expected a raw alarm, got {config:?}");
+        };
+        assert_eq!(raw.day_date().day_or_date(), 2);
+        assert_eq!(
+            DS3231Alarm2::encode(
+                &config,
+                WeekdayNumbering::MondayIsOne,
+                TimeRepresentation::TwentyFourHour,
+            )
+            .unwrap(),
+            alarm
+        );
     }
 }
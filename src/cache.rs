@@ -0,0 +1,180 @@
+//! Shadow copies of the DS3231 configuration registers.
+//!
+//! The driver keeps a shadow of the registers that only change when they are written: the
+//! alarm registers (0x07-0x0D), the control register (0x0E) and the aging offset (0x10). Every
+//! write goes through to the device and updates the shadow. Whether reads are served from the
+//! shadow is selected with a [`CachePolicy`].
+//!
+//! The status register (0x0F) is not shadowed because the device sets its flags by itself, and
+//! the self-clearing `CONV` bit of the control register is never taken from the shadow.
+
+use crate::registers::RegAddr;
+
+/// Registers covered by the shadow (0x00-0x10), indexed by register address.
+const SHADOW_REGISTERS: usize = RegAddr::AgingOffset as usize + 1;
+
+/// Control register bits the device clears by itself (`CONV`).
+const CONTROL_VOLATILE: u8 = 0x20;
+
+/// How the driver uses its shadow copies of the configuration registers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CachePolicy {
+    /// Always read the registers from the device; writes still update the shadow
+    #[default]
+    AlwaysRead,
+    /// Serve reads of shadowed registers from the shadow once they have been read or written
+    TrustCache,
+    /// Like `TrustCache`, but discard the whole shadow after any bus error, as a failed
+    /// transfer may have left the device in an unknown state
+    InvalidateOnError,
+}
+
+/// Shadow register storage.
+#[derive(Debug, Default)]
+pub(crate) struct RegisterCache {
+    policy: CachePolicy,
+    registers: [u8; SHADOW_REGISTERS],
+    /// Bit `n` is set when `registers[n]` holds the device value
+    valid: u32,
+}
+
+impl RegisterCache {
+    pub(crate) fn policy(&self) -> CachePolicy {
+        self.policy
+    }
+
+    pub(crate) fn set_policy(&mut self, policy: CachePolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns `true` if `register` is shadowed.
+    fn is_shadowed(register: usize) -> bool {
+        (RegAddr::Alarm1Seconds as usize..=RegAddr::Control as usize).contains(&register)
+            || register == RegAddr::AgingOffset as usize
+    }
+
+    /// Fills `buffer` from the shadow if the policy allows it and all the registers are held.
+    pub(crate) fn read(&self, register: u8, buffer: &mut [u8]) -> bool {
+        if self.policy == CachePolicy::AlwaysRead {
+            return false;
+        }
+        let start = usize::from(register);
+        let held = (start..start + buffer.len())
+            .all(|r| Self::is_shadowed(r) && self.valid & (1 << r) != 0);
+        if held {
+            buffer.copy_from_slice(&self.registers[start..start + buffer.len()]);
+        }
+        held
+    }
+
+    /// Records the values of consecutive registers starting at `register` after a read or write.
+    pub(crate) fn update(&mut self, register: u8, data: &[u8]) {
+        let start = usize::from(register);
+        for (r, &value) in (start..).zip(data) {
+            if Self::is_shadowed(r) {
+                self.registers[r] = if r == RegAddr::Control as usize {
+                    value & !CONTROL_VOLATILE
+                } else {
+                    value
+                };
+                self.valid |= 1 << r;
+            }
+        }
+    }
+
+    /// Discards the shadow after a bus error if the policy asks for it.
+    ///
+    /// Returns `true` if the shadow was discarded.
+    pub(crate) fn on_error(&mut self) -> bool {
+        if self.policy == CachePolicy::InvalidateOnError {
+            self.invalidate();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Discards the whole shadow.
+    pub(crate) fn invalidate(&mut self) {
+        self.valid = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache(policy: CachePolicy) -> RegisterCache {
+        let mut cache = RegisterCache::default();
+        cache.set_policy(policy);
+        cache
+    }
+
+    #[test]
+    fn test_always_read_never_serves_reads() {
+        let mut cache = cache(CachePolicy::AlwaysRead);
+        cache.update(0x0E, &[0x1C]);
+        let mut data = [0];
+        assert!(!cache.read(0x0E, &mut data));
+    }
+
+    #[test]
+    fn test_trust_cache_serves_held_registers() {
+        let mut cache = cache(CachePolicy::TrustCache);
+        let mut data = [0; 4];
+        assert!(!cache.read(0x07, &mut data));
+
+        cache.update(0x07, &[0x30, 0x15, 0x09, 0x80]);
+        assert!(cache.read(0x07, &mut data));
+        assert_eq!(data, [0x30, 0x15, 0x09, 0x80]);
+
+        // A partly held range goes to the device
+        let mut data = [0; 5];
+        assert!(!cache.read(0x07, &mut data));
+    }
+
+    #[test]
+    fn test_unshadowed_registers() {
+        let mut cache = cache(CachePolicy::TrustCache);
+        cache.update(0x00, &[0x00; 0x11]);
+
+        let mut data = [0];
+        assert!(!cache.read(0x00, &mut data)); // seconds
+        assert!(!cache.read(0x0F, &mut data)); // status
+        assert!(cache.read(0x0E, &mut data)); // control
+        assert!(cache.read(0x10, &mut data)); // aging offset
+        assert!(!cache.read(0x11, &mut data)); // temperature
+    }
+
+    #[test]
+    fn test_control_conv_bit_not_shadowed() {
+        let mut cache = cache(CachePolicy::TrustCache);
+        cache.update(0x0E, &[0x3C]);
+        let mut data = [0];
+        assert!(cache.read(0x0E, &mut data));
+        assert_eq!(data, [0x1C]);
+    }
+
+    #[test]
+    fn test_on_error() {
+        let mut cache = cache(CachePolicy::TrustCache);
+        cache.update(0x0E, &[0x1C]);
+        assert!(!cache.on_error());
+        let mut data = [0];
+        assert!(cache.read(0x0E, &mut data));
+
+        cache.set_policy(CachePolicy::InvalidateOnError);
+        assert!(cache.on_error());
+        assert!(!cache.read(0x0E, &mut data));
+    }
+
+    #[test]
+    fn test_invalidate() {
+        let mut cache = cache(CachePolicy::TrustCache);
+        cache.update(0x10, &[0x05]);
+        cache.invalidate();
+        let mut data = [0];
+        assert!(!cache.read(0x10, &mut data));
+    }
+}
@@ -0,0 +1,317 @@
+//! Aging offset calibration from reference time samples.
+//!
+//! The aging offset register (0x10) trims the crystal frequency. At +25°C one LSB changes the
+//! frequency by about 0.1 ppm; positive values slow the oscillator down and negative values
+//! speed it up. A [`DriftEstimator`] collects pairs of (reference time, RTC time), for example
+//! from NTP or GPS over several days, estimates the drift of the RTC in ppm and recommends an
+//! aging offset that cancels it.
+//!
+//! The drift is the slope of a least-squares fit of the RTC error against the reference time,
+//! so samples do not need to be evenly spaced and a single late sample does not dominate. The
+//! RTC only counts whole seconds, so the samples should span days: over a week, one second
+//! of error is about 1.7 ppm.
+//!
+//! The estimator state is a fixed-size byte array ([`DriftEstimator::to_bytes`]) so it can be
+//! kept in flash, or in the SRAM of a DS3232/DS3234, across reboots.
+
+use chrono::NaiveDateTime;
+
+use crate::Celsius;
+
+/// Frequency change of one aging offset LSB at +25°C, in ppm.
+const PPM_PER_LSB: f64 = 0.1;
+
+/// Version of the serialized estimator state.
+const STATE_VERSION: u8 = 1;
+
+/// Drift estimator used to calibrate the aging offset.
+///
+/// ```rust,ignore
+/// let mut estimator = DriftEstimator::new();
+/// // Once in a while, right after getting the time from NTP or GPS:
+/// rtc.sample_drift(&reference, &mut estimator)?;
+/// // After a few days:
+/// if let Some(offset) = rtc.apply_aging_offset(&estimator)? {
+///     estimator.reset(); // the drift changed with the new offset
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DriftEstimator {
+    count: u32,
+    /// Reference time of the first sample, in milliseconds since the Unix epoch
+    origin_ms: i64,
+    /// Mean reference time since the origin, in seconds
+    mean_time: f64,
+    /// Mean RTC error (RTC - reference), in seconds
+    mean_error: f64,
+    /// Sum of squared deviations of the reference time
+    time_m2: f64,
+    /// Sum of the products of the reference time and RTC error deviations
+    covariance: f64,
+    /// Sum of the sampled temperatures, in quarter degrees Celsius
+    temperature_sum: i64,
+    temperature_count: u32,
+}
+
+impl DriftEstimator {
+    /// Size of the serialized state returned by [`to_bytes`](Self::to_bytes).
+    pub const SERIALIZED_SIZE: usize = 57;
+
+    /// Creates an estimator without samples.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            origin_ms: 0,
+            mean_time: 0.0,
+            mean_error: 0.0,
+            time_m2: 0.0,
+            covariance: 0.0,
+            temperature_sum: 0,
+            temperature_count: 0,
+        }
+    }
+
+    /// Discards all samples, e.g. after a new aging offset has been applied.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Adds a sample.
+    ///
+    /// # Arguments
+    /// * `reference` - The reference time, e.g. from NTP or GPS
+    /// * `rtc` - The RTC time read at the same moment
+    /// * `temperature` - The RTC temperature at that moment, if known
+    #[allow(clippy::cast_precision_loss)]
+    pub fn add_sample(
+        &mut self,
+        reference: &NaiveDateTime,
+        rtc: &NaiveDateTime,
+        temperature: Option<Celsius>,
+    ) {
+        let reference_ms = reference.and_utc().timestamp_millis();
+        if self.count == 0 {
+            self.origin_ms = reference_ms;
+        }
+        let time = (reference_ms - self.origin_ms) as f64 / 1000.0;
+        let error = (*rtc - *reference).num_milliseconds() as f64 / 1000.0;
+
+        // Welford's online update, which stays accurate over long sample spans
+        self.count += 1;
+        let count = f64::from(self.count);
+        let time_delta = time - self.mean_time;
+        self.mean_time += time_delta / count;
+        self.mean_error += (error - self.mean_error) / count;
+        self.time_m2 += time_delta * (time - self.mean_time);
+        self.covariance += time_delta * (error - self.mean_error);
+
+        if let Some(temperature) = temperature {
+            self.temperature_sum += i64::from(temperature.quarter_degrees());
+            self.temperature_count += 1;
+        }
+    }
+
+    /// Gets the number of samples.
+    #[must_use]
+    pub fn sample_count(&self) -> u32 {
+        self.count
+    }
+
+    /// Gets the estimated drift of the RTC in ppm (positive if the RTC runs fast).
+    ///
+    /// Returns `None` until there are samples at two different reference times.
+    #[must_use]
+    pub fn drift_ppm(&self) -> Option<f64> {
+        (self.count >= 2 && self.time_m2 > 0.0).then(|| self.covariance / self.time_m2 * 1e6)
+    }
+
+    /// Gets the mean temperature of the samples that had one.
+    #[must_use]
+    pub fn mean_temperature(&self) -> Option<Celsius> {
+        if self.temperature_count == 0 {
+            return None;
+        }
+        let mean = self.temperature_sum / i64::from(self.temperature_count);
+        i16::try_from(mean).ok().map(Celsius::from_quarter_degrees)
+    }
+
+    /// Recommends an aging offset that cancels the estimated drift.
+    ///
+    /// Uses the datasheet's 0.1 ppm per LSB, which holds at +25°C; the further the
+    /// [`mean_temperature`](Self::mean_temperature) is from that, the less exact the
+    /// correction. The result is saturated to the register range.
+    ///
+    /// # Arguments
+    /// * `current` - The aging offset in effect while the samples were taken
+    ///
+    /// # Returns
+    /// * `Some(i8)` - The recommended aging offset
+    /// * `None` if there is no drift estimate yet
+    #[must_use]
+    pub fn recommended_aging_offset(&self, current: i8) -> Option<i8> {
+        let steps = round(self.drift_ppm()? / PPM_PER_LSB);
+        let offset = i32::from(current) + steps;
+        Some(i8::try_from(offset).unwrap_or(if offset < 0 { i8::MIN } else { i8::MAX }))
+    }
+
+    /// Serializes the estimator state.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; Self::SERIALIZED_SIZE] {
+        let mut bytes = [0; Self::SERIALIZED_SIZE];
+        bytes[0] = STATE_VERSION;
+        bytes[1..5].copy_from_slice(&self.count.to_le_bytes());
+        bytes[5..13].copy_from_slice(&self.origin_ms.to_le_bytes());
+        bytes[13..21].copy_from_slice(&self.mean_time.to_le_bytes());
+        bytes[21..29].copy_from_slice(&self.mean_error.to_le_bytes());
+        bytes[29..37].copy_from_slice(&self.time_m2.to_le_bytes());
+        bytes[37..45].copy_from_slice(&self.covariance.to_le_bytes());
+        bytes[45..53].copy_from_slice(&self.temperature_sum.to_le_bytes());
+        bytes[53..57].copy_from_slice(&self.temperature_count.to_le_bytes());
+        bytes
+    }
+
+    /// Restores an estimator state serialized with [`to_bytes`](Self::to_bytes).
+    ///
+    /// Returns `None` if the bytes do not hold a valid state (e.g. erased storage).
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8; Self::SERIALIZED_SIZE]) -> Option<Self> {
+        if bytes[0] != STATE_VERSION {
+            return None;
+        }
+        let u32_at =
+            |i: usize| u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+        let bytes8 = |i: usize| {
+            let mut value = [0; 8];
+            value.copy_from_slice(&bytes[i..i + 8]);
+            value
+        };
+        let state = Self {
+            count: u32_at(1),
+            origin_ms: i64::from_le_bytes(bytes8(5)),
+            mean_time: f64::from_le_bytes(bytes8(13)),
+            mean_error: f64::from_le_bytes(bytes8(21)),
+            time_m2: f64::from_le_bytes(bytes8(29)),
+            covariance: f64::from_le_bytes(bytes8(37)),
+            temperature_sum: i64::from_le_bytes(bytes8(45)),
+            temperature_count: u32_at(53),
+        };
+        let finite = [
+            state.mean_time,
+            state.mean_error,
+            state.time_m2,
+            state.covariance,
+        ]
+        .iter()
+        .all(|value| value.is_finite());
+        (finite && state.temperature_count <= state.count).then_some(state)
+    }
+}
+
+/// Rounds to the nearest integer, away from zero on ties, saturating at the `i32` range.
+#[allow(clippy::cast_possible_truncation)]
+fn round(value: f64) -> i32 {
+    if value < 0.0 {
+        (value - 0.5) as i32
+    } else {
+        (value + 0.5) as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeDelta};
+
+    fn reference(days: i64) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            + TimeDelta::days(days)
+    }
+
+    /// Builds an estimator for an RTC that gains `ppm` and starts `start_ms` ahead.
+    fn estimator(ppm: f64, start_ms: i64) -> DriftEstimator {
+        let mut estimator = DriftEstimator::new();
+        for day in 0..10 {
+            let reference = reference(day);
+            #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+            let error_ms = start_ms + (ppm * 86_400.0 * 1e-3 * day as f64) as i64;
+            let rtc = reference + TimeDelta::milliseconds(error_ms);
+            estimator.add_sample(&reference, &rtc, Some(Celsius::from_quarter_degrees(100)));
+        }
+        estimator
+    }
+
+    #[test]
+    fn test_no_estimate_without_span() {
+        let mut estimator = DriftEstimator::new();
+        assert_eq!(estimator.drift_ppm(), None);
+        assert_eq!(estimator.recommended_aging_offset(0), None);
+        estimator.add_sample(&reference(0), &reference(0), None);
+        estimator.add_sample(&reference(0), &reference(0), None);
+        assert_eq!(estimator.sample_count(), 2);
+        assert_eq!(estimator.drift_ppm(), None);
+    }
+
+    #[test]
+    fn test_drift_estimate() {
+        let fast = estimator(2.0, 1500);
+        assert!((fast.drift_ppm().unwrap() - 2.0).abs() < 0.01);
+        assert_eq!(fast.recommended_aging_offset(0), Some(20));
+        assert_eq!(fast.recommended_aging_offset(-5), Some(15));
+
+        let slow = estimator(-1.23, -300);
+        assert!((slow.drift_ppm().unwrap() + 1.23).abs() < 0.01);
+        assert_eq!(slow.recommended_aging_offset(10), Some(-2));
+    }
+
+    #[test]
+    fn test_recommendation_saturates() {
+        let estimator = estimator(20.0, 0);
+        assert_eq!(estimator.recommended_aging_offset(100), Some(i8::MAX));
+        let estimator = self::estimator(-20.0, 0);
+        assert_eq!(estimator.recommended_aging_offset(-100), Some(i8::MIN));
+    }
+
+    #[test]
+    fn test_mean_temperature() {
+        let mut estimator = DriftEstimator::new();
+        assert_eq!(estimator.mean_temperature(), None);
+        estimator.add_sample(
+            &reference(0),
+            &reference(0),
+            Some(Celsius::from_quarter_degrees(100)),
+        );
+        estimator.add_sample(&reference(1), &reference(1), None);
+        estimator.add_sample(
+            &reference(2),
+            &reference(2),
+            Some(Celsius::from_quarter_degrees(104)),
+        );
+        assert_eq!(
+            estimator.mean_temperature(),
+            Some(Celsius::from_quarter_degrees(102))
+        );
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let estimator = estimator(1.5, 250);
+        let restored = DriftEstimator::from_bytes(&estimator.to_bytes()).unwrap();
+        assert_eq!(restored, estimator);
+
+        assert_eq!(
+            DriftEstimator::from_bytes(&[0xFF; DriftEstimator::SERIALIZED_SIZE]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut estimator = estimator(1.5, 250);
+        estimator.reset();
+        assert_eq!(estimator, DriftEstimator::new());
+    }
+}
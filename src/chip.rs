@@ -0,0 +1,74 @@
+//! Chip variants supported by the driver.
+//!
+//! The DS3231 family shares the same timekeeping, alarm, control and temperature registers.
+//! The driver is parameterised over a [`Chip`] marker type so that variant specific features,
+//! such as the battery-backed SRAM of the DS3232, are only available on the matching chip.
+//!
+//! - [`DS3231Chip`] - The DS3231 (default)
+//! - [`DS3232Chip`] - The DS3232, adding 236 bytes of SRAM and extra control/status bits
+//! - [`DS3234Chip`] - The DS3234, an SPI part with 256 bytes of SRAM and the DS3232 extras
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A chip variant of the DS3231 family.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Chip: private::Sealed {
+    /// Size of the battery-backed SRAM in bytes (0 if the chip has none)
+    const SRAM_SIZE: usize;
+}
+
+/// A chip variant with battery-backed SRAM and the temperature control register.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait SramChip: Chip {
+    /// Whether the SRAM is reached through the SRAM address/data registers (0x18/0x19) rather
+    /// than being mapped into the register space
+    const INDIRECT_SRAM: bool;
+}
+
+/// Marker type for the DS3231.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DS3231Chip;
+
+impl private::Sealed for DS3231Chip {}
+impl Chip for DS3231Chip {
+    const SRAM_SIZE: usize = 0;
+}
+
+/// Marker type for the DS3232.
+///
+/// The DS3232 adds 236 bytes of battery-backed SRAM at 0x14-0xFF, the temperature control
+/// register (`BB_TD`) and the `BB32kHz` and `CRATE1`/`CRATE0` bits in the status register.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DS3232Chip;
+
+impl private::Sealed for DS3232Chip {}
+impl Chip for DS3232Chip {
+    const SRAM_SIZE: usize = 236;
+}
+
+impl SramChip for DS3232Chip {
+    const INDIRECT_SRAM: bool = false;
+}
+
+/// Marker type for the DS3234.
+///
+/// The DS3234 is the SPI version of the DS3232. Its 256 bytes of battery-backed SRAM are
+/// accessed through the SRAM address (0x18) and SRAM data (0x19) registers.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DS3234Chip;
+
+impl private::Sealed for DS3234Chip {}
+impl Chip for DS3234Chip {
+    const SRAM_SIZE: usize = 256;
+}
+
+impl SramChip for DS3234Chip {
+    const INDIRECT_SRAM: bool = true;
+}
@@ -1,11 +1,14 @@
 //! `DateTime` conversion and register utilities for the DS3231 RTC.
 //!
 //! This module provides the internal representation and conversion logic for the DS3231's date and time registers.
-//! It enables safe, validated conversion between the DS3231's BCD-encoded registers and chrono's `NaiveDateTime`.
+//! It enables safe, validated conversion between the DS3231's BCD-encoded registers and the
+//! supported date/time types. Every conversion goes through the same BCD encoding and validation.
 //!
 //! # Features
 //!
-//! - Conversion to/from chrono `NaiveDateTime`
+//! - Conversion to/from chrono `NaiveDateTime` (`chrono` feature)
+//! - Conversion to/from `time` `PrimitiveDateTime` (`time` feature)
+//! - Conversion to/from Unix timestamps, without either crate
 //! - Error handling for invalid or out-of-range values
 //!
 //! # Register Model
@@ -17,14 +20,166 @@
 //!
 //! Conversion errors are reported via [`DS3231DateTimeError`].
 
-use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use core::fmt;
+
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike, Weekday};
+#[cfg(feature = "time")]
+use time::PrimitiveDateTime;
 
 use crate::{Date, Day, Hours, Minutes, Month, Seconds, TimeRepresentation, Year};
 
+/// Seconds in a day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Unix timestamp of 2000-01-01T00:00:00, the start of the device's range.
+pub(crate) const Y2K_TIMESTAMP: i64 = 946_684_800;
+
+/// How the Day register numbers the days of the week.
+///
+/// The DS3231 advances the Day register at midnight, wrapping from 7 to 1, and leaves the
+/// meaning of each value to the application. The driver writes the Day register and the
+/// day-of-week alarms in the numbering set with `set_weekday_numbering`. The `day` of an alarm
+/// configuration always counts from 1 = Sunday and is converted when the alarm is written or
+/// read.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WeekdayNumbering {
+    /// 1 = Sunday to 7 = Saturday
+    #[default]
+    SundayIsOne,
+    /// 1 = Monday to 7 = Sunday
+    MondayIsOne,
+}
+
+impl WeekdayNumbering {
+    /// The ISO 8601 numbering, 1 = Monday to 7 = Sunday.
+    pub const ISO: Self = Self::MondayIsOne;
+
+    /// Converts a day of the week counted from 1 = Sunday to a Day register value.
+    ///
+    /// Values outside 1-7 are returned unchanged so that validation still rejects them.
+    pub(crate) const fn encode_day(self, day: u8) -> u8 {
+        match self {
+            Self::MondayIsOne if matches!(day, 1..=7) => (day + 5) % 7 + 1,
+            _ => day,
+        }
+    }
+
+    /// Converts a Day register value to a day of the week counted from 1 = Sunday.
+    ///
+    /// Values outside 1-7 are returned unchanged so that validation still rejects them.
+    pub(crate) const fn decode_day(self, value: u8) -> u8 {
+        match self {
+            Self::MondayIsOne if matches!(value, 1..=7) => value % 7 + 1,
+            _ => value,
+        }
+    }
+
+    /// Gets the Day register value (1-7) of a day of the week.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub const fn number(self, weekday: Weekday) -> u8 {
+        let days = match self {
+            Self::SundayIsOne => weekday.num_days_from_sunday(),
+            Self::MondayIsOne => weekday.num_days_from_monday(),
+        };
+        (days % 7) as u8 + 1
+    }
+
+    /// Gets the day of the week of a Day register value, or `None` if it is not 1-7.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn weekday(self, number: u8) -> Option<Weekday> {
+        if !(1..=7).contains(&number) {
+            return None;
+        }
+        let from_monday = match self {
+            Self::SundayIsOne => (number + 5) % 7,
+            Self::MondayIsOne => number - 1,
+        };
+        Weekday::try_from(from_monday).ok()
+    }
+}
+
+/// A day of the week, available without chrono.
+///
+/// [`RtcDateTime::weekday`] returns it. [`number`](Self::number) gives the Day register value
+/// in a `WeekdayNumbering`, the same 1-7 values that the driver writes to the Day register and
+/// to day-of-week alarms.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DayOfWeek {
+    /// Sunday
+    Sunday,
+    /// Monday
+    Monday,
+    /// Tuesday
+    Tuesday,
+    /// Wednesday
+    Wednesday,
+    /// Thursday
+    Thursday,
+    /// Friday
+    Friday,
+    /// Saturday
+    Saturday,
+}
+
+impl DayOfWeek {
+    /// The days from Sunday to Saturday.
+    const ALL: [Self; 7] = [
+        Self::Sunday,
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+    ];
+
+    /// Gets the Day register value (1-7) of the day in `numbering`.
+    #[must_use]
+    pub const fn number(self, numbering: WeekdayNumbering) -> u8 {
+        numbering.encode_day(self as u8 + 1)
+    }
+
+    /// Gets the day of a Day register value in `numbering`, or `None` if it is not 1-7.
+    #[must_use]
+    pub const fn from_number(number: u8, numbering: WeekdayNumbering) -> Option<Self> {
+        match numbering.decode_day(number) {
+            day @ 1..=7 => Some(Self::ALL[day as usize - 1]),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<DayOfWeek> for Weekday {
+    fn from(day: DayOfWeek) -> Self {
+        match day {
+            DayOfWeek::Sunday => Weekday::Sun,
+            DayOfWeek::Monday => Weekday::Mon,
+            DayOfWeek::Tuesday => Weekday::Tue,
+            DayOfWeek::Wednesday => Weekday::Wed,
+            DayOfWeek::Thursday => Weekday::Thu,
+            DayOfWeek::Friday => Weekday::Fri,
+            DayOfWeek::Saturday => Weekday::Sat,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Weekday> for DayOfWeek {
+    fn from(weekday: Weekday) -> Self {
+        DayOfWeek::ALL[weekday.num_days_from_sunday() as usize]
+    }
+}
+
 /// Internal representation of the DS3231 RTC date and time.
 ///
 /// This struct models the 7 date/time registers of the DS3231, using strongly-typed bitfield wrappers for each field.
-/// It is used for register-level I/O and conversion to/from chrono's `NaiveDateTime`.
+/// It is used for register-level I/O and conversion to/from the supported date/time types.
 ///
 /// Values are always validated and encoded/decoded as BCD.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -102,8 +257,47 @@ impl DS3231DateTime {
         Ok(value)
     }
 
+    /// Decodes an hours register (the clock's or an alarm's) to 0-23.
+    ///
+    /// Returns `None` if the register does not hold a valid hour. Bit 7, the alarm mask bit,
+    /// is ignored.
+    pub(crate) fn decode_hour(value: u8) -> Option<u32> {
+        let hours = Hours(value & 0x7F);
+        let ones = u32::from(hours.hours());
+        let tens = u32::from(hours.ten_hours());
+        let high = u32::from(hours.pm_or_twenty_hours());
+        if ones > 9 {
+            return None;
+        }
+        match hours.time_representation() {
+            TimeRepresentation::TwentyFourHour => {
+                Some(20 * high + 10 * tens + ones).filter(|h| *h <= 23)
+            }
+            TimeRepresentation::TwelveHour => match 10 * tens + ones {
+                hour @ 1..=12 => Some(hour % 12 + 12 * high),
+                _ => None,
+            },
+        }
+    }
+
+    /// Re-encodes an hours register (the clock's or an alarm's) in `time_representation`.
+    ///
+    /// Bit 7, the alarm mask bit, is kept. A register that does not hold a valid hour only has
+    /// its 12/24 hour bit changed.
+    pub(crate) fn convert_hours_register(value: u8, time_representation: TimeRepresentation) -> u8 {
+        let hours = Self::decode_hour(value)
+            .and_then(|hour| Self::convert_hours(hour, time_representation).ok());
+        if let Some(hours) = hours {
+            (value & 0x80) | u8::from(hours)
+        } else {
+            let mut hours = Hours(value);
+            hours.set_time_representation(time_representation);
+            hours.0
+        }
+    }
+
     fn convert_day(weekday: u32) -> Result<Day, DS3231DateTimeError> {
-        if weekday > 6 {
+        if !(1..=7).contains(&weekday) {
             return Err(DS3231DateTimeError::InvalidDateTime);
         }
         let mut value = Day::default();
@@ -154,17 +348,81 @@ impl DS3231DateTime {
         Ok((value, century))
     }
 
+    #[cfg(feature = "chrono")]
     pub(crate) fn from_datetime(
         datetime: &NaiveDateTime,
         time_representation: TimeRepresentation,
     ) -> Result<Self, DS3231DateTimeError> {
-        let seconds = Self::convert_seconds(datetime.second())?;
-        let minutes = Self::convert_minutes(datetime.minute())?;
-        let hours = Self::convert_hours(datetime.hour(), time_representation)?;
-        let day = Self::convert_day(datetime.weekday().num_days_from_sunday())?;
-        let date = Self::convert_date(datetime.day())?;
-        let mut month = Self::convert_month(datetime.month())?;
-        let (year, century) = Self::convert_year(datetime.year())?;
+        Self::from_fields(
+            datetime.year(),
+            datetime.month(),
+            datetime.day(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second(),
+            time_representation,
+        )
+    }
+
+    #[cfg(feature = "time")]
+    pub(crate) fn from_primitive_datetime(
+        datetime: &PrimitiveDateTime,
+        time_representation: TimeRepresentation,
+    ) -> Result<Self, DS3231DateTimeError> {
+        Self::from_fields(
+            datetime.year(),
+            u32::from(u8::from(datetime.month())),
+            u32::from(datetime.day()),
+            u32::from(datetime.hour()),
+            u32::from(datetime.minute()),
+            u32::from(datetime.second()),
+            time_representation,
+        )
+    }
+
+    pub(crate) fn from_rtc_datetime(
+        datetime: RtcDateTime,
+        time_representation: TimeRepresentation,
+    ) -> Result<Self, DS3231DateTimeError> {
+        Self::from_fields(
+            i32::from(datetime.year),
+            u32::from(datetime.month),
+            u32::from(datetime.day),
+            u32::from(datetime.hour),
+            u32::from(datetime.minute),
+            u32::from(datetime.second),
+            time_representation,
+        )
+    }
+
+    /// Converts a Unix timestamp (seconds since 1970-01-01T00:00:00).
+    pub(crate) fn from_timestamp(
+        timestamp: i64,
+        time_representation: TimeRepresentation,
+    ) -> Result<Self, DS3231DateTimeError> {
+        Self::from_rtc_datetime(RtcDateTime::from_timestamp(timestamp)?, time_representation)
+    }
+
+    /// Encodes a date and time; the date must be a valid calendar date.
+    fn from_fields(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        time_representation: TimeRepresentation,
+    ) -> Result<Self, DS3231DateTimeError> {
+        let seconds = Self::convert_seconds(second)?;
+        let minutes = Self::convert_minutes(minute)?;
+        let hours = Self::convert_hours(hour, time_representation)?;
+        let (year_register, century) = Self::convert_year(year)?;
+        // Day register 1 is Sunday; the driver renumbers it with `with_weekday_numbering`
+        let weekday = weekday(days_from_civil(i64::from(year), month, day));
+        let day_register =
+            Self::convert_day(u32::from(weekday.number(WeekdayNumbering::SundayIsOne)))?;
+        let date = Self::convert_date(day)?;
+        let mut month = Self::convert_month(month)?;
 
         if century {
             month.set_century(true);
@@ -174,10 +432,10 @@ impl DS3231DateTime {
             seconds,
             minutes,
             hours,
-            day,
+            day: day_register,
             date,
             month,
-            year,
+            year: year_register,
         };
 
         debug!("raw={:?}", raw);
@@ -185,7 +443,14 @@ impl DS3231DateTime {
         Ok(raw)
     }
 
-    pub(crate) fn into_datetime(self) -> Result<NaiveDateTime, DS3231DateTimeError> {
+    /// Renumbers the Day register, which is encoded with 1 = Sunday.
+    pub(crate) fn with_weekday_numbering(mut self, numbering: WeekdayNumbering) -> Self {
+        self.day.set_day(numbering.encode_day(self.day.day()));
+        self
+    }
+
+    /// Decodes the registers into (year, month, day, hour, minute, second) without validation.
+    fn fields(self) -> (i32, u32, u32, u32, u32, u32) {
         let seconds: u32 =
             10 * u32::from(self.seconds.ten_seconds()) + u32::from(self.seconds.seconds());
         let minutes =
@@ -215,11 +480,299 @@ impl DS3231DateTime {
         let year = 2000_i32 + i32::try_from(year_offset + century_offset).unwrap();
         let month = 10 * u32::from(self.month.ten_month()) + u32::from(self.month.month());
         let date = 10 * u32::from(self.date.ten_date()) + u32::from(self.date.date());
+        (year, month, date, hours, minutes, seconds)
+    }
+
+    /// Decodes and validates the registers.
+    pub(crate) fn into_rtc_datetime(self) -> Result<RtcDateTime, DS3231DateTimeError> {
+        let (year, month, date, hours, minutes, seconds) = self.fields();
+        let narrow =
+            |value: u32| u8::try_from(value).map_err(|_| DS3231DateTimeError::InvalidDateTime);
+        RtcDateTime::new(
+            u16::try_from(year).map_err(|_| DS3231DateTimeError::InvalidDateTime)?,
+            narrow(month)?,
+            narrow(date)?,
+            narrow(hours)?,
+            narrow(minutes)?,
+            narrow(seconds)?,
+        )
+        // The registers cannot hold a year outside the device range
+        .map_err(|_| DS3231DateTimeError::InvalidDateTime)
+    }
+
+    #[cfg(feature = "chrono")]
+    pub(crate) fn into_datetime(self) -> Result<NaiveDateTime, DS3231DateTimeError> {
+        self.into_rtc_datetime().map(NaiveDateTime::from)
+    }
+
+    #[cfg(feature = "time")]
+    pub(crate) fn into_primitive_datetime(self) -> Result<PrimitiveDateTime, DS3231DateTimeError> {
+        self.into_rtc_datetime().map(PrimitiveDateTime::from)
+    }
+
+    /// Converts to a Unix timestamp (seconds since 1970-01-01T00:00:00).
+    pub(crate) fn into_timestamp(self) -> Result<i64, DS3231DateTimeError> {
+        self.into_rtc_datetime()
+            .map(|datetime| datetime.timestamp())
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+///
+/// Howard Hinnant's `days_from_civil`, computed in 400-year eras starting in March.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Day of the week of a number of days since 1970-01-01, which was a Thursday.
+fn weekday(days: i64) -> DayOfWeek {
+    DayOfWeek::ALL[usize::try_from((days + 4).rem_euclid(7)).unwrap_or_default()]
+}
+
+/// (hour, minute, second) of a Unix timestamp.
+fn time_of_day(timestamp: i64) -> (u8, u8, u8) {
+    let seconds = timestamp.rem_euclid(SECONDS_PER_DAY);
+    let narrow = |value: i64| u8::try_from(value).unwrap();
+    (
+        narrow(seconds / 3600),
+        narrow(seconds / 60 % 60),
+        narrow(seconds % 60),
+    )
+}
+
+/// Proleptic Gregorian (year, month, day) of a number of days since 1970-01-01.
+///
+/// The inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = u8::try_from(day_of_year - (153 * month_from_march + 2) / 5 + 1).unwrap();
+    let month = u8::try_from((month_from_march + 2) % 12 + 1).unwrap();
+    let year = era * 400 + year_of_era + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// A date and time within the DS3231 range, 2000-01-01 00:00:00 to 2199-12-31 23:59:59.
+///
+/// `RtcDateTime` is always valid and needs neither chrono nor the `time` crate, which keeps
+/// the driver small on targets such as bootloaders. It is returned by `datetime_raw` and
+/// converts to chrono's `NaiveDateTime` and the `time` crate's `PrimitiveDateTime` when those
+/// features are enabled. Displays as `2024-03-14 15:30:00`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RtcDateTime {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    /// Derived from the date, so it never decides the ordering
+    weekday: DayOfWeek,
+}
+
+impl RtcDateTime {
+    /// Creates a date and time, computing the day of the week.
+    ///
+    /// # Arguments
+    /// * `year` - 2000 to 2199
+    /// * `month` - 1 to 12
+    /// * `day` - 1 to the length of the month
+    /// * `hour` - 0 to 23
+    /// * `minute`, `second` - 0 to 59
+    ///
+    /// # Errors
+    /// * `DS3231DateTimeError::YearNotAfter1999` or `YearNotBefore2200` if the year is outside
+    ///   the device range
+    /// * `DS3231DateTimeError::InvalidDateTime` if any other field is out of range
+    pub fn new(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Self, DS3231DateTimeError> {
+        if year < 2000 {
+            return Err(DS3231DateTimeError::YearNotAfter1999);
+        }
+        if year > 2199 {
+            return Err(DS3231DateTimeError::YearNotBefore2200);
+        }
+        let valid = (1..=12).contains(&month)
+            && (1..=days_in_month(i32::from(year), u32::from(month))).contains(&u32::from(day))
+            && hour < 24
+            && minute < 60
+            && second < 60;
+        if !valid {
+            return Err(DS3231DateTimeError::InvalidDateTime);
+        }
+        let weekday = weekday(days_from_civil(
+            i64::from(year),
+            u32::from(month),
+            u32::from(day),
+        ));
+        Ok(Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            weekday,
+        })
+    }
+
+    /// Creates a date and time from a Unix timestamp (seconds since 1970-01-01T00:00:00).
+    ///
+    /// # Errors
+    /// * `DS3231DateTimeError::YearNotAfter1999` or `YearNotBefore2200` if the timestamp is
+    ///   outside the device range
+    pub fn from_timestamp(timestamp: i64) -> Result<Self, DS3231DateTimeError> {
+        let (year, month, day) = civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY));
+        let year = match u16::try_from(year) {
+            Ok(year) => year,
+            Err(_) if year < 0 => return Err(DS3231DateTimeError::YearNotAfter1999),
+            Err(_) => return Err(DS3231DateTimeError::YearNotBefore2200),
+        };
+        let (hour, minute, second) = time_of_day(timestamp);
+        Self::new(year, month, day, hour, minute, second)
+    }
+
+    /// Gets the Unix timestamp (seconds since 1970-01-01T00:00:00).
+    #[must_use]
+    pub fn timestamp(&self) -> i64 {
+        let days = days_from_civil(
+            i64::from(self.year),
+            u32::from(self.month),
+            u32::from(self.day),
+        );
+        let seconds =
+            u32::from(self.hour) * 3600 + u32::from(self.minute) * 60 + u32::from(self.second);
+        days * SECONDS_PER_DAY + i64::from(seconds)
+    }
+
+    /// Gets the year (2000-2199).
+    #[must_use]
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Gets the month (1-12).
+    #[must_use]
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Gets the day of the month (1-31).
+    #[must_use]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Gets the day of the week, computed from the date. `set_datetime_raw` writes it to the
+    /// Day register as `weekday().number(numbering)` with the driver's `WeekdayNumbering`.
+    #[must_use]
+    pub fn weekday(&self) -> DayOfWeek {
+        self.weekday
+    }
+
+    /// Gets the hour (0-23).
+    #[must_use]
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
 
-        // Validate the date components before creating NaiveDateTime
-        NaiveDate::from_ymd_opt(year, month, date)
-            .and_then(|d| d.and_hms_opt(hours, minutes, seconds))
-            .ok_or(DS3231DateTimeError::InvalidDateTime)
+    /// Gets the minute (0-59).
+    #[must_use]
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Gets the second (0-59).
+    #[must_use]
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+}
+
+impl fmt::Display for RtcDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for RtcDateTime {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "{}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second
+        );
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<RtcDateTime> for NaiveDateTime {
+    fn from(datetime: RtcDateTime) -> Self {
+        NaiveDate::from_ymd_opt(
+            i32::from(datetime.year),
+            u32::from(datetime.month),
+            u32::from(datetime.day),
+        )
+        .and_then(|d| {
+            d.and_hms_opt(
+                u32::from(datetime.hour),
+                u32::from(datetime.minute),
+                u32::from(datetime.second),
+            )
+        })
+        .expect("RtcDateTime is always valid")
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<RtcDateTime> for PrimitiveDateTime {
+    fn from(datetime: RtcDateTime) -> Self {
+        let month = time::Month::try_from(datetime.month).expect("RtcDateTime is always valid");
+        let date = time::Date::from_calendar_date(i32::from(datetime.year), month, datetime.day)
+            .expect("RtcDateTime is always valid");
+        let time = time::Time::from_hms(datetime.hour, datetime.minute, datetime.second)
+            .expect("RtcDateTime is always valid");
+        PrimitiveDateTime::new(date, time)
     }
 }
 
@@ -255,22 +808,10 @@ impl From<&DS3231DateTime> for [u8; 7] {
 impl defmt::Format for DS3231DateTime {
     fn format(&self, f: defmt::Formatter) {
         // Convert to readable datetime format for display
-        match self.into_datetime() {
-            Ok(dt) => {
-                defmt::write!(
-                    f,
-                    "DS3231DateTime({}-{:02}-{:02} {:02}:{:02}:{:02})",
-                    dt.year(),
-                    dt.month(),
-                    dt.day(),
-                    dt.hour(),
-                    dt.minute(),
-                    dt.second()
-                );
-            }
-            Err(_) => {
-                defmt::write!(f, "DS3231DateTime(invalid)");
-            }
+        if let Ok(dt) = self.into_rtc_datetime() {
+            defmt::write!(f, "DS3231DateTime({})", dt);
+        } else {
+            defmt::write!(f, "DS3231DateTime(invalid)");
         }
     }
 }
@@ -284,11 +825,15 @@ pub enum DS3231DateTimeError {
     YearNotBefore2200,
     /// The year is not after 1999 (DS3231 only supports years >= 2000)
     YearNotAfter1999,
+    /// The date/time does not fit in the requested timestamp type (e.g. 32-bit seconds since
+    /// 2000 end in 2136)
+    TimestampOverflow,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "chrono")]
     use chrono::NaiveDate;
 
     #[test]
@@ -322,6 +867,7 @@ mod tests {
         ));
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_from_datetime_and_into_datetime_roundtrip() {
         let dt = NaiveDate::from_ymd_opt(2024, 3, 14)
@@ -333,6 +879,7 @@ mod tests {
         core::assert_eq!(dt, dt2);
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_from_datetime_century_flag() {
         let dt = NaiveDate::from_ymd_opt(2099, 12, 31)
@@ -350,6 +897,7 @@ mod tests {
         assert_eq!(raw2.month.century(), true);
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_from_datetime_year_too_early() {
         let dt = NaiveDate::from_ymd_opt(1999, 12, 31)
@@ -361,6 +909,7 @@ mod tests {
         assert!(matches!(err, DS3231DateTimeError::YearNotAfter1999));
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_from_datetime_year_too_late() {
         let dt = NaiveDate::from_ymd_opt(2200, 1, 1)
@@ -372,6 +921,7 @@ mod tests {
         assert!(matches!(err, DS3231DateTimeError::YearNotBefore2200));
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_from_and_into_bcd_array() {
         let dt = NaiveDate::from_ymd_opt(2024, 3, 14)
@@ -385,6 +935,216 @@ mod tests {
         core::assert_eq!(dt, dt2);
     }
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_timestamp_matches_chrono() {
+        let mut timestamp = Y2K_TIMESTAMP;
+        while timestamp < 7_258_118_400 {
+            let expected = chrono::DateTime::from_timestamp(timestamp, 0)
+                .unwrap()
+                .naive_utc();
+            for repr in [
+                TimeRepresentation::TwentyFourHour,
+                TimeRepresentation::TwelveHour,
+            ] {
+                let raw = DS3231DateTime::from_timestamp(timestamp, repr).unwrap();
+                assert_eq!(raw, DS3231DateTime::from_datetime(&expected, repr).unwrap());
+                assert_eq!(raw.into_timestamp().unwrap(), timestamp);
+            }
+            timestamp += 3_456_789;
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_primitive_datetime_matches_timestamp() {
+        let mut timestamp = Y2K_TIMESTAMP;
+        while timestamp < 7_258_118_400 {
+            let utc = time::OffsetDateTime::from_unix_timestamp(timestamp).unwrap();
+            let expected = PrimitiveDateTime::new(utc.date(), utc.time());
+            for repr in [
+                TimeRepresentation::TwentyFourHour,
+                TimeRepresentation::TwelveHour,
+            ] {
+                let raw = DS3231DateTime::from_primitive_datetime(&expected, repr).unwrap();
+                assert_eq!(
+                    raw,
+                    DS3231DateTime::from_timestamp(timestamp, repr).unwrap()
+                );
+                assert_eq!(raw.into_primitive_datetime().unwrap(), expected);
+            }
+            timestamp += 3_456_789;
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_primitive_datetime_errors() {
+        let datetime = PrimitiveDateTime::new(
+            time::Date::from_calendar_date(1999, time::Month::December, 31).unwrap(),
+            time::Time::MIDNIGHT,
+        );
+        assert!(matches!(
+            DS3231DateTime::from_primitive_datetime(&datetime, TimeRepresentation::TwentyFourHour),
+            Err(DS3231DateTimeError::YearNotAfter1999)
+        ));
+
+        // 2023-02-29 does not exist
+        let raw = DS3231DateTime::from([0x00, 0x00, 0x00, 0x03, 0x29, 0x02, 0x23]);
+        assert!(matches!(
+            raw.into_primitive_datetime(),
+            Err(DS3231DateTimeError::InvalidDateTime)
+        ));
+    }
+
+    #[test]
+    fn test_rtc_datetime_validation() {
+        let datetime = RtcDateTime::new(2024, 2, 29, 23, 59, 59).unwrap();
+        assert_eq!(
+            (datetime.year(), datetime.month(), datetime.day()),
+            (2024, 2, 29)
+        );
+        assert_eq!(
+            (datetime.hour(), datetime.minute(), datetime.second()),
+            (23, 59, 59)
+        );
+        assert_eq!(datetime.weekday(), DayOfWeek::Thursday);
+
+        assert!(matches!(
+            RtcDateTime::new(1999, 12, 31, 0, 0, 0),
+            Err(DS3231DateTimeError::YearNotAfter1999)
+        ));
+        assert!(matches!(
+            RtcDateTime::new(2200, 1, 1, 0, 0, 0),
+            Err(DS3231DateTimeError::YearNotBefore2200)
+        ));
+        for (month, day, hour, minute, second) in [
+            (2, 30, 0, 0, 0),
+            (0, 1, 0, 0, 0),
+            (13, 1, 0, 0, 0),
+            (4, 31, 0, 0, 0),
+            (1, 0, 0, 0, 0),
+            (1, 1, 24, 0, 0),
+            (1, 1, 0, 60, 0),
+            (1, 1, 0, 0, 60),
+        ] {
+            assert!(matches!(
+                RtcDateTime::new(2023, month, day, hour, minute, second),
+                Err(DS3231DateTimeError::InvalidDateTime)
+            ));
+        }
+        // 2100 is not a leap year
+        assert!(RtcDateTime::new(2100, 2, 29, 0, 0, 0).is_err());
+        assert!(RtcDateTime::new(2000, 2, 29, 0, 0, 0).is_ok());
+    }
+
+    #[test]
+    fn test_rtc_datetime_timestamp() {
+        let datetime = RtcDateTime::from_timestamp(1_700_000_000).unwrap();
+        assert_eq!(
+            datetime,
+            RtcDateTime::new(2023, 11, 14, 22, 13, 20).unwrap()
+        );
+        assert_eq!(datetime.weekday(), DayOfWeek::Tuesday);
+        assert_eq!(datetime.timestamp(), 1_700_000_000);
+        assert!(matches!(
+            RtcDateTime::from_timestamp(-1),
+            Err(DS3231DateTimeError::YearNotAfter1999)
+        ));
+        assert!(matches!(
+            RtcDateTime::from_timestamp(i64::MAX),
+            Err(DS3231DateTimeError::YearNotBefore2200)
+        ));
+    }
+
+    #[test]
+    fn test_rtc_datetime_codec() {
+        let datetime = RtcDateTime::new(2124, 3, 14, 15, 30, 0).unwrap();
+        for repr in [
+            TimeRepresentation::TwentyFourHour,
+            TimeRepresentation::TwelveHour,
+        ] {
+            let raw = DS3231DateTime::from_rtc_datetime(datetime, repr).unwrap();
+            assert!(raw.month.century());
+            assert_eq!(raw.into_rtc_datetime().unwrap(), datetime);
+        }
+    }
+
+    #[test]
+    fn test_rtc_datetime_ordering_and_display() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        let earlier = RtcDateTime::new(2024, 3, 14, 15, 30, 0).unwrap();
+        let later = RtcDateTime::new(2024, 3, 14, 15, 30, 1).unwrap();
+        assert!(earlier < later);
+        assert_eq!(earlier.to_string(), "2024-03-14 15:30:00");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_rtc_datetime_into_chrono() {
+        let datetime = RtcDateTime::new(2024, 3, 14, 15, 30, 0).unwrap();
+        assert_eq!(
+            NaiveDateTime::from(datetime),
+            NaiveDate::from_ymd_opt(2024, 3, 14)
+                .unwrap()
+                .and_hms_opt(15, 30, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_timestamp_known_values() {
+        // 2023-11-14 22:13:20, a Tuesday
+        let raw = DS3231DateTime::from_timestamp(1_700_000_000, TimeRepresentation::TwentyFourHour)
+            .unwrap();
+        let arr: [u8; 7] = (&raw).into();
+        assert_eq!(arr, [0x20, 0x13, 0x22, 0x03, 0x14, 0x11, 0x23]);
+
+        // 2000-02-29 and 2100-03-01 across the century bit
+        let raw = DS3231DateTime::from([0x00, 0x00, 0x00, 0x02, 0x29, 0x02, 0x00]);
+        assert_eq!(raw.into_timestamp().unwrap(), 951_782_400);
+        let raw = DS3231DateTime::from([0x00, 0x00, 0x00, 0x01, 0x01, 0x83, 0x00]);
+        assert_eq!(raw.into_timestamp().unwrap(), 4_107_542_400);
+    }
+
+    #[test]
+    fn test_timestamp_out_of_range() {
+        let repr = TimeRepresentation::TwentyFourHour;
+        for timestamp in [Y2K_TIMESTAMP - 1, 0, i64::MIN] {
+            assert!(matches!(
+                DS3231DateTime::from_timestamp(timestamp, repr),
+                Err(DS3231DateTimeError::YearNotAfter1999)
+            ));
+        }
+        for timestamp in [7_258_118_400, i64::MAX] {
+            assert!(matches!(
+                DS3231DateTime::from_timestamp(timestamp, repr),
+                Err(DS3231DateTimeError::YearNotBefore2200)
+            ));
+        }
+        assert!(DS3231DateTime::from_timestamp(Y2K_TIMESTAMP, repr).is_ok());
+        assert!(DS3231DateTime::from_timestamp(7_258_118_399, repr).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_bcd_to_timestamp() {
+        // 2023-02-29 does not exist
+        let raw = DS3231DateTime::from([0x00, 0x00, 0x00, 0x03, 0x29, 0x02, 0x23]);
+        assert!(matches!(
+            raw.into_timestamp(),
+            Err(DS3231DateTimeError::InvalidDateTime)
+        ));
+        // 0x5A seconds
+        let raw = DS3231DateTime::from([0x5A, 0x00, 0x00, 0x03, 0x28, 0x02, 0x23]);
+        assert!(matches!(
+            raw.into_timestamp(),
+            Err(DS3231DateTimeError::InvalidDateTime)
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_invalid_bcd_to_datetime() {
         // Invalid BCD values for month (0x13 = 19 in decimal)
@@ -398,6 +1158,7 @@ mod tests {
         ));
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_valid_edge_cases() {
         // Test maximum valid values
@@ -417,6 +1178,7 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_twelve_hour_mode() {
         // Test PM time (1 PM = 13:00)
@@ -476,9 +1238,10 @@ mod tests {
         assert!(DS3231DateTime::convert_minutes(59).is_ok());
 
         // Test convert_day edge cases
-        assert!(DS3231DateTime::convert_day(7).is_err());
-        assert!(DS3231DateTime::convert_day(0).is_ok());
-        assert!(DS3231DateTime::convert_day(6).is_ok());
+        assert!(DS3231DateTime::convert_day(0).is_err());
+        assert!(DS3231DateTime::convert_day(8).is_err());
+        assert!(DS3231DateTime::convert_day(1).is_ok());
+        assert!(DS3231DateTime::convert_day(7).is_ok());
 
         // Test convert_date edge cases
         assert!(DS3231DateTime::convert_date(32).is_err());
@@ -583,6 +1346,7 @@ mod tests {
         ));
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_into_datetime_twelve_hour_mode() {
         // Test 12-hour mode conversion for 2 PM
@@ -590,7 +1354,7 @@ mod tests {
             seconds: Seconds(0x30), // 30 seconds
             minutes: Minutes(0x45), // 45 minutes
             hours: Hours(0x00),     // Will be set properly below
-            day: Day(0x04),         // Thursday
+            day: Day(0x05),         // Thursday
             date: Date(0x14),       // 14th
             month: Month(0x03),     // March
             year: Year(0x24),       // 2024
@@ -629,6 +1393,7 @@ mod tests {
         assert_eq!(dt.hour(), 12); // 12 PM = 12:00 in 24-hour
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_invalid_bcd_values() {
         // Test invalid seconds BCD
@@ -668,6 +1433,7 @@ mod tests {
         assert!(invalid_date.into_datetime().is_err());
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_array_conversions() {
         let dt = NaiveDate::from_ymd_opt(2024, 6, 15)
@@ -708,6 +1474,7 @@ mod tests {
         assert!(debug_str.contains("YearNotBefore2200"));
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_leap_year_handling() {
         // Test leap year (2024)
@@ -732,6 +1499,7 @@ mod tests {
         assert_eq!(non_leap_year_dt, converted_back);
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_weekday_conversion() {
         // Test all weekdays
@@ -741,7 +1509,7 @@ mod tests {
             TimeRepresentation::TwentyFourHour,
         )
         .unwrap();
-        assert_eq!(raw.day.day(), 0); // Sunday = 0 in DS3231
+        assert_eq!(raw.day.day(), 1); // Sunday = 1
 
         let monday = NaiveDate::from_ymd_opt(2024, 3, 11).unwrap(); // Monday
         let raw = DS3231DateTime::from_datetime(
@@ -749,7 +1517,7 @@ mod tests {
             TimeRepresentation::TwentyFourHour,
         )
         .unwrap();
-        assert_eq!(raw.day.day(), 1); // Monday = 1 in DS3231
+        assert_eq!(raw.day.day(), 2); // Monday = 2
 
         let saturday = NaiveDate::from_ymd_opt(2024, 3, 16).unwrap(); // Saturday
         let raw = DS3231DateTime::from_datetime(
@@ -757,9 +1525,75 @@ mod tests {
             TimeRepresentation::TwentyFourHour,
         )
         .unwrap();
-        assert_eq!(raw.day.day(), 6); // Saturday = 6 in DS3231
+        assert_eq!(raw.day.day(), 7); // Saturday = 7
+
+        let raw = raw.with_weekday_numbering(WeekdayNumbering::ISO);
+        assert_eq!(raw.day.day(), 6); // Saturday = 6 from Monday
     }
 
+    #[test]
+    fn test_weekday_numbering_registers() {
+        let numbering = WeekdayNumbering::MondayIsOne;
+        // Sunday = 1 becomes 7, Monday = 2 becomes 1
+        assert_eq!(numbering.encode_day(1), 7);
+        assert_eq!(numbering.encode_day(2), 1);
+        assert_eq!(numbering.encode_day(7), 6);
+        for day in 1..=7 {
+            assert_eq!(numbering.decode_day(numbering.encode_day(day)), day);
+            assert_eq!(WeekdayNumbering::SundayIsOne.encode_day(day), day);
+        }
+        // Out of range values are left for validation
+        assert_eq!(numbering.encode_day(0), 0);
+        assert_eq!(numbering.decode_day(8), 8);
+    }
+
+    #[test]
+    fn test_day_of_week() {
+        // 2024-03-17 is a Sunday
+        let sunday = RtcDateTime::new(2024, 3, 17, 0, 0, 0).unwrap();
+        assert_eq!(sunday.weekday(), DayOfWeek::Sunday);
+        assert_eq!(sunday.weekday().number(WeekdayNumbering::SundayIsOne), 1);
+        assert_eq!(sunday.weekday().number(WeekdayNumbering::ISO), 7);
+        assert_eq!(DayOfWeek::Monday.number(WeekdayNumbering::MondayIsOne), 1);
+        for numbering in [WeekdayNumbering::SundayIsOne, WeekdayNumbering::ISO] {
+            for day in DayOfWeek::ALL {
+                assert_eq!(
+                    DayOfWeek::from_number(day.number(numbering), numbering),
+                    Some(day)
+                );
+            }
+            assert_eq!(DayOfWeek::from_number(0, numbering), None);
+            assert_eq!(DayOfWeek::from_number(8, numbering), None);
+        }
+        // The Day register is written with the same numbering
+        let raw = DS3231DateTime::from_rtc_datetime(sunday, TimeRepresentation::TwentyFourHour)
+            .unwrap()
+            .with_weekday_numbering(WeekdayNumbering::ISO);
+        assert_eq!(raw.day.day(), 7);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_weekday_numbering_chrono() {
+        assert_eq!(WeekdayNumbering::SundayIsOne.number(Weekday::Sun), 1);
+        assert_eq!(WeekdayNumbering::SundayIsOne.number(Weekday::Sat), 7);
+        assert_eq!(WeekdayNumbering::ISO.number(Weekday::Mon), 1);
+        assert_eq!(WeekdayNumbering::ISO.number(Weekday::Sun), 7);
+        for numbering in [WeekdayNumbering::SundayIsOne, WeekdayNumbering::MondayIsOne] {
+            for number in 1..=7 {
+                let weekday = numbering.weekday(number).unwrap();
+                assert_eq!(numbering.number(weekday), number);
+            }
+            for day in DayOfWeek::ALL {
+                assert_eq!(DayOfWeek::from(Weekday::from(day)), day);
+                assert_eq!(numbering.number(Weekday::from(day)), day.number(numbering));
+            }
+            assert_eq!(numbering.weekday(0), None);
+            assert_eq!(numbering.weekday(8), None);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_century_boundary_years() {
         // Test year 2099 -> 2100 transition
@@ -835,20 +1669,48 @@ mod tests {
         assert_eq!(minutes_45.ten_minutes(), 4);
     }
 
+    #[test]
+    fn test_convert_hours_register() {
+        let twelve = TimeRepresentation::TwelveHour;
+        let twenty_four = TimeRepresentation::TwentyFourHour;
+        // 00, 12, 15 and 23 hours both ways
+        for (h24, h12) in [(0x00, 0x52), (0x12, 0x72), (0x15, 0x63), (0x23, 0x71)] {
+            assert_eq!(DS3231DateTime::convert_hours_register(h24, twelve), h12);
+            assert_eq!(
+                DS3231DateTime::convert_hours_register(h12, twenty_four),
+                h24
+            );
+            assert_eq!(
+                DS3231DateTime::convert_hours_register(h24, twenty_four),
+                h24
+            );
+        }
+        // The alarm mask bit is kept
+        assert_eq!(DS3231DateTime::convert_hours_register(0x95, twelve), 0xE3);
+        // Invalid hours only get the mode bit
+        assert_eq!(DS3231DateTime::decode_hour(0x40), None);
+        assert_eq!(DS3231DateTime::decode_hour(0x3F), None);
+        assert_eq!(
+            DS3231DateTime::convert_hours_register(0x40, twenty_four),
+            0x00
+        );
+        assert_eq!(DS3231DateTime::convert_hours_register(0x24, twelve), 0x64);
+    }
+
     #[test]
     fn test_convert_day_direct() {
         // Test successful conversions for all valid weekdays
-        let day_0 = DS3231DateTime::convert_day(0).unwrap(); // Sunday
-        assert_eq!(day_0.day(), 0);
-
-        let day_1 = DS3231DateTime::convert_day(1).unwrap(); // Monday
+        let day_1 = DS3231DateTime::convert_day(1).unwrap(); // Sunday
         assert_eq!(day_1.day(), 1);
 
-        let day_6 = DS3231DateTime::convert_day(6).unwrap(); // Saturday
-        assert_eq!(day_6.day(), 6);
+        let day_2 = DS3231DateTime::convert_day(2).unwrap(); // Monday
+        assert_eq!(day_2.day(), 2);
+
+        let day_7 = DS3231DateTime::convert_day(7).unwrap(); // Saturday
+        assert_eq!(day_7.day(), 7);
 
-        let day_3 = DS3231DateTime::convert_day(3).unwrap(); // Wednesday
-        assert_eq!(day_3.day(), 3);
+        let day_4 = DS3231DateTime::convert_day(4).unwrap(); // Wednesday
+        assert_eq!(day_4.day(), 4);
     }
 
     #[test]
@@ -895,6 +1757,7 @@ mod tests {
         assert_eq!(month_12.ten_month(), 1);
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_ds3231datetime_debug_formatting() {
         extern crate alloc;
@@ -919,6 +1782,7 @@ mod tests {
         assert!(debug_str.contains("year"));
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_ds3231datetime_copy_trait() {
         // Test Copy trait implementation
@@ -943,6 +1807,7 @@ mod tests {
         assert_eq!(dt, converted_original);
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_ds3231datetime_clone_trait() {
         // Test Clone trait implementation
@@ -963,6 +1828,7 @@ mod tests {
         assert_eq!(dt, converted_original);
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_ds3231datetime_clone_from_trait() {
         // Test Clone::clone_from trait implementation
@@ -995,6 +1861,7 @@ mod tests {
         assert_eq!(dt1, converted1);
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_ds3231datetime_partialeq_ne() {
         // Test PartialEq::ne trait implementation (not-equal)
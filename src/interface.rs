@@ -0,0 +1,234 @@
+//! Bus interfaces used to reach the device registers.
+//!
+//! The driver talks to the chip through an [`Interface`] (or [`AsyncInterface`] with the
+//! `async` feature), which only knows how to read and write a run of registers. This keeps the
+//! datetime, alarm, control and temperature logic independent of the bus:
+//!
+//! - [`I2cInterface`] - I2C, used by the DS3231 and DS3232
+//! - [`SpiInterface`] - SPI, used by the DS3234 (reads at the register address, writes at
+//!   address | 0x80)
+
+use embedded_hal::i2c::I2c;
+use embedded_hal::spi::{Operation as SpiOperation, SpiDevice};
+#[cfg(feature = "async")]
+use embedded_hal_async::i2c::I2c as AsyncI2c;
+#[cfg(feature = "async")]
+use embedded_hal_async::spi::SpiDevice as AsyncSpiDevice;
+
+/// Largest register write sent as a single I2C write (the whole timekeeping register map).
+const MAX_BUFFERED_WRITE: usize = 0x13;
+
+/// Bit set in the register address of an SPI write.
+const SPI_WRITE: u8 = 0x80;
+
+/// Register level access to the device.
+///
+/// With the `async` feature, `AsyncInterface` provides the same methods as async functions.
+#[maybe_async_cfg::maybe(
+    idents(Interface(sync, async = "AsyncInterface")),
+    sync(),
+    async(feature = "async")
+)]
+#[allow(async_fn_in_trait)]
+pub trait Interface {
+    /// Bus error type
+    type Error;
+
+    /// Reads `buffer.len()` consecutive registers starting at `register`.
+    ///
+    /// # Errors
+    /// Returns the bus error if the transfer fails.
+    async fn read_registers(&mut self, register: u8, buffer: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Writes `data` to consecutive registers starting at `register`.
+    ///
+    /// # Errors
+    /// Returns the bus error if the transfer fails.
+    async fn write_registers(&mut self, register: u8, data: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// I2C interface for the DS3231 and DS3232.
+#[derive(Debug)]
+pub struct I2cInterface<I2C> {
+    pub(crate) i2c: I2C,
+    pub(crate) address: u8,
+}
+
+impl<I2C> I2cInterface<I2C> {
+    /// Creates a new I2C interface.
+    ///
+    /// # Arguments
+    /// * `i2c` - The I2C bus implementation
+    /// * `address` - The I2C address of the device (typically 0x68)
+    pub fn new(i2c: I2C, address: u8) -> Self {
+        Self { i2c, address }
+    }
+
+    /// Returns the I2C address of the device.
+    pub fn address(&self) -> u8 {
+        self.address
+    }
+
+    /// Consumes the interface and returns the I2C bus.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        Interface(sync, async = "AsyncInterface"),
+        I2c(sync, async = "AsyncI2c"),
+        I2cInterface(sync, async = "I2cInterface")
+    ),
+    sync(),
+    async(feature = "async")
+)]
+impl<I2C, E> Interface for I2cInterface<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    type Error = E;
+
+    async fn read_registers(&mut self, register: u8, buffer: &mut [u8]) -> Result<(), E> {
+        self.i2c.write_read(self.address, &[register], buffer).await
+    }
+
+    async fn write_registers(&mut self, register: u8, data: &[u8]) -> Result<(), E> {
+        if data.len() <= MAX_BUFFERED_WRITE {
+            let mut buffer = [0; MAX_BUFFERED_WRITE + 1];
+            buffer[0] = register;
+            buffer[1..=data.len()].copy_from_slice(data);
+            self.i2c.write(self.address, &buffer[..=data.len()]).await
+        } else {
+            // Adjacent write operations are sent without a repeated start, so the register
+            // address and the data form a single bus write.
+            self.i2c
+                .transaction(
+                    self.address,
+                    &mut [
+                        embedded_hal::i2c::Operation::Write(&[register]),
+                        embedded_hal::i2c::Operation::Write(data),
+                    ],
+                )
+                .await
+        }
+    }
+}
+
+/// SPI interface for the DS3234.
+///
+/// The `SpiDevice` manages chip select; the DS3234 supports SPI modes 1 and 3.
+#[derive(Debug)]
+pub struct SpiInterface<SPI> {
+    pub(crate) spi: SPI,
+}
+
+impl<SPI> SpiInterface<SPI> {
+    /// Creates a new SPI interface.
+    ///
+    /// # Arguments
+    /// * `spi` - The SPI device implementation
+    pub fn new(spi: SPI) -> Self {
+        Self { spi }
+    }
+
+    /// Consumes the interface and returns the SPI device.
+    pub fn release(self) -> SPI {
+        self.spi
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        Interface(sync, async = "AsyncInterface"),
+        SpiDevice(sync, async = "AsyncSpiDevice"),
+        SpiInterface(sync, async = "SpiInterface")
+    ),
+    sync(),
+    async(feature = "async")
+)]
+impl<SPI, E> Interface for SpiInterface<SPI>
+where
+    SPI: SpiDevice<Error = E>,
+{
+    type Error = E;
+
+    async fn read_registers(&mut self, register: u8, buffer: &mut [u8]) -> Result<(), E> {
+        self.spi
+            .transaction(&mut [
+                SpiOperation::Write(&[register & !SPI_WRITE]),
+                SpiOperation::Read(buffer),
+            ])
+            .await
+    }
+
+    async fn write_registers(&mut self, register: u8, data: &[u8]) -> Result<(), E> {
+        self.spi
+            .transaction(&mut [
+                SpiOperation::Write(&[register | SPI_WRITE]),
+                SpiOperation::Write(data),
+            ])
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::vec;
+
+    use super::{I2cInterface, Interface, SpiInterface, MAX_BUFFERED_WRITE};
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+    use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTrans};
+
+    #[test]
+    fn test_i2c_short_write_is_single_write() {
+        let mut i2c = I2cMock::new(&[I2cTrans::write(0x68, vec![0x0E, 0x1C, 0x88])]);
+        let mut interface = I2cInterface::new(i2c.clone(), 0x68);
+        interface.write_registers(0x0E, &[0x1C, 0x88]).unwrap();
+        i2c.done();
+    }
+
+    #[test]
+    fn test_i2c_long_write_is_transaction() {
+        let data = [0x55; MAX_BUFFERED_WRITE + 1];
+        let mut i2c = I2cMock::new(&[
+            I2cTrans::transaction_start(0x68),
+            I2cTrans::write(0x68, vec![0x14]),
+            I2cTrans::write(0x68, data.to_vec()),
+            I2cTrans::transaction_end(0x68),
+        ]);
+        let mut interface = I2cInterface::new(i2c.clone(), 0x68);
+        interface.write_registers(0x14, &data).unwrap();
+        i2c.done();
+    }
+
+    #[test]
+    fn test_spi_read_uses_register_address() {
+        let mut spi = SpiMock::new(&[
+            SpiTrans::transaction_start(),
+            SpiTrans::write(0x11),
+            SpiTrans::read_vec(vec![0x19, 0x40]),
+            SpiTrans::transaction_end(),
+        ]);
+        let mut interface = SpiInterface::new(spi.clone());
+        let mut data = [0; 2];
+        interface.read_registers(0x11, &mut data).unwrap();
+        assert_eq!(data, [0x19, 0x40]);
+        spi.done();
+    }
+
+    #[test]
+    fn test_spi_write_sets_write_bit() {
+        let mut spi = SpiMock::new(&[
+            SpiTrans::transaction_start(),
+            SpiTrans::write(0x8E),
+            SpiTrans::write_vec(vec![0x1C]),
+            SpiTrans::transaction_end(),
+        ]);
+        let mut interface = SpiInterface::new(spi.clone());
+        interface.write_registers(0x0E, &[0x1C]).unwrap();
+        spi.done();
+    }
+}
@@ -7,6 +7,8 @@
 //! ## Features
 //!
 //! - Both blocking and async I²C operation support
+//! - DS3232 (I²C) and DS3234 (SPI) variants
+//! - Fixed-point temperature readings with [`Celsius`]
 //! - Optional logging support via `log` or `defmt`
 //!
 //! ### Blocking Usage
@@ -51,15 +53,24 @@
 //! rtc.set_alarm2(&alarm2)?;
 //! ```
 //!
+//! The `day` of an alarm counts from 1 = Sunday; with chrono,
+//! `Alarm2Config::on_weekday(Weekday::Fri, NaiveTime::from_hms_opt(17, 0, 0).unwrap())` builds
+//! the same alarm. Alarm hours are written in the clock's current 12/24 hour format, whichever
+//! form the configuration uses.
+//! `set_weekday_numbering` selects how the Day register itself numbers the days: 1 = Sunday
+//! by default, or 1 = Monday as in ISO 8601.
+//!
 //! ### Async Usage
 //!
-//! Enable the async feature on `ds3231`in your `Cargo.toml` and use with async/await:
+//! Enable the async feature on `ds3231` in your `Cargo.toml` and use `DS3231Async` with
+//! async/await. The blocking `DS3231` driver remains available, so both can be used in the
+//! same build (for example a blocking bootloader and an async application):
 //!
 //! ```rust,ignore
-//! use ds3231::{DS3231, Alarm1Config, Alarm2Config};
+//! use ds3231::{DS3231Async, Alarm1Config, Alarm2Config};
 //!
 //! // Initialize device
-//! let mut rtc = DS3231::new(i2c, 0x68);
+//! let mut rtc = DS3231Async::new(i2c, 0x68);
 //!
 //! // Configure asynchronously
 //! rtc.configure(&config).await?;
@@ -80,12 +91,153 @@
 //! rtc.set_alarm2(&alarm2).await?;
 //! ```
 //!
+//! Instead of polling the status register, the async driver can wait for the INT/SQW pin
+//! (an `embedded_hal_async::digital::Wait` input) and clear the alarm flags:
+//!
+//! ```rust,ignore
+//! let event = rtc.wait_for_alarm(&mut int_pin).await?;
+//! if event.includes(AlarmId::Alarm1) { /* ... */ }
+//! ```
+//!
+//! ### DS3232
+//!
+//! The DS3232 shares the DS3231 register map and adds 236 bytes of battery-backed SRAM. Create
+//! the driver with `new_ds3232` to get access to the SRAM and the extra control/status bits:
+//!
+//! ```rust,ignore
+//! use ds3231::DS3231;
+//!
+//! let mut rtc = DS3231::new_ds3232(i2c, 0x68);
+//! rtc.write_sram(0, b"boot count")?;
+//!
+//! let mut data = [0; 10];
+//! rtc.read_sram(0, &mut data)?;
+//! ```
+//!
+//! ### DS3234
+//!
+//! The DS3234 is the SPI version of the DS3232. The driver reaches the registers through an
+//! [`interface`], so the same code runs over an `embedded-hal` `SpiDevice` (blocking or async):
+//!
+//! ```rust,ignore
+//! use ds3231::DS3231;
+//!
+//! let mut rtc = DS3231::new_ds3234(spi_device);
+//! let datetime = rtc.datetime()?;
+//! rtc.write_sram(200, b"hello")?;
+//! ```
+//!
+//! ### Consistent Reads
+//!
+//! Reading the time one register at a time can tear across a rollover (for example at
+//! 23:59:59). Use `snapshot` to read any combination of register groups consistently:
+//!
+//! ```rust,ignore
+//! use ds3231::RegisterGroups;
+//!
+//! let snapshot = rtc.snapshot(RegisterGroups::TIME | RegisterGroups::STATUS)?;
+//! let now = snapshot.datetime().unwrap()?;
+//! let alarm1_fired = snapshot.status().unwrap().alarm1_flag();
+//! ```
+//!
+//! ### Register Cache
+//!
+//! The driver shadows the alarm, control and aging offset registers and the 12/24 hour
+//! setting. By default every read still goes to the device. With `CachePolicy::TrustCache` or
+//! `CachePolicy::InvalidateOnError` reads are served from the shadow, which saves bus traffic
+//! when the driver is the only one touching the chip. Call `invalidate` after anything else
+//! may have changed the registers:
+//!
+//! ```rust,ignore
+//! use ds3231::CachePolicy;
+//!
+//! rtc.set_cache_policy(CachePolicy::InvalidateOnError);
+//! let control = rtc.control()?; // read from the device once, then from the shadow
+//! rtc.invalidate();
+//! ```
+//!
+//! ### Local Time
+//!
+//! Keep the device in UTC and convert with a [`TimeZone`] parsed from a POSIX TZ string
+//! (see the [`tz`] module):
+//!
+//! ```rust,ignore
+//! use ds3231::TimeZone;
+//!
+//! let tz = TimeZone::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+//! let local = rtc.local_datetime(&tz)?;
+//! rtc.set_local_alarm1(&tz, &Alarm1Config::AtTime { hours: 7, minutes: 0, seconds: 0, is_pm: None })?;
+//! ```
+//!
+//! ### Alarm Scheduling
+//!
+//! An [`AlarmScheduler`] multiplexes up to `N` logical alarms over one hardware alarm (see the
+//! [`scheduler`] module):
+//!
+//! ```rust,ignore
+//! use ds3231::{AlarmId, AlarmScheduler, Schedule};
+//!
+//! let mut scheduler: AlarmScheduler<Task, 8> = AlarmScheduler::new(AlarmId::Alarm1);
+//! scheduler.add(Task::Upload, Schedule::Once(upload_time), &rtc.datetime()?)?;
+//! rtc.service_scheduler(&mut scheduler)?; // programs the earliest alarm
+//! // When the alarm fires:
+//! for alarm in &rtc.service_scheduler(&mut scheduler)? { /* alarm.tag is due */ }
+//! ```
+//!
+//! ### Timestamps
+//!
+//! The time can also be read and set as Unix seconds, or as compact 32-bit seconds since
+//! 2000-01-01, without going through chrono:
+//!
+//! ```rust,ignore
+//! let now: i64 = rtc.timestamp()?;
+//! rtc.set_seconds_since_2000(logged_seconds)?;
+//! ```
+//!
+//! ### Minimal Builds
+//!
+//! With `default-features = false` the driver does not depend on chrono. [`RtcDateTime`] is a
+//! validated date and time read with `datetime_raw` and written with `set_datetime_raw`:
+//!
+//! ```rust,ignore
+//! use ds3231::RtcDateTime;
+//!
+//! let now = rtc.datetime_raw()?;
+//! rtc.set_datetime_raw(&RtcDateTime::new(2024, 3, 14, 15, 30, 0)?)?;
+//! ```
+//!
+//! Reading and setting the time this way pulls in none of chrono's calendar code: on
+//! `thumbv7em-none-eabihf` with `opt-level = "s"` and LTO, reading the time and writing it back
+//! takes 1446 bytes of `.text` and 7 of `.rodata`, against 2944 and 1972 through `datetime` with
+//! `chrono` (see `examples/size-check`).
+//!
+//! ### Aging Offset Calibration
+//!
+//! A [`DriftEstimator`] estimates how fast or slow the RTC runs from samples against a
+//! reference clock and recommends an aging offset:
+//!
+//! ```rust,ignore
+//! use ds3231::DriftEstimator;
+//!
+//! let mut estimator = DriftEstimator::new();
+//! rtc.sample_drift(&ntp_time, &mut estimator)?; // repeat over several days
+//! if let Some(offset) = rtc.apply_aging_offset(&estimator)? {
+//!     estimator.reset();
+//! }
+//! ```
+//!
 //! ## Features
 //!
-//! - `async` - Enables optional async I²C support
+//! - `chrono` (default) - chrono `NaiveDateTime` access, the [`tz`] and [`scheduler`] modules
+//!   and [`DriftEstimator`]
+//! - `time` - `time` crate `PrimitiveDateTime`/`OffsetDateTime` access
+//! - `async` - Enables the async `DS3231Async` driver alongside the blocking `DS3231`
 //! - `log` - Enables logging via the `log` crate
 //! - `defmt` - Enables logging via the `defmt` crate
 //! - `temperature_f32` - Enables temperature reading as f32
+//! - `sim` - Enables the [`sim`] module, a software DS3231 for host-side testing
+//! - `rtcc` - Implements the `rtcc` crate's `DateTimeAccess` and `Rtcc` traits for `DS3231`
+//!   (implies `chrono`)
 //!
 //! ## Register Map
 //!
@@ -97,13 +249,19 @@
 //! - Status: oscillator stop, 32kHz output, busy flags
 //! - Aging offset
 //! - Temperature
+//! - DS3232/DS3234 only: temperature control (`BB_TD`), `BB32kHz`/`CRATE` status bits and SRAM
+//! - DS3234 only: SRAM address and SRAM data registers
 //!
 //! ## Error Handling
 //!
 //! The driver uses a custom error type `DS3231Error` that wraps:
-//! - I²C communication errors
+//! - Bus (I²C or SPI) communication errors
 //! - `DateTime` validation errors
 //! - Alarm configuration errors
+//! - Snapshots whose registers kept changing while being read
+//! - SRAM accesses outside of the chip's SRAM
+//! - Timeouts waiting for the device, such as a forced temperature conversion
+//! - Errors of the INT/SQW pin while waiting for an alarm
 //! - Proper error propagation
 //!
 //! ## Safety
@@ -120,28 +278,115 @@
 mod fmt;
 
 mod alarm;
+mod cache;
+#[cfg(feature = "chrono")]
+mod calibration;
+pub mod chip;
 mod datetime;
+pub mod interface;
 mod registers;
+#[cfg(feature = "rtcc")]
+mod rtcc;
+#[cfg(feature = "chrono")]
+pub mod scheduler;
+#[cfg(any(test, feature = "sim"))]
+pub mod sim;
+mod snapshot;
+mod temperature;
+#[cfg(feature = "chrono")]
+mod trigger;
+#[cfg(feature = "chrono")]
+pub mod tz;
+
+#[cfg(feature = "async")]
+use core::future::{poll_fn, Future};
+use core::marker::PhantomData;
+#[cfg(feature = "async")]
+use core::pin::{pin, Pin};
+#[cfg(feature = "async")]
+use core::task::Poll;
+#[cfg(feature = "chrono")]
+use core::time::Duration;
 
+#[cfg(feature = "chrono")]
 use chrono::NaiveDateTime;
-use datetime::DS3231DateTimeError;
-#[cfg(not(feature = "async"))]
-use embedded_hal::i2c::I2c;
+use embedded_hal::delay::DelayNs;
+#[cfg(feature = "async")]
+use embedded_hal::digital::Error as _;
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
 #[cfg(feature = "async")]
-use embedded_hal_async::i2c::I2c;
+use embedded_hal_async::digital::Wait;
 use paste::paste;
+#[cfg(feature = "time")]
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
 
-use crate::datetime::DS3231DateTime;
+use crate::cache::RegisterCache;
+use crate::chip::{Chip, SramChip};
+use crate::datetime::{DS3231DateTime, Y2K_TIMESTAMP};
+#[cfg(feature = "async")]
+use crate::interface::AsyncInterface;
+use crate::interface::Interface;
 use crate::registers::RegAddr;
+use crate::snapshot::SNAPSHOT_REGISTERS;
+
+/// Maximum number of passes `snapshot` makes over non-adjacent register groups.
+const MAX_SNAPSHOT_READS: usize = 3;
+
+/// Interval between polls of the BSY and CONV bits, in milliseconds.
+const CONVERSION_POLL_MS: u32 = 2;
+
+/// Maximum number of polls while waiting for BSY or CONV to clear. This allows about 250 ms,
+/// above the 200 ms maximum temperature conversion time.
+const CONVERSION_MAX_POLLS: u32 = 125;
+
+/// Error of `set_alarm1_after`/`set_alarm2_after` when the date would come round before the
+/// requested time.
+#[cfg(feature = "chrono")]
+const ALARM_DURATION_TOO_LONG: AlarmError =
+    AlarmError::InvalidTime("duration is too long for an alarm");
+
+/// Maximum number of times `service_scheduler` collects the due alarms and programs the next
+/// one. Another pass is only needed if the clock reaches the programmed time during the write.
+#[cfg(feature = "chrono")]
+const SCHEDULER_MAX_PASSES: u32 = 3;
 
 // Re-export public types from alarm module
-pub use crate::alarm::{Alarm1Config, Alarm2Config, AlarmError, DS3231Alarm1, DS3231Alarm2};
+pub use crate::alarm::{
+    Alarm1, Alarm1Config, Alarm2, Alarm2Config, AlarmError, AlarmEvent, AlarmId, AlarmMatch,
+    AlarmUpdate, DS3231Alarm1, DS3231Alarm2, FieldMatch, FiredAlarms,
+};
+// Re-export the cache policy
+pub use crate::cache::CachePolicy;
+// Re-export the aging offset calibration
+#[cfg(feature = "chrono")]
+pub use crate::calibration::DriftEstimator;
+// Re-export the chip variant markers
+pub use crate::chip::{DS3231Chip, DS3232Chip, DS3234Chip};
+// Re-export the datetime error and the native date/time
+pub use crate::datetime::{DS3231DateTimeError, DayOfWeek, RtcDateTime, WeekdayNumbering};
+// Re-export the bus interfaces
+pub use crate::interface::{I2cInterface, SpiInterface};
 // Re-export public types from registers module
 pub use crate::registers::{
     AgingOffset, AlarmDayDate, AlarmHours, AlarmMinutes, AlarmSeconds, Control, Date, Day,
     DayDateSelect, Hours, InterruptControl, Minutes, Month, Oscillator, Seconds,
-    SquareWaveFrequency, Status, Temperature, TemperatureFraction, TimeRepresentation, Year,
+    SquareWaveFrequency, Status, Temperature, TemperatureControl, TemperatureConversionRate,
+    TemperatureFraction, TimeRepresentation, Year,
 };
+// Re-export the alarm scheduler
+#[cfg(feature = "chrono")]
+pub use crate::scheduler::{AlarmScheduler, DueAlarm, DueAlarms, Schedule, SchedulerError};
+// Re-export the snapshot types
+pub use crate::snapshot::{RegisterGroups, Snapshot};
+// Re-export the fixed-point temperature
+pub use crate::temperature::Celsius;
+// Re-export the alarm trigger iterator
+#[cfg(feature = "chrono")]
+pub use crate::trigger::AlarmTriggers;
+// Re-export the time zone
+#[cfg(feature = "chrono")]
+pub use crate::tz::TimeZone;
 
 /// Configuration for the DS3231 RTC device.
 ///
@@ -166,16 +411,24 @@ pub struct Config {
 /// Error type for DS3231 operations.
 #[derive(Debug)]
 pub enum DS3231Error<I2CE> {
-    /// I2C bus error
+    /// Bus error (I2C, or SPI for the DS3234)
     I2c(I2CE),
     /// `DateTime` validation or conversion error
     DateTime(DS3231DateTimeError),
     /// Alarm configuration error
     Alarm(AlarmError),
+    /// SRAM access outside of the chip's SRAM
+    SramOutOfRange,
+    /// The registers kept changing while a snapshot was being read
+    SnapshotUnstable,
+    /// The device did not finish an operation (such as a temperature conversion) in time
+    Timeout,
+    /// Waiting for the INT/SQW pin failed
+    Pin(embedded_hal::digital::ErrorKind),
 }
 
 impl<I2CE> From<I2CE> for DS3231Error<I2CE> {
-    /// Creates a `DS3231Error` from an I2C error.
+    /// Creates a `DS3231Error` from a bus error.
     fn from(e: I2CE) -> Self {
         DS3231Error::I2c(e)
     }
@@ -183,104 +436,210 @@ impl<I2CE> From<I2CE> for DS3231Error<I2CE> {
 
 /// DS3231 Real-Time Clock driver.
 ///
-/// This struct provides the blocking interface to the DS3231 RTC device.
-pub struct DS3231<I2C> {
-    i2c: I2C,
-    address: u8,
+/// `DS3231` uses the blocking `embedded-hal` traits. With the `async` feature enabled,
+/// `DS3231Async` provides the same interface on top of `embedded-hal-async`; both drivers can
+/// be used in the same build.
+///
+/// The `IF` parameter is the bus interface (see [`interface`]): `I2cInterface` for the DS3231
+/// and DS3232, `SpiInterface` for the DS3234. The `C` parameter selects the chip variant (see
+/// [`chip`]). It defaults to the DS3231; use `new_ds3232` or `new_ds3234` to drive the other
+/// variants and access their SRAM.
+#[maybe_async_cfg::maybe(
+    idents(DS3231(sync, async = "DS3231Async")),
+    sync(),
+    async(feature = "async")
+)]
+pub struct DS3231<IF, C = DS3231Chip> {
+    interface: IF,
     time_representation: Option<TimeRepresentation>,
+    weekday_numbering: WeekdayNumbering,
+    cache: RegisterCache,
+    chip: PhantomData<C>,
 }
 
 // Register access implementations
 macro_rules! impl_register_access {
     ($(($name:ident, $regaddr:expr, $typ:ty)),+) => {
-        $(
-            paste! {
-                #[doc = concat!("Gets the value of the ", stringify!($name), " register.")]
-                #[doc = "\n\n# Returns"]
-                #[doc = concat!("* `Ok(", stringify!($typ), ")` - The register value on success")]
-                #[doc = "* `Err(DS3231Error)` on error"]
-                #[doc = "\n\n# Errors"]
-                #[doc = "Returns `DS3231Error::I2c` if there is an I2C communication error"]
-                #[cfg(feature = "async")]
-                pub async fn $name(&mut self) -> Result<$typ, DS3231Error<E>> {
-                    let mut data = [0];
-                    self.i2c
-                        .write_read(self.address, &[$regaddr as u8], &mut data)
-                        .await?;
-                    Ok($typ(data[0]))
-                }
-                #[doc = concat!("Gets the value of the ", stringify!($name), " register.")]
-                #[doc = "\n\n# Returns"]
-                #[doc = concat!("* `Ok(", stringify!($typ), ")` - The register value on success")]
-                #[doc = "* `Err(DS3231Error)` on error"]
-                #[doc = "\n\n# Errors"]
-                #[doc = "Returns `DS3231Error::I2c` if there is an I2C communication error"]
-                #[cfg(not(feature = "async"))]
-                pub fn $name(&mut self) -> Result<$typ, DS3231Error<E>> {
-                    let mut data = [0];
-                    self.i2c
-                        .write_read(self.address, &[$regaddr as u8], &mut data)?;
-                    Ok($typ(data[0]))
-                }
+        impl<IF, E, C> DS3231<IF, C>
+        where
+            IF: Interface<Error = E>,
+            C: Chip,
+        {
+            $(
+                paste! {
+                    #[doc = concat!("Gets the value of the ", stringify!($name), " register.")]
+                    #[doc = "\n\n# Returns"]
+                    #[doc = concat!("* `Ok(", stringify!($typ), ")` - The register value on success")]
+                    #[doc = "* `Err(DS3231Error)` on error"]
+                    #[doc = "\n\n# Errors"]
+                    #[doc = "Returns `DS3231Error::I2c` if there is a bus communication error"]
+                    pub fn $name(&mut self) -> Result<$typ, DS3231Error<E>> {
+                        let mut data = [0];
+                        self.read_registers($regaddr as u8, &mut data)?;
+                        Ok($typ(data[0]))
+                    }
 
-                #[doc = concat!("Sets the value of the ", stringify!($name), " register.")]
-                #[doc = "\n\n# Arguments"]
-                #[doc = concat!("* `value` - The value to write to the ", stringify!($name), " register")]
-                #[doc = "\n\n# Returns"]
-                #[doc = "* `Ok(())` on success"]
-                #[doc = "* `Err(DS3231Error)` on error"]
-                #[doc = "\n\n# Errors"]
-                #[doc = "Returns `DS3231Error::I2c` if there is an I2C communication error"]
-                #[cfg(feature = "async")]
-                pub async fn [<set_ $name>](&mut self, value: $typ) -> Result<(), DS3231Error<E>> {
-                    self.i2c.write(
-                        self.address,
-                        &[$regaddr as u8, value.into()],
-                    ).await?;
-                    Ok(())
+                    #[doc = concat!("Sets the value of the ", stringify!($name), " register.")]
+                    #[doc = "\n\n# Arguments"]
+                    #[doc = concat!("* `value` - The value to write to the ", stringify!($name), " register")]
+                    #[doc = "\n\n# Returns"]
+                    #[doc = "* `Ok(())` on success"]
+                    #[doc = "* `Err(DS3231Error)` on error"]
+                    #[doc = "\n\n# Errors"]
+                    #[doc = "Returns `DS3231Error::I2c` if there is a bus communication error"]
+                    pub fn [<set_ $name>](&mut self, value: $typ) -> Result<(), DS3231Error<E>> {
+                        self.write_registers($regaddr as u8, &[value.into()])?;
+                        Ok(())
+                    }
                 }
-                #[doc = concat!("Sets the value of the ", stringify!($name), " register.")]
-                #[doc = "\n\n# Arguments"]
-                #[doc = concat!("* `value` - The value to write to the ", stringify!($name), " register")]
-                #[doc = "\n\n# Returns"]
-                #[doc = "* `Ok(())` on success"]
-                #[doc = "* `Err(DS3231Error)` on error"]
-                #[doc = "\n\n# Errors"]
-                #[doc = "Returns `DS3231Error::I2c` if there is an I2C communication error"]
-                #[cfg(not(feature = "async"))]
-                pub fn [<set_ $name>](&mut self, value: $typ) -> Result<(), DS3231Error<E>> {
-                    self.i2c.write(
-                        self.address,
-                        &[$regaddr as u8, value.into()],
-                    )?;
-                    Ok(())
+            )+
+        }
+
+        #[cfg(feature = "async")]
+        impl<IF, E, C> DS3231Async<IF, C>
+        where
+            IF: AsyncInterface<Error = E>,
+            C: Chip,
+        {
+            $(
+                paste! {
+                    #[doc = concat!("Gets the value of the ", stringify!($name), " register.")]
+                    #[doc = "\n\n# Returns"]
+                    #[doc = concat!("* `Ok(", stringify!($typ), ")` - The register value on success")]
+                    #[doc = "* `Err(DS3231Error)` on error"]
+                    #[doc = "\n\n# Errors"]
+                    #[doc = "Returns `DS3231Error::I2c` if there is a bus communication error"]
+                    pub async fn $name(&mut self) -> Result<$typ, DS3231Error<E>> {
+                        let mut data = [0];
+                        self.read_registers($regaddr as u8, &mut data)
+                            .await?;
+                        Ok($typ(data[0]))
+                    }
+
+                    #[doc = concat!("Sets the value of the ", stringify!($name), " register.")]
+                    #[doc = "\n\n# Arguments"]
+                    #[doc = concat!("* `value` - The value to write to the ", stringify!($name), " register")]
+                    #[doc = "\n\n# Returns"]
+                    #[doc = "* `Ok(())` on success"]
+                    #[doc = "* `Err(DS3231Error)` on error"]
+                    #[doc = "\n\n# Errors"]
+                    #[doc = "Returns `DS3231Error::I2c` if there is a bus communication error"]
+                    pub async fn [<set_ $name>](&mut self, value: $typ) -> Result<(), DS3231Error<E>> {
+                        self.write_registers($regaddr as u8, &[value.into()])
+                            .await?;
+                        Ok(())
+                    }
                 }
-            }
-        )+
+            )+
+        }
     }
 }
 
 #[maybe_async_cfg::maybe(
-    sync(cfg(not(feature = "async")), keep_self),
-    async(feature = "async", keep_self)
+    idents(DS3231(sync, async = "DS3231Async"), I2c(sync, async = "AsyncI2c")),
+    sync(),
+    async(feature = "async")
 )]
-impl<I2C, E> DS3231<I2C>
-where
-    I2C: I2c<Error = E>,
-{
-    /// Creates a new DS3231 async driver instance.
+impl<I2C> DS3231<I2cInterface<I2C>> {
+    /// Creates a new DS3231 driver instance.
     ///
     /// # Arguments
-    /// * `i2c` - The async I2C bus implementation
+    /// * `i2c` - The I2C bus implementation
     /// * `address` - The I2C address of the device (typically 0x68)
     pub fn new(i2c: I2C, address: u8) -> Self {
+        Self::with_interface(I2cInterface::new(i2c, address))
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(DS3231(sync, async = "DS3231Async")),
+    sync(),
+    async(feature = "async")
+)]
+impl<IF, C> DS3231<IF, C>
+where
+    C: Chip,
+{
+    /// Creates a driver instance for the chip variant `C` on top of a bus interface.
+    ///
+    /// Prefer `new`, `new_ds3232` or `new_ds3234`, which pair each chip with its bus.
+    ///
+    /// # Arguments
+    /// * `interface` - The bus interface (see [`interface`])
+    pub fn with_interface(interface: IF) -> Self {
         Self {
-            i2c,
-            address,
+            interface,
             time_representation: None,
+            weekday_numbering: WeekdayNumbering::default(),
+            cache: RegisterCache::default(),
+            chip: PhantomData,
         }
     }
 
+    /// Gets the policy used for the shadow copies of the configuration registers.
+    pub fn cache_policy(&self) -> CachePolicy {
+        self.cache.policy()
+    }
+
+    /// Sets the policy used for the shadow copies of the configuration registers.
+    ///
+    /// The driver shadows the alarm, control and aging offset registers and the 12/24 hour
+    /// setting. With `CachePolicy::AlwaysRead` (the default) they are always read from the
+    /// device; the other policies serve reads from the shadow. See [`CachePolicy`].
+    ///
+    /// # Arguments
+    /// * `policy` - The cache policy to use
+    pub fn set_cache_policy(&mut self, policy: CachePolicy) {
+        self.cache.set_policy(policy);
+    }
+
+    /// Gets the numbering of the days of the week in the Day register.
+    pub fn weekday_numbering(&self) -> WeekdayNumbering {
+        self.weekday_numbering
+    }
+
+    /// Sets the numbering of the days of the week in the Day register.
+    ///
+    /// It applies to the Day register written with the date and time and to the day of
+    /// day-of-week alarms. Alarm configurations keep counting from 1 = Sunday; the driver
+    /// converts their day when it writes or reads the alarm registers. Set the numbering
+    /// before setting the time, since the device only advances the Day register.
+    ///
+    /// # Arguments
+    /// * `numbering` - The weekday numbering to use
+    pub fn set_weekday_numbering(&mut self, numbering: WeekdayNumbering) {
+        self.weekday_numbering = numbering;
+    }
+
+    /// Discards the shadow registers and the cached 12/24 hour setting.
+    ///
+    /// Call this when something other than this driver (another bus master, a reset of the
+    /// device) may have changed the registers, so that the next accesses read the device.
+    pub fn invalidate(&mut self) {
+        self.cache.invalidate();
+        self.time_representation = None;
+    }
+
+    /// Consumes the driver and returns the bus interface.
+    pub fn release(self) -> IF {
+        self.interface
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        DS3231(sync, async = "DS3231Async"),
+        Interface(sync, async = "AsyncInterface"),
+        DelayNs(sync, async = "AsyncDelayNs")
+    ),
+    sync(),
+    async(feature = "async")
+)]
+impl<IF, E, C> DS3231<IF, C>
+where
+    IF: Interface<Error = E>,
+    C: Chip,
+{
     /// Configures the device according to the provided configuration.
     ///
     /// # Arguments
@@ -291,7 +650,7 @@ where
     /// * `Err(DS3231Error)` on error
     ///
     /// # Errors
-    /// Returns `DS3231Error::I2c` if there is an I2C communication error.
+    /// Returns `DS3231Error::I2c` if there is a bus communication error.
     pub async fn configure(&mut self, config: &Config) -> Result<(), DS3231Error<E>> {
         debug!("DS3231: reading control register");
         let mut control = self.control().await?;
@@ -301,14 +660,113 @@ where
         control.set_interrupt_control(config.interrupt_control);
         debug!("DS3231: writing control: {:?}", control);
         self.set_control(control).await?;
-        debug!("DS3231: reading hours register");
-        let mut hours = self.hour().await?;
-        hours.set_time_representation(config.time_representation);
-        self.set_hour(hours).await?;
-        self.time_representation = Some(config.time_representation);
+        self.set_time_representation(config.time_representation)
+            .await
+    }
+
+    /// Switches between 12- and 24-hour mode, keeping the time and the alarm times.
+    ///
+    /// The hours register and the hours registers of both alarms are re-encoded in the new
+    /// format; 15:00 becomes 3 PM and back. The alarm mask bits are kept, and an hours register
+    /// that does not hold a valid hour only has its 12/24 hour bit changed. Registers already in
+    /// the requested format are not written.
+    ///
+    /// The minutes are read back after the hours register is written. If they wrapped, the hour
+    /// rolled over during the update and the next hour is written, so the update cannot undo a
+    /// rollover.
+    ///
+    /// # Arguments
+    /// * `time_representation` - The format to switch to
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn set_time_representation(
+        &mut self,
+        time_representation: TimeRepresentation,
+    ) -> Result<(), DS3231Error<E>> {
+        debug!("DS3231: reading time");
+        let mut time = [0; 3];
+        self.read_registers(RegAddr::Seconds as u8, &mut time)
+            .await?;
+        let hours = DS3231DateTime::convert_hours_register(time[2], time_representation);
+        if hours != time[2] {
+            self.write_registers(RegAddr::Hours as u8, &[hours]).await?;
+            let mut minutes = [0];
+            self.read_registers(RegAddr::Minutes as u8, &mut minutes)
+                .await?;
+            if minutes[0] < time[1] {
+                if let Some(hour) = DS3231DateTime::decode_hour(time[2]) {
+                    let next = DS3231DateTime::convert_hours((hour + 1) % 24, time_representation)
+                        .map_err(DS3231Error::DateTime)?;
+                    self.write_registers(RegAddr::Hours as u8, &[next.into()])
+                        .await?;
+                }
+            }
+        }
+        for register in [RegAddr::Alarm1Hours, RegAddr::Alarm2Hours] {
+            let mut alarm_hours = [0];
+            self.read_registers(register as u8, &mut alarm_hours)
+                .await?;
+            let converted =
+                DS3231DateTime::convert_hours_register(alarm_hours[0], time_representation);
+            if converted != alarm_hours[0] {
+                self.write_registers(register as u8, &[converted]).await?;
+            }
+        }
+        self.time_representation = Some(time_representation);
         Ok(())
     }
 
+    /// Reads consecutive registers, from the shadow if the cache policy allows it.
+    async fn read_registers(
+        &mut self,
+        register: u8,
+        buffer: &mut [u8],
+    ) -> Result<(), DS3231Error<E>> {
+        if self.cache.read(register, buffer) {
+            return Ok(());
+        }
+        self.fetch_registers(register, buffer).await
+    }
+
+    /// Reads consecutive registers from the device and refreshes the shadow.
+    async fn fetch_registers(
+        &mut self,
+        register: u8,
+        buffer: &mut [u8],
+    ) -> Result<(), DS3231Error<E>> {
+        match self.interface.read_registers(register, buffer).await {
+            Ok(()) => {
+                self.cache.update(register, buffer);
+                Ok(())
+            }
+            Err(e) => Err(self.bus_error(e)),
+        }
+    }
+
+    /// Writes consecutive registers to the device and updates the shadow.
+    async fn write_registers(&mut self, register: u8, data: &[u8]) -> Result<(), DS3231Error<E>> {
+        match self.interface.write_registers(register, data).await {
+            Ok(()) => {
+                self.cache.update(register, data);
+                Ok(())
+            }
+            Err(e) => Err(self.bus_error(e)),
+        }
+    }
+
+    /// Applies the cache policy to a bus error and wraps it.
+    fn bus_error(&mut self, error: E) -> DS3231Error<E> {
+        if self.cache.on_error() {
+            self.time_representation = None;
+        }
+        DS3231Error::I2c(error)
+    }
+
     /// Reads the raw datetime registers from the device.
     ///
     /// # Returns
@@ -316,8 +774,7 @@ where
     /// * `Err(DS3231Error)` on error
     async fn read_raw_datetime(&mut self) -> Result<DS3231DateTime, DS3231Error<E>> {
         let mut data = [0; 7];
-        self.i2c
-            .write_read(self.address, &[RegAddr::Seconds as u8], &mut data)
+        self.read_registers(RegAddr::Seconds as u8, &mut data)
             .await?;
         Ok(data.into())
     }
@@ -331,25 +788,59 @@ where
     /// * `Ok(())` on success
     /// * `Err(DS3231Error)` on error
     async fn write_raw_datetime(&mut self, datetime: DS3231DateTime) -> Result<(), DS3231Error<E>> {
+        let datetime = datetime.with_weekday_numbering(self.weekday_numbering);
         let data: [u8; 7] = (&datetime).into();
-        self.i2c
-            .write(
-                self.address,
-                &[
-                    RegAddr::Seconds as u8,
-                    data[0],
-                    data[1],
-                    data[2],
-                    data[3],
-                    data[4],
-                    data[5],
-                    data[6],
-                ],
-            )
-            .await?;
+        self.write_registers(RegAddr::Seconds as u8, &data).await?;
         Ok(())
     }
 
+    /// Encodes an Alarm 1 configuration in the device's 12/24 hour format and weekday
+    /// numbering. The format is only looked up for alarms that match the hours.
+    async fn encode_alarm1(
+        &mut self,
+        config: &Alarm1Config,
+    ) -> Result<DS3231Alarm1, DS3231Error<E>> {
+        config.validate().map_err(DS3231Error::Alarm)?;
+        let time_representation = if config.uses_hours() {
+            self.current_time_representation().await?
+        } else {
+            TimeRepresentation::TwentyFourHour
+        };
+        DS3231Alarm1::encode(config, self.weekday_numbering, time_representation)
+            .map_err(DS3231Error::Alarm)
+    }
+
+    /// Encodes an Alarm 2 configuration in the device's 12/24 hour format and weekday
+    /// numbering. The format is only looked up for alarms that match the hours.
+    async fn encode_alarm2(
+        &mut self,
+        config: &Alarm2Config,
+    ) -> Result<DS3231Alarm2, DS3231Error<E>> {
+        config.validate().map_err(DS3231Error::Alarm)?;
+        let time_representation = if config.uses_hours() {
+            self.current_time_representation().await?
+        } else {
+            TimeRepresentation::TwentyFourHour
+        };
+        DS3231Alarm2::encode(config, self.weekday_numbering, time_representation)
+            .map_err(DS3231Error::Alarm)
+    }
+
+    /// Returns the time representation the device is using.
+    ///
+    /// The hours register is read unless the cache policy trusts the cached setting and it is
+    /// known.
+    async fn current_time_representation(&mut self) -> Result<TimeRepresentation, DS3231Error<E>> {
+        if self.cache.policy() != CachePolicy::AlwaysRead {
+            if let Some(repr) = self.time_representation {
+                return Ok(repr);
+            }
+        }
+        // Read the hours register to determine the current time representation
+        let hours = self.hour().await?;
+        Ok(hours.time_representation())
+    }
+
     /// Gets the current date and time from the device.
     ///
     /// # Returns
@@ -357,13 +848,113 @@ where
     /// * `Err(DS3231Error)` on error
     ///
     /// # Errors
-    /// * Returns `DS3231Error::I2c` if there is an I2C communication error
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
     /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    #[cfg(feature = "chrono")]
     pub async fn datetime(&mut self) -> Result<NaiveDateTime, DS3231Error<E>> {
         let raw = self.read_raw_datetime().await?;
         raw.into_datetime().map_err(DS3231Error::DateTime)
     }
 
+    /// Gets the current local date and time, with the device keeping UTC.
+    ///
+    /// # Arguments
+    /// * `tz` - The local time zone
+    ///
+    /// # Returns
+    /// * `Ok(NaiveDateTime)` - The current local date and time
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    #[cfg(feature = "chrono")]
+    pub async fn local_datetime(&mut self, tz: &TimeZone) -> Result<NaiveDateTime, DS3231Error<E>> {
+        let utc = self.datetime().await?;
+        Ok(tz.to_local(&utc))
+    }
+
+    /// Sets the device to the UTC time of a local date and time.
+    ///
+    /// Local times that are skipped or repeated at DST transitions are resolved as described
+    /// in `TimeZone::to_utc`.
+    ///
+    /// # Arguments
+    /// * `tz` - The local time zone
+    /// * `local` - The local date and time to set
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the UTC time is invalid for the device
+    #[cfg(feature = "chrono")]
+    pub async fn set_local_datetime(
+        &mut self,
+        tz: &TimeZone,
+        local: &NaiveDateTime,
+    ) -> Result<(), DS3231Error<E>> {
+        self.set_datetime(&tz.to_utc(local)).await
+    }
+
+    /// Reads a consistent snapshot of the selected register groups.
+    ///
+    /// Adjacent groups are read in a single burst. Groups that are not adjacent are read in
+    /// several bursts and verified with a second read; see [`Snapshot`] for the guarantees.
+    ///
+    /// # Arguments
+    /// * `groups` - The register groups to capture
+    ///
+    /// # Returns
+    /// * `Ok(Snapshot)` - The captured registers on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::SnapshotUnstable` if no two consecutive reads matched
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// let snapshot = rtc.snapshot(RegisterGroups::TIME | RegisterGroups::STATUS).await?;
+    /// let now = snapshot.datetime().unwrap()?;
+    /// let alarm1_fired = snapshot.status().unwrap().alarm1_flag();
+    /// ```
+    pub async fn snapshot(&mut self, groups: RegisterGroups) -> Result<Snapshot, DS3231Error<E>> {
+        let mut snapshot = Snapshot::new(groups);
+        self.read_bursts(groups, &mut snapshot.registers).await?;
+        if groups.bursts().count() <= 1 {
+            return Ok(snapshot);
+        }
+
+        for _ in 1..MAX_SNAPSHOT_READS {
+            let mut registers = [0; SNAPSHOT_REGISTERS];
+            self.read_bursts(groups, &mut registers).await?;
+            if registers == snapshot.registers {
+                return Ok(snapshot);
+            }
+            debug!("DS3231: snapshot changed between reads, retrying");
+            snapshot.registers = registers;
+        }
+        Err(DS3231Error::SnapshotUnstable)
+    }
+
+    /// Reads the registers of `groups` into `registers`, one burst per run of adjacent
+    /// registers.
+    async fn read_bursts(
+        &mut self,
+        groups: RegisterGroups,
+        registers: &mut [u8; SNAPSHOT_REGISTERS],
+    ) -> Result<(), DS3231Error<E>> {
+        for (start, len) in groups.bursts() {
+            let start_usize = usize::from(start);
+            self.fetch_registers(start, &mut registers[start_usize..start_usize + len])
+                .await?;
+        }
+        Ok(())
+    }
+
     /// Sets the current date and time on the device.
     ///
     /// # Arguments
@@ -374,65 +965,254 @@ where
     /// * `Err(DS3231Error)` on error
     ///
     /// # Errors
-    /// * Returns `DS3231Error::I2c` if there is an I2C communication error
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
     /// * Returns `DS3231Error::DateTime` if the provided datetime is invalid for the device
+    #[cfg(feature = "chrono")]
     pub async fn set_datetime(&mut self, datetime: &NaiveDateTime) -> Result<(), DS3231Error<E>> {
-        let time_representation = if let Some(repr) = self.time_representation {
-            repr
-        } else {
-            // Read the hours register to determine the current time representation
-            let hours = self.hour().await?;
-            hours.time_representation()
-        };
-
+        let time_representation = self.current_time_representation().await?;
         let raw = DS3231DateTime::from_datetime(datetime, time_representation)
             .map_err(DS3231Error::DateTime)?;
         self.write_raw_datetime(raw).await?;
         Ok(())
     }
 
-    /// Gets the current Alarm 1 configuration from the device.
+    /// Gets the current date and time from the device as an [`RtcDateTime`].
+    ///
+    /// Unlike [`datetime`](Self::datetime) this needs neither chrono nor the `time` crate.
+    /// The day of the week is computed from the date as a [`DayOfWeek`]; its Day register
+    /// value is `weekday().number(self.weekday_numbering())`.
     ///
     /// # Returns
-    /// * `Ok(Alarm1Config)` - The current alarm 1 configuration
+    /// * `Ok(RtcDateTime)` - The current date and time
     /// * `Err(DS3231Error)` on error
     ///
     /// # Errors
-    /// * Returns `DS3231Error::I2c` if there is an I2C communication error
-    /// * Returns `DS3231Error::Alarm` if the device contains invalid alarm register values
-    pub async fn alarm1(&mut self) -> Result<Alarm1Config, DS3231Error<E>> {
-        let seconds = self.alarm1_second().await?;
-        let minutes = self.alarm1_minute().await?;
-        let hours = self.alarm1_hour().await?;
-        let day_date = self.alarm1_day_date().await?;
-
-        let alarm = DS3231Alarm1::from_registers(seconds, minutes, hours, day_date);
-        alarm.to_config().map_err(DS3231Error::Alarm)
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    pub async fn datetime_raw(&mut self) -> Result<RtcDateTime, DS3231Error<E>> {
+        let raw = self.read_raw_datetime().await?;
+        raw.into_rtc_datetime().map_err(DS3231Error::DateTime)
     }
 
-    /// Sets Alarm 1 configuration.
+    /// Sets the current date and time on the device from an [`RtcDateTime`].
     ///
-    /// Alarm 1 supports seconds-level precision and various matching modes.
+    /// The Day register is written as `datetime.weekday().number(..)` in the driver's
+    /// `WeekdayNumbering`, like `set_datetime`.
     ///
     /// # Arguments
-    /// * `config` - The alarm configuration
+    /// * `datetime` - The date and time to set
     ///
     /// # Returns
     /// * `Ok(())` on success
     /// * `Err(DS3231Error)` on error
     ///
     /// # Errors
-    /// * Returns `DS3231Error::I2c` if there is an I2C communication error
-    /// * Returns `DS3231Error::Alarm` if the provided configuration is invalid
-    ///
-    /// # Examples
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn set_datetime_raw(&mut self, datetime: &RtcDateTime) -> Result<(), DS3231Error<E>> {
+        let time_representation = self.current_time_representation().await?;
+        let raw = DS3231DateTime::from_rtc_datetime(*datetime, time_representation)
+            .map_err(DS3231Error::DateTime)?;
+        self.write_raw_datetime(raw).await?;
+        Ok(())
+    }
+
+    /// Gets the current date and time from the device as a `time` crate `PrimitiveDateTime`.
     ///
-    /// ```rust,ignore
-    /// use ds3231::{DS3231, Alarm1Config};
+    /// # Returns
+    /// * `Ok(PrimitiveDateTime)` - The current date and time
+    /// * `Err(DS3231Error)` on error
     ///
-    /// // Daily alarm at 9:30:00 AM (24-hour mode)
-    /// let daily_alarm = Alarm1Config::AtTime {
-    ///     hours: 9,
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    #[cfg(feature = "time")]
+    pub async fn primitive_datetime(&mut self) -> Result<PrimitiveDateTime, DS3231Error<E>> {
+        let raw = self.read_raw_datetime().await?;
+        raw.into_primitive_datetime().map_err(DS3231Error::DateTime)
+    }
+
+    /// Sets the current date and time on the device from a `time` crate `PrimitiveDateTime`.
+    ///
+    /// # Arguments
+    /// * `datetime` - The date and time to set
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the provided datetime is invalid for the device
+    #[cfg(feature = "time")]
+    pub async fn set_primitive_datetime(
+        &mut self,
+        datetime: &PrimitiveDateTime,
+    ) -> Result<(), DS3231Error<E>> {
+        let time_representation = self.current_time_representation().await?;
+        let raw = DS3231DateTime::from_primitive_datetime(datetime, time_representation)
+            .map_err(DS3231Error::DateTime)?;
+        self.write_raw_datetime(raw).await?;
+        Ok(())
+    }
+
+    /// Gets the current date and time as a `time` crate `OffsetDateTime`, treating the device
+    /// time as UTC.
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - The current date and time in UTC
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    #[cfg(feature = "time")]
+    pub async fn offset_datetime(&mut self) -> Result<OffsetDateTime, DS3231Error<E>> {
+        Ok(self.primitive_datetime().await?.assume_utc())
+    }
+
+    /// Sets the device to the UTC time of a `time` crate `OffsetDateTime`.
+    ///
+    /// # Arguments
+    /// * `datetime` - The date and time to set, in any offset
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the UTC time is invalid for the device
+    #[cfg(feature = "time")]
+    pub async fn set_offset_datetime(
+        &mut self,
+        datetime: &OffsetDateTime,
+    ) -> Result<(), DS3231Error<E>> {
+        let utc = datetime
+            .checked_to_offset(UtcOffset::UTC)
+            .ok_or(DS3231Error::DateTime(DS3231DateTimeError::InvalidDateTime))?;
+        self.set_primitive_datetime(&PrimitiveDateTime::new(utc.date(), utc.time()))
+            .await
+    }
+
+    /// Gets the current time as a Unix timestamp, treating the device time as UTC.
+    ///
+    /// # Returns
+    /// * `Ok(i64)` - Seconds since 1970-01-01T00:00:00
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    pub async fn timestamp(&mut self) -> Result<i64, DS3231Error<E>> {
+        let raw = self.read_raw_datetime().await?;
+        raw.into_timestamp().map_err(DS3231Error::DateTime)
+    }
+
+    /// Sets the device time from a Unix timestamp, keeping the device in UTC.
+    ///
+    /// # Arguments
+    /// * `timestamp` - Seconds since 1970-01-01T00:00:00
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` with `YearNotAfter1999` or `YearNotBefore2200` if the
+    ///   timestamp is outside the device's 2000-2199 range
+    pub async fn set_timestamp(&mut self, timestamp: i64) -> Result<(), DS3231Error<E>> {
+        let time_representation = self.current_time_representation().await?;
+        let raw = DS3231DateTime::from_timestamp(timestamp, time_representation)
+            .map_err(DS3231Error::DateTime)?;
+        self.write_raw_datetime(raw).await?;
+        Ok(())
+    }
+
+    /// Gets the current time as seconds since 2000-01-01T00:00:00 UTC.
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - Seconds since 2000-01-01T00:00:00
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data, or
+    ///   `TimestampOverflow` if the device time is after 2136-02-07T06:28:15
+    pub async fn seconds_since_2000(&mut self) -> Result<u32, DS3231Error<E>> {
+        let timestamp = self.timestamp().await?;
+        u32::try_from(timestamp - Y2K_TIMESTAMP)
+            .map_err(|_| DS3231Error::DateTime(DS3231DateTimeError::TimestampOverflow))
+    }
+
+    /// Sets the device time from seconds since 2000-01-01T00:00:00 UTC.
+    ///
+    /// Every `u32` value is within the device's range.
+    ///
+    /// # Arguments
+    /// * `seconds` - Seconds since 2000-01-01T00:00:00
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn set_seconds_since_2000(&mut self, seconds: u32) -> Result<(), DS3231Error<E>> {
+        self.set_timestamp(Y2K_TIMESTAMP + i64::from(seconds)).await
+    }
+
+    /// Gets the current Alarm 1 configuration from the device.
+    ///
+    /// Hours are always returned in 24-hour form with `is_pm: None`, whether
+    /// the alarm was stored in 12-hour or 24-hour form, so the result does not
+    /// depend on the clock's current time representation. Registers that are not a
+    /// documented alarm, or that hold a value the clock never reaches, are returned as
+    /// `Alarm1Config::Raw`; see `DS3231Alarm1::alarm_match` to inspect them.
+    ///
+    /// # Returns
+    /// * `Ok(Alarm1Config)` - The current alarm 1 configuration
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn alarm1(&mut self) -> Result<Alarm1Config, DS3231Error<E>> {
+        let mut data = [0; 4];
+        self.read_registers(RegAddr::Alarm1Seconds as u8, &mut data)
+            .await?;
+
+        let alarm = DS3231Alarm1::from_registers(
+            AlarmSeconds(data[0]),
+            AlarmMinutes(data[1]),
+            AlarmHours(data[2]),
+            AlarmDayDate(data[3]),
+        );
+        Ok(alarm.decode(self.weekday_numbering))
+    }
+
+    /// Sets Alarm 1 configuration.
+    ///
+    /// Alarm 1 supports seconds-level precision and various matching modes.
+    ///
+    /// # Arguments
+    /// * `config` - The alarm configuration
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::Alarm` if the provided configuration is invalid
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use ds3231::{DS3231, Alarm1Config};
+    ///
+    /// // Daily alarm at 9:30:00 AM (24-hour mode)
+    /// let daily_alarm = Alarm1Config::AtTime {
+    ///     hours: 9,
     ///     minutes: 30,
     ///     seconds: 0,
     ///     is_pm: None, // 24-hour mode
@@ -477,32 +1257,61 @@ where
     /// rtc.set_alarm1(&minutes_seconds_alarm).await?;
     /// ```
     pub async fn set_alarm1(&mut self, config: &Alarm1Config) -> Result<(), DS3231Error<E>> {
-        let alarm = DS3231Alarm1::from_config(config).map_err(DS3231Error::Alarm)?;
-
-        self.set_alarm1_second(alarm.seconds()).await?;
-        self.set_alarm1_minute(alarm.minutes()).await?;
-        self.set_alarm1_hour(alarm.hours()).await?;
-        self.set_alarm1_day_date(alarm.day_date()).await?;
+        self.set_alarm1_with(config, AlarmUpdate::Direct).await
+    }
 
-        Ok(())
+    /// Sets Alarm 1 configuration, optionally masking its interrupt during the update.
+    ///
+    /// The four alarm 1 registers (0x07-0x0A) are written in a single burst. With
+    /// `AlarmUpdate::MaskInterrupt` and the alarm 1 interrupt enabled, the interrupt is
+    /// disabled for the duration of the write and restored afterwards.
+    ///
+    /// # Arguments
+    /// * `config` - The alarm configuration
+    /// * `update` - How to treat the alarm interrupt during the update
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::Alarm` if the provided configuration is invalid
+    pub async fn set_alarm1_with(
+        &mut self,
+        config: &Alarm1Config,
+        update: AlarmUpdate,
+    ) -> Result<(), DS3231Error<E>> {
+        let alarm = self.encode_alarm1(config).await?;
+        self.write_alarm_registers(AlarmId::Alarm1, &alarm.to_registers(), update)
+            .await
     }
 
     /// Gets the current Alarm 2 configuration from the device.
     ///
+    /// Hours are always returned in 24-hour form with `is_pm: None`, whether
+    /// the alarm was stored in 12-hour or 24-hour form, so the result does not
+    /// depend on the clock's current time representation. Registers that are not a
+    /// documented alarm, or that hold a value the clock never reaches, are returned as
+    /// `Alarm2Config::Raw`; see `DS3231Alarm2::alarm_match` to inspect them.
+    ///
     /// # Returns
     /// * `Ok(Alarm2Config)` - The current alarm 2 configuration
     /// * `Err(DS3231Error)` on error
     ///
     /// # Errors
-    /// * Returns `DS3231Error::I2c` if there is an I2C communication error
-    /// * Returns `DS3231Error::Alarm` if the device contains invalid alarm register values
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
     pub async fn alarm2(&mut self) -> Result<Alarm2Config, DS3231Error<E>> {
-        let minutes = self.alarm2_minute().await?;
-        let hours = self.alarm2_hour().await?;
-        let day_date = self.alarm2_day_date().await?;
+        let mut data = [0; 3];
+        self.read_registers(RegAddr::Alarm2Minutes as u8, &mut data)
+            .await?;
 
-        let alarm = DS3231Alarm2::from_registers(minutes, hours, day_date);
-        alarm.to_config().map_err(DS3231Error::Alarm)
+        let alarm = DS3231Alarm2::from_registers(
+            AlarmMinutes(data[0]),
+            AlarmHours(data[1]),
+            AlarmDayDate(data[2]),
+        );
+        Ok(alarm.decode(self.weekday_numbering))
     }
 
     /// Sets Alarm 2 configuration.
@@ -518,7 +1327,7 @@ where
     /// * `Err(DS3231Error)` on error
     ///
     /// # Errors
-    /// * Returns `DS3231Error::I2c` if there is an I2C communication error
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
     /// * Returns `DS3231Error::Alarm` if the provided configuration is invalid
     ///
     /// # Examples
@@ -563,298 +1372,1216 @@ where
     /// rtc.set_alarm2(&minutes_alarm).await?;
     /// ```
     pub async fn set_alarm2(&mut self, config: &Alarm2Config) -> Result<(), DS3231Error<E>> {
-        let alarm = DS3231Alarm2::from_config(config).map_err(DS3231Error::Alarm)?;
-
-        self.set_alarm2_minute(alarm.minutes()).await?;
-        self.set_alarm2_hour(alarm.hours()).await?;
-        self.set_alarm2_day_date(alarm.day_date()).await?;
+        self.set_alarm2_with(config, AlarmUpdate::Direct).await
+    }
 
-        Ok(())
+    /// Sets Alarm 2 configuration, optionally masking its interrupt during the update.
+    ///
+    /// The three alarm 2 registers (0x0B-0x0D) are written in a single burst. With
+    /// `AlarmUpdate::MaskInterrupt` and the alarm 2 interrupt enabled, the interrupt is
+    /// disabled for the duration of the write and restored afterwards.
+    ///
+    /// # Arguments
+    /// * `config` - The alarm configuration
+    /// * `update` - How to treat the alarm interrupt during the update
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::Alarm` if the provided configuration is invalid
+    pub async fn set_alarm2_with(
+        &mut self,
+        config: &Alarm2Config,
+        update: AlarmUpdate,
+    ) -> Result<(), DS3231Error<E>> {
+        let alarm = self.encode_alarm2(config).await?;
+        self.write_alarm_registers(AlarmId::Alarm2, &alarm.to_registers(), update)
+            .await
     }
 
-    /// Gets the value of the hour register and caches the time representation.
+    /// Reads which alarms fired from the alarm flags (A1F, A2F) of the status register.
     ///
     /// # Returns
-    /// * `Ok(Hours)` - The register value on success
+    /// * `Ok(FiredAlarms)` - The alarm flags
     /// * `Err(DS3231Error)` on error
     ///
     /// # Errors
-    /// Returns `DS3231Error::I2c` if there is an I2C communication error
-    pub async fn hour(&mut self) -> Result<Hours, DS3231Error<E>> {
-        let mut data = [0];
-        self.i2c
-            .write_read(self.address, &[RegAddr::Hours as u8], &mut data)
-            .await?;
-        let hours = Hours(data[0]);
+    /// Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn alarms_fired(&mut self) -> Result<FiredAlarms, DS3231Error<E>> {
+        let status = self.status().await?;
+        Ok(FiredAlarms::from(status))
+    }
 
-        // Cache the time representation if not already cached
-        if self.time_representation.is_none() {
-            self.time_representation = Some(hours.time_representation());
+    /// Clears the flag of one alarm, which also releases INT if no other alarm holds it.
+    ///
+    /// The status register is only written if the flag is set. The write leaves the other
+    /// alarm flag and the oscillator stop flag as they are, even if they were raised after the
+    /// status was read, and keeps `EN32kHz` and the other control bits of the status register.
+    ///
+    /// # Arguments
+    /// * `alarm` - The alarm whose flag to clear
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn clear_alarm(&mut self, alarm: AlarmId) -> Result<(), DS3231Error<E>> {
+        let status = self.status().await?;
+        if alarm.flag(status) {
+            self.set_status(alarm.clear_flag(status)).await?;
         }
-
-        Ok(hours)
+        Ok(())
     }
 
-    /// Sets the value of the hour register and caches the time representation.
+    /// Enables or disables the interrupt of one alarm (A1IE/A2IE in the control register).
+    ///
+    /// The control register is only written if the setting changes. The alarm only asserts INT
+    /// with `InterruptControl::Interrupt` (INTCN) configured.
     ///
     /// # Arguments
-    /// * `value` - The value to write to the hour register
+    /// * `alarm` - The alarm whose interrupt to change
+    /// * `enable` - Whether the alarm asserts INT when it fires
     ///
     /// # Returns
     /// * `Ok(())` on success
     /// * `Err(DS3231Error)` on error
     ///
     /// # Errors
-    /// Returns `DS3231Error::I2c` if there is an I2C communication error
-    pub async fn set_hour(&mut self, value: Hours) -> Result<(), DS3231Error<E>> {
-        self.i2c
-            .write(self.address, &[RegAddr::Hours as u8, value.into()])
-            .await?;
-
-        // Cache the time representation
-        self.time_representation = Some(value.time_representation());
-
+    /// Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn enable_alarm_interrupt(
+        &mut self,
+        alarm: AlarmId,
+        enable: bool,
+    ) -> Result<(), DS3231Error<E>> {
+        let mut control = self.control().await?;
+        if alarm.interrupt_enabled(control) != enable {
+            alarm.set_interrupt_enabled(&mut control, enable);
+            self.set_control(control).await?;
+        }
         Ok(())
     }
 
-    /// Reads the temperature from both temperature registers and returns it as an f32.
+    /// Programs Alarm 1, clears its flag and enables its interrupt.
     ///
-    /// This method combines the integer temperature from register 0x11 and the fractional
-    /// temperature from register 0x12 to provide a precise temperature reading with 0.25°C resolution.
-    /// The temperature is encoded in two's complement format.
+    /// The steps run in the order that cannot produce a stale or spurious interrupt: the
+    /// interrupt is disabled, the alarm registers are written in one burst, A1F is cleared and
+    /// the interrupt is enabled again.
+    ///
+    /// # Arguments
+    /// * `config` - The alarm configuration
     ///
     /// # Returns
-    /// * `Ok(f32)` - The temperature in degrees Celsius with 0.25°C resolution
+    /// * `Ok(())` on success
     /// * `Err(DS3231Error)` on error
     ///
     /// # Errors
-    /// Returns `DS3231Error::I2c` if there is an I2C communication error.
-    ///
-    /// # Examples
-    /// ```rust,ignore
-    /// let temp = rtc.temperature_f32().await?;
-    /// println!("Temperature: {:.2}°C", temp);
-    /// ```
-    #[cfg(feature = "temperature_f32")]
-    pub async fn temperature_f32(&mut self) -> Result<f32, DS3231Error<E>> {
-        // Read both temperature registers in a single transaction
-        let mut data = [0; 2];
-        self.i2c
-            .write_read(self.address, &[RegAddr::MSBTemp as u8], &mut data)
-            .await?;
-
-        #[allow(clippy::cast_possible_wrap)]
-        let integer_part = data[0] as i8;
-        let fraction_bits = (data[1] >> 6) & 0x03;
-        let fractional_part = match fraction_bits {
-            0b00 => 0.00,
-            0b01 => 0.25,
-            0b10 => 0.50,
-            0b11 => 0.75,
-            _ => unreachable!(), // Should not happen as we mask with 0x03
-        };
-
-        Ok(f32::from(integer_part) + fractional_part)
-    }
-
-    impl_register_access!(
-        (second, RegAddr::Seconds, Seconds),
-        (minute, RegAddr::Minutes, Minutes),
-        (day, RegAddr::Day, Day),
-        (date, RegAddr::Date, Date),
-        (month, RegAddr::Month, Month),
-        (year, RegAddr::Year, Year),
-        (alarm1_second, RegAddr::Alarm1Seconds, AlarmSeconds),
-        (alarm1_minute, RegAddr::Alarm1Minutes, AlarmMinutes),
-        (alarm1_hour, RegAddr::Alarm1Hours, AlarmHours),
-        (alarm1_day_date, RegAddr::Alarm1DayDate, AlarmDayDate),
-        (alarm2_minute, RegAddr::Alarm2Minutes, AlarmMinutes),
-        (alarm2_hour, RegAddr::Alarm2Hours, AlarmHours),
-        (alarm2_day_date, RegAddr::Alarm2DayDate, AlarmDayDate),
-        (control, RegAddr::Control, Control),
-        (status, RegAddr::ControlStatus, Status),
-        (aging_offset, RegAddr::AgingOffset, AgingOffset),
-        (temperature, RegAddr::MSBTemp, Temperature),
-        (temperature_fraction, RegAddr::LSBTemp, TemperatureFraction)
-    );
-}
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::Alarm` if the provided configuration is invalid
+    pub async fn arm_alarm1(&mut self, config: &Alarm1Config) -> Result<(), DS3231Error<E>> {
+        let alarm = self.encode_alarm1(config).await?;
+        self.arm_alarm(AlarmId::Alarm1, &alarm.to_registers()).await
+    }
 
-#[maybe_async_cfg::maybe(
-    sync(cfg(not(feature = "async")), keep_self),
-    async(feature = "async", keep_self)
-)]
-#[cfg(test)]
-mod tests {
-    extern crate alloc;
-    use alloc::vec;
+    /// Programs Alarm 2, clears its flag and enables its interrupt.
+    ///
+    /// See `arm_alarm1`.
+    ///
+    /// # Arguments
+    /// * `config` - The alarm configuration
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::Alarm` if the provided configuration is invalid
+    pub async fn arm_alarm2(&mut self, config: &Alarm2Config) -> Result<(), DS3231Error<E>> {
+        let alarm = self.encode_alarm2(config).await?;
+        self.arm_alarm(AlarmId::Alarm2, &alarm.to_registers()).await
+    }
 
-    use super::*;
-    use chrono::{Datelike, NaiveDate, Timelike};
-    use embedded_hal::i2c::ErrorKind as I2cErrorKind;
-    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+    /// Sets Alarm 1 to a local time, with the device keeping UTC.
+    ///
+    /// Reads the current time and programs the UTC equivalent of the next occurrence of the
+    /// local alarm (see `TimeZone::alarm1_to_utc`). Call it again after the alarm fires so that
+    /// DST transitions are followed.
+    ///
+    /// # Arguments
+    /// * `tz` - The local time zone
+    /// * `local` - The alarm configuration in local time
+    ///
+    /// # Returns
+    /// * `Ok(Alarm1Config)` - The UTC configuration that was written
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    /// * Returns `DS3231Error::Alarm` if the provided configuration is invalid
+    #[cfg(feature = "chrono")]
+    pub async fn set_local_alarm1(
+        &mut self,
+        tz: &TimeZone,
+        local: &Alarm1Config,
+    ) -> Result<Alarm1Config, DS3231Error<E>> {
+        let now = self.datetime().await?;
+        let config = tz.alarm1_to_utc(local, &now).map_err(DS3231Error::Alarm)?;
+        self.set_alarm1(&config).await?;
+        Ok(config)
+    }
 
-    const DEVICE_ADDRESS: u8 = 0x68;
+    /// Sets Alarm 2 to a local time, with the device keeping UTC.
+    ///
+    /// See `set_local_alarm1`.
+    ///
+    /// # Arguments
+    /// * `tz` - The local time zone
+    /// * `local` - The alarm configuration in local time
+    ///
+    /// # Returns
+    /// * `Ok(Alarm2Config)` - The UTC configuration that was written
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    /// * Returns `DS3231Error::Alarm` if the provided configuration is invalid
+    #[cfg(feature = "chrono")]
+    pub async fn set_local_alarm2(
+        &mut self,
+        tz: &TimeZone,
+        local: &Alarm2Config,
+    ) -> Result<Alarm2Config, DS3231Error<E>> {
+        let now = self.datetime().await?;
+        let config = tz.alarm2_to_utc(local, &now).map_err(DS3231Error::Alarm)?;
+        self.set_alarm2(&config).await?;
+        Ok(config)
+    }
 
-    fn setup_mock(expectations: &[I2cTrans]) -> I2cMock {
-        I2cMock::new(expectations)
+    /// Sets Alarm 1 to fire once, `duration` from now.
+    ///
+    /// The time is read once and the alarm matches the date, hours, minutes and seconds of the
+    /// earliest whole second at which `duration` has certainly passed, so it never fires early
+    /// and fires at most about a second late. A date alarm comes round again a month later, so
+    /// `duration` must be shorter than the month: up to 28 days is always accepted.
+    ///
+    /// # Arguments
+    /// * `duration` - How long to wait
+    ///
+    /// # Returns
+    /// * `Ok(NaiveDateTime)` - The device time at which the alarm fires
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    /// * Returns `DS3231Error::Alarm` if `duration` is too long for a date alarm
+    #[cfg(feature = "chrono")]
+    pub async fn set_alarm1_after(
+        &mut self,
+        duration: Duration,
+    ) -> Result<NaiveDateTime, DS3231Error<E>> {
+        let now = self.datetime().await?;
+        let (at, config) = trigger::one_shot_time(&now, duration, false)
+            .map(|at| (at, scheduler::alarm1_at(&at)))
+            .filter(|(at, config)| config.next_trigger(&now) == Some(*at))
+            .ok_or(DS3231Error::Alarm(ALARM_DURATION_TOO_LONG))?;
+        self.set_alarm1(&config).await?;
+        Ok(at)
     }
 
-    // Test register access macro - generates comprehensive test functions for register operations
-    macro_rules! test_register_access {
-        ($(($name:ident, $regaddr:expr, $typ:ty, $test_read_value:expr, $test_write_value:expr)),+) => {
-            $(
-                #[cfg(feature = "async")]
-                paste! {
-                    #[tokio::test]
-                    async fn [<test_read_ $name>]() {
-                        let expected = $test_read_value;
-                        let mock = setup_mock(&[I2cTrans::write_read(
-                            DEVICE_ADDRESS,
-                            vec![$regaddr as u8],
-                            vec![expected],
-                        )]);
-                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+    /// Sets Alarm 2 to fire once, `duration` from now.
+    ///
+    /// See `set_alarm1_after`. Alarm 2 fires at second 00, so the time is rounded up to the
+    /// next whole minute and the alarm can fire up to a minute late.
+    ///
+    /// # Arguments
+    /// * `duration` - How long to wait
+    ///
+    /// # Returns
+    /// * `Ok(NaiveDateTime)` - The device time at which the alarm fires
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    /// * Returns `DS3231Error::Alarm` if `duration` is too long for a date alarm
+    #[cfg(feature = "chrono")]
+    pub async fn set_alarm2_after(
+        &mut self,
+        duration: Duration,
+    ) -> Result<NaiveDateTime, DS3231Error<E>> {
+        let now = self.datetime().await?;
+        let (at, config) = trigger::one_shot_time(&now, duration, true)
+            .map(|at| (at, scheduler::alarm2_at(&at)))
+            .filter(|(at, config)| config.next_trigger(&now) == Some(*at))
+            .ok_or(DS3231Error::Alarm(ALARM_DURATION_TOO_LONG))?;
+        self.set_alarm2(&config).await?;
+        Ok(at)
+    }
 
-                        let result = dev.$name().await.unwrap();
-                        assert_eq!(result.0, expected);
-                        dev.i2c.done();
-                    }
+    /// Services an alarm scheduler: reports the logical alarms that are due and programs the
+    /// next one into the scheduler's hardware alarm.
+    ///
+    /// Call it at startup, after adding or removing alarms, and whenever the hardware alarm
+    /// fires. The hardware alarm's flag is cleared and the alarm is written with
+    /// `AlarmUpdate::MaskInterrupt`; enabling the alarm interrupt is left to the caller. If the
+    /// clock reaches the programmed time while it is written, the due alarms are collected
+    /// again so that none is skipped. See the [`scheduler`] module.
+    ///
+    /// The hardware alarm matches the date of the month and the time, not the month. An alarm
+    /// due more than a month ahead therefore also fires on the same date in the months before;
+    /// servicing the scheduler then reports nothing and programs the same alarm again.
+    ///
+    /// # Arguments
+    /// * `scheduler` - The logical alarms
+    ///
+    /// # Returns
+    /// * `Ok(DueAlarms)` - The logical alarms that were due
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    #[cfg(feature = "chrono")]
+    pub async fn service_scheduler<T: Clone, const N: usize>(
+        &mut self,
+        scheduler: &mut AlarmScheduler<T, N>,
+    ) -> Result<DueAlarms<T, N>, DS3231Error<E>> {
+        let alarm = scheduler.alarm();
+        self.clear_alarm(alarm).await?;
+
+        let mut due = DueAlarms::new();
+        let mut now = self.datetime().await?;
+        for _ in 0..SCHEDULER_MAX_PASSES {
+            scheduler.take_due(&now, &mut due);
+            let Some(at) = scheduler.arm() else {
+                break;
+            };
+            match alarm {
+                AlarmId::Alarm1 => {
+                    let config = scheduler::alarm1_at(&at);
+                    self.set_alarm1_with(&config, AlarmUpdate::MaskInterrupt)
+                        .await?;
+                }
+                AlarmId::Alarm2 => {
+                    let config = scheduler::alarm2_at(&at);
+                    self.set_alarm2_with(&config, AlarmUpdate::MaskInterrupt)
+                        .await?;
+                }
+            }
+            now = self.datetime().await?;
+            if at > now {
+                break;
+            }
+        }
+        Ok(due)
+    }
 
-                    #[tokio::test]
-                    async fn [<test_read_ $name _with_error>]() {
-                        let expected = $test_read_value;
-                        let mock = setup_mock(&[I2cTrans::write_read(
-                            DEVICE_ADDRESS,
-                            vec![$regaddr as u8],
-                            vec![expected],
-                        )
-                        .with_error(I2cErrorKind::Other)]);
-                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+    /// Waits for an alarm on the INT/SQW pin and clears the alarm flags that are set.
+    ///
+    /// INT is active low and stays low while an enabled alarm flag is set, so this waits for
+    /// the pin to be low (`Wait::wait_for_low`) rather than for a falling edge: an alarm that
+    /// fired before the call, or between a status read and the start of an edge wait, is
+    /// returned right away instead of being missed. The pin only has to support level waits.
+    /// The device has to be configured with `InterruptControl::Interrupt` and the alarm
+    /// interrupts enabled.
+    ///
+    /// The flags are cleared with a single status write that leaves a flag raised after the
+    /// status was read, and the oscillator stop flag, as they are.
+    ///
+    /// # Arguments
+    /// * `int_pin` - The input connected to INT/SQW
+    ///
+    /// # Returns
+    /// * `Ok(AlarmEvent)` - The alarms that fired
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::Pin` if waiting for the pin fails
+    #[maybe_async_cfg::only_if(async)]
+    pub async fn wait_for_alarm<P: Wait>(
+        &mut self,
+        int_pin: &mut P,
+    ) -> Result<AlarmEvent, DS3231Error<E>> {
+        loop {
+            int_pin
+                .wait_for_low()
+                .await
+                .map_err(|e| DS3231Error::Pin(e.kind()))?;
+            if let Some(event) = self.take_alarm_event().await? {
+                return Ok(event);
+            }
+            // INT is low without an alarm flag, e.g. another device on a shared line
+            int_pin
+                .wait_for_high()
+                .await
+                .map_err(|e| DS3231Error::Pin(e.kind()))?;
+        }
+    }
 
-                        let err = dev.$name().await.unwrap_err();
-                        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
-                        dev.i2c.done();
-                    }
+    /// Waits for an alarm on the INT/SQW pin like `wait_for_alarm`, giving up after a timeout.
+    ///
+    /// The timeout only applies to waiting for the pin; once the pin is low the status is
+    /// read and the flags are cleared without interruption.
+    ///
+    /// # Arguments
+    /// * `int_pin` - The input connected to INT/SQW
+    /// * `delay` - The delay used for the timeout
+    /// * `timeout_ms` - How long to wait for an alarm, in milliseconds
+    ///
+    /// # Returns
+    /// * `Ok(Some(AlarmEvent))` - The alarms that fired
+    /// * `Ok(None)` - No alarm fired before the timeout
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::Pin` if waiting for the pin fails
+    #[maybe_async_cfg::only_if(async)]
+    pub async fn wait_for_alarm_timeout<P: Wait, D: DelayNs>(
+        &mut self,
+        int_pin: &mut P,
+        delay: &mut D,
+        timeout_ms: u32,
+    ) -> Result<Option<AlarmEvent>, DS3231Error<E>> {
+        let mut timeout = pin!(delay.delay_ms(timeout_ms));
+        loop {
+            match with_timeout(int_pin.wait_for_low(), timeout.as_mut()).await {
+                Some(result) => result.map_err(|e| DS3231Error::Pin(e.kind()))?,
+                None => return Ok(None),
+            }
+            if let Some(event) = self.take_alarm_event().await? {
+                return Ok(Some(event));
+            }
+            match with_timeout(int_pin.wait_for_high(), timeout.as_mut()).await {
+                Some(result) => result.map_err(|e| DS3231Error::Pin(e.kind()))?,
+                None => return Ok(None),
+            }
+        }
+    }
 
-                    #[tokio::test]
-                    async fn [<test_write_ $name>]() {
-                        let value = $typ($test_write_value);
-                        let mock = setup_mock(&[I2cTrans::write(
-                            DEVICE_ADDRESS,
-                            vec![$regaddr as u8, $test_write_value],
-                        )]);
-                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+    /// Reads the status and clears the alarm flags that are set.
+    #[maybe_async_cfg::only_if(async)]
+    async fn take_alarm_event(&mut self) -> Result<Option<AlarmEvent>, DS3231Error<E>> {
+        let status = self.status().await?;
+        let event = AlarmEvent::from_status(status);
+        if let Some(event) = event {
+            let cleared = clear_alarm_flags(
+                status,
+                event.includes(AlarmId::Alarm1),
+                event.includes(AlarmId::Alarm2),
+            );
+            self.set_status(cleared).await?;
+        }
+        Ok(event)
+    }
 
-                        dev.[<set_ $name>](value).await.unwrap();
-                        dev.i2c.done();
-                    }
+    /// Disables the interrupt of an alarm, writes its registers, clears its flag and enables
+    /// the interrupt.
+    async fn arm_alarm(&mut self, alarm: AlarmId, data: &[u8]) -> Result<(), DS3231Error<E>> {
+        self.enable_alarm_interrupt(alarm, false).await?;
+        self.write_registers(alarm.first_register() as u8, data)
+            .await?;
+        self.clear_alarm(alarm).await?;
+        self.enable_alarm_interrupt(alarm, true).await
+    }
 
-                    #[tokio::test]
-                    async fn [<test_write_ $name _with_error>]() {
-                        let value = $typ($test_write_value);
-                        let mock = setup_mock(&[I2cTrans::write(
-                            DEVICE_ADDRESS,
-                            vec![$regaddr as u8, $test_write_value],
-                        )
-                        .with_error(I2cErrorKind::Other)]);
-                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+    /// Writes the registers of one alarm in a single burst, masking its interrupt if requested.
+    async fn write_alarm_registers(
+        &mut self,
+        slot: AlarmId,
+        data: &[u8],
+        update: AlarmUpdate,
+    ) -> Result<(), DS3231Error<E>> {
+        let control = if update == AlarmUpdate::MaskInterrupt {
+            Some(self.control().await?).filter(|control| slot.interrupt_enabled(*control))
+        } else {
+            None
+        };
 
-                        let err = dev.[<set_ $name>](value).await.unwrap_err();
-                        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
-                        dev.i2c.done();
-                    }
-                }
-                #[cfg(not(feature = "async"))]
-                paste! {
-                    #[test]
-                    fn [<test_read_ $name>]() {
-                        let expected = $test_read_value;
-                        let mock = setup_mock(&[I2cTrans::write_read(
-                            DEVICE_ADDRESS,
-                            vec![$regaddr as u8],
-                            vec![expected],
-                        )]);
-                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+        if let Some(control) = control {
+            let flag_was_set = slot.flag(self.status().await?);
+            let mut masked = control;
+            slot.set_interrupt_enabled(&mut masked, false);
+            self.set_control(masked).await?;
 
-                        let result = dev.$name().unwrap();
-                        assert_eq!(result.0, expected);
-                        dev.i2c.done();
-                    }
+            self.write_registers(slot.first_register() as u8, data)
+                .await?;
 
-                    #[test]
-                    fn [<test_read_ $name _with_error>]() {
-                        let expected = $test_read_value;
-                        let mock = setup_mock(&[I2cTrans::write_read(
-                            DEVICE_ADDRESS,
-                            vec![$regaddr as u8],
-                            vec![expected],
-                        )
-                        .with_error(I2cErrorKind::Other)]);
-                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+            // Drop a flag raised by the partially written alarm before the interrupt comes back
+            let status = self.status().await?;
+            if !flag_was_set && slot.flag(status) {
+                self.set_status(slot.clear_flag(status)).await?;
+            }
+            self.set_control(control).await?;
+        } else {
+            self.write_registers(slot.first_register() as u8, data)
+                .await?;
+        }
+        Ok(())
+    }
 
-                        let err = dev.$name().unwrap_err();
-                        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
-                        dev.i2c.done();
-                    }
+    /// Gets the value of the hour register and caches the time representation.
+    ///
+    /// # Returns
+    /// * `Ok(Hours)` - The register value on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn hour(&mut self) -> Result<Hours, DS3231Error<E>> {
+        let mut data = [0];
+        self.read_registers(RegAddr::Hours as u8, &mut data).await?;
+        let hours = Hours(data[0]);
 
-                    #[test]
-                    fn [<test_write_ $name>]() {
-                        let value = $typ($test_write_value);
-                        let mock = setup_mock(&[I2cTrans::write(
-                            DEVICE_ADDRESS,
-                            vec![$regaddr as u8, $test_write_value],
-                        )]);
-                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+        // Cache the time representation, which may have been changed by someone else
+        self.time_representation = Some(hours.time_representation());
 
-                        dev.[<set_ $name>](value).unwrap();
-                        dev.i2c.done();
-                    }
+        Ok(hours)
+    }
 
-                    #[test]
-                    fn [<test_write_ $name _with_error>]() {
-                        let value = $typ($test_write_value);
-                        let mock = setup_mock(&[I2cTrans::write(
-                            DEVICE_ADDRESS,
-                            vec![$regaddr as u8, $test_write_value],
-                        )
-                        .with_error(I2cErrorKind::Other)]);
-                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+    /// Sets the value of the hour register and caches the time representation.
+    ///
+    /// # Arguments
+    /// * `value` - The value to write to the hour register
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn set_hour(&mut self, value: Hours) -> Result<(), DS3231Error<E>> {
+        self.write_registers(RegAddr::Hours as u8, &[value.into()])
+            .await?;
 
-                        let err = dev.[<set_ $name>](value).unwrap_err();
-                        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
-                        dev.i2c.done();
-                    }
-                }
-            )+
+        // Cache the time representation
+        self.time_representation = Some(value.time_representation());
+
+        Ok(())
+    }
+
+    /// Reads the temperature from both temperature registers as a fixed-point value.
+    ///
+    /// The integer part (register 0x11) and the fractional part (register 0x12) are read in a
+    /// single burst, so they always belong to the same conversion. The result has 0.25°C
+    /// resolution and needs no floating point support.
+    ///
+    /// # Returns
+    /// * `Ok(Celsius)` - The temperature on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// let temp = rtc.temperature_celsius().await?;
+    /// println!("Temperature: {}", temp); // e.g. "25.75°C"
+    /// ```
+    pub async fn temperature_celsius(&mut self) -> Result<Celsius, DS3231Error<E>> {
+        let mut data = [0; 2];
+        self.read_registers(RegAddr::MSBTemp as u8, &mut data)
+            .await?;
+        Ok(Celsius::from_registers(
+            Temperature(data[0]),
+            TemperatureFraction(data[1]),
+        ))
+    }
+
+    /// Forces a temperature conversion and returns the fresh reading.
+    ///
+    /// The device converts the temperature on its own every 64 seconds, so `temperature_celsius`
+    /// can return a value that old. This follows the datasheet procedure for a user-forced
+    /// conversion: wait until BSY is clear, set CONV, then poll until the device clears CONV,
+    /// and reads the temperature registers once the conversion is complete.
+    ///
+    /// # Arguments
+    /// * `delay` - Delay provider used between polls of the BSY and CONV bits
+    ///
+    /// # Returns
+    /// * `Ok(Celsius)` - The newly converted temperature on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::Timeout` if BSY or CONV did not clear within about 250 ms
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// let temp = rtc.force_temperature_conversion(&mut delay).await?;
+    /// ```
+    pub async fn force_temperature_conversion<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<Celsius, DS3231Error<E>> {
+        // Wait for a conversion started by the device itself to finish
+        let mut polls = 0;
+        while self.status().await?.busy() {
+            if polls == CONVERSION_MAX_POLLS {
+                return Err(DS3231Error::Timeout);
+            }
+            polls += 1;
+            delay.delay_ms(CONVERSION_POLL_MS).await;
+        }
+
+        let mut control = self.control().await?;
+        control.set_convert_temperature(true);
+        debug!("DS3231: starting temperature conversion");
+        self.set_control(control).await?;
+
+        // The device clears CONV when the conversion is complete. The shadow never holds CONV,
+        // so the control register is read from the device.
+        let mut polls = 0;
+        loop {
+            let mut data = [0];
+            self.fetch_registers(RegAddr::Control as u8, &mut data)
+                .await?;
+            if !Control(data[0]).convert_temperature() {
+                break;
+            }
+            if polls == CONVERSION_MAX_POLLS {
+                return Err(DS3231Error::Timeout);
+            }
+            polls += 1;
+            delay.delay_ms(CONVERSION_POLL_MS).await;
         }
+
+        self.temperature_celsius().await
     }
 
-    // Generate comprehensive register tests
-    test_register_access!(
-        (second, RegAddr::Seconds, Seconds, 0x45, 0x30),
-        (minute, RegAddr::Minutes, Minutes, 0x30, 0x45),
-        (day, RegAddr::Day, Day, 0x04, 0x02),
-        (date, RegAddr::Date, Date, 0x14, 0x10),
-        (month, RegAddr::Month, Month, 0x03, 0x06),
-        (year, RegAddr::Year, Year, 0x24, 0x25),
-        (
-            alarm1_second,
-            RegAddr::Alarm1Seconds,
-            AlarmSeconds,
-            0x30,
-            0x00
-        ),
-        (
-            alarm1_minute,
-            RegAddr::Alarm1Minutes,
-            AlarmMinutes,
-            0x45,
-            0x15
-        ),
-        (alarm1_hour, RegAddr::Alarm1Hours, AlarmHours, 0x12, 0x09),
-        (
-            alarm1_day_date,
-            RegAddr::Alarm1DayDate,
+    /// Compares the RTC to a reference time and adds the result to a drift estimator.
+    ///
+    /// Call this right after obtaining the reference time (e.g. from NTP or GPS). The
+    /// temperature is recorded with the sample. See [`DriftEstimator`].
+    ///
+    /// # Arguments
+    /// * `reference` - The reference time
+    /// * `estimator` - The estimator to add the sample to
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    /// * Returns `DS3231Error::DateTime` if the device returns invalid date/time data
+    #[cfg(feature = "chrono")]
+    pub async fn sample_drift(
+        &mut self,
+        reference: &NaiveDateTime,
+        estimator: &mut DriftEstimator,
+    ) -> Result<(), DS3231Error<E>> {
+        let rtc = self.datetime().await?;
+        let temperature = self.temperature_celsius().await?;
+        estimator.add_sample(reference, &rtc, Some(temperature));
+        Ok(())
+    }
+
+    /// Writes the aging offset recommended by a drift estimator.
+    ///
+    /// The estimator's samples must have been taken with the current aging offset in effect.
+    /// The new offset takes effect at the next temperature conversion, and the drift
+    /// measured before it no longer applies, so reset the estimator afterwards.
+    ///
+    /// # Arguments
+    /// * `estimator` - The estimator holding the drift samples
+    ///
+    /// # Returns
+    /// * `Ok(Some(AgingOffset))` - The aging offset that was written
+    /// * `Ok(None)` - The estimator has no drift estimate yet; nothing was written
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error.
+    #[cfg(feature = "chrono")]
+    pub async fn apply_aging_offset(
+        &mut self,
+        estimator: &DriftEstimator,
+    ) -> Result<Option<AgingOffset>, DS3231Error<E>> {
+        let current = self.aging_offset().await?;
+        match estimator.recommended_aging_offset(current.aging_offset()) {
+            Some(value) => {
+                let mut offset = AgingOffset::default();
+                offset.set_aging_offset(value);
+                debug!("DS3231: setting aging offset to {}", value);
+                self.set_aging_offset(offset).await?;
+                Ok(Some(offset))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Reads the temperature from both temperature registers and returns it as an f32.
+    ///
+    /// This is `temperature_celsius` converted to degrees Celsius as an `f32`.
+    ///
+    /// # Returns
+    /// * `Ok(f32)` - The temperature in degrees Celsius with 0.25°C resolution
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// let temp = rtc.temperature_f32().await?;
+    /// println!("Temperature: {:.2}°C", temp);
+    /// ```
+    #[cfg(feature = "temperature_f32")]
+    pub async fn temperature_f32(&mut self) -> Result<f32, DS3231Error<E>> {
+        Ok(self.temperature_celsius().await?.to_f32())
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(DS3231(sync, async = "DS3231Async")),
+    sync(),
+    async(feature = "async")
+)]
+impl<I2C> DS3231<I2cInterface<I2C>, DS3232Chip> {
+    /// Creates a new driver instance for a DS3232.
+    ///
+    /// The DS3232 shares the DS3231 register map and adds battery-backed SRAM, the
+    /// temperature control register and extra status bits, all of which are available on
+    /// the returned driver.
+    ///
+    /// # Arguments
+    /// * `i2c` - The I2C bus implementation
+    /// * `address` - The I2C address of the device (typically 0x68)
+    pub fn new_ds3232(i2c: I2C, address: u8) -> Self {
+        Self::with_interface(I2cInterface::new(i2c, address))
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(DS3231(sync, async = "DS3231Async")),
+    sync(),
+    async(feature = "async")
+)]
+impl<SPI> DS3231<SpiInterface<SPI>, DS3234Chip> {
+    /// Creates a new driver instance for a DS3234.
+    ///
+    /// The DS3234 is the SPI version of the DS3232. The timekeeping, alarm, control and
+    /// temperature registers work exactly as on the DS3231; the SRAM and temperature control
+    /// register are also available on the returned driver.
+    ///
+    /// # Arguments
+    /// * `spi` - The SPI device implementation (mode 1 or 3)
+    pub fn new_ds3234(spi: SPI) -> Self {
+        Self::with_interface(SpiInterface::new(spi))
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        DS3231(sync, async = "DS3231Async"),
+        Interface(sync, async = "AsyncInterface")
+    ),
+    sync(),
+    async(feature = "async")
+)]
+impl<IF, E, C> DS3231<IF, C>
+where
+    IF: Interface<Error = E>,
+    C: SramChip,
+{
+    /// Gets the value of the temperature control register.
+    ///
+    /// # Returns
+    /// * `Ok(TemperatureControl)` - The register value on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn temperature_control(&mut self) -> Result<TemperatureControl, DS3231Error<E>> {
+        let mut data = [0];
+        self.read_registers(RegAddr::TemperatureControl as u8, &mut data)
+            .await?;
+        Ok(TemperatureControl(data[0]))
+    }
+
+    /// Sets the value of the temperature control register.
+    ///
+    /// # Arguments
+    /// * `value` - The value to write to the temperature control register
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn set_temperature_control(
+        &mut self,
+        value: TemperatureControl,
+    ) -> Result<(), DS3231Error<E>> {
+        self.write_registers(RegAddr::TemperatureControl as u8, &[value.into()])
+            .await?;
+        Ok(())
+    }
+
+    /// Reads a burst of bytes from the battery-backed SRAM.
+    ///
+    /// # Arguments
+    /// * `offset` - Offset into the SRAM (0-235 on the DS3232, 0-255 on the DS3234) of the
+    ///   first byte to read
+    /// * `buffer` - Buffer to fill with the SRAM contents
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::SramOutOfRange` if the read extends past the end of the SRAM
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn read_sram(&mut self, offset: u8, buffer: &mut [u8]) -> Result<(), DS3231Error<E>> {
+        check_sram_range::<C, E>(offset, buffer.len())?;
+        if buffer.is_empty() {
+            return Ok(());
+        }
+        if C::INDIRECT_SRAM {
+            // The SRAM address auto-increments on each access to the data register
+            self.write_registers(RegAddr::SramAddress as u8, &[offset])
+                .await?;
+            self.read_registers(RegAddr::SramData as u8, buffer).await?;
+        } else {
+            self.read_registers(RegAddr::SramStart as u8 + offset, buffer)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Writes a burst of bytes to the battery-backed SRAM.
+    ///
+    /// # Arguments
+    /// * `offset` - Offset into the SRAM (0-235 on the DS3232, 0-255 on the DS3234) of the
+    ///   first byte to write
+    /// * `data` - The bytes to write
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::SramOutOfRange` if the write extends past the end of the SRAM
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn write_sram(&mut self, offset: u8, data: &[u8]) -> Result<(), DS3231Error<E>> {
+        check_sram_range::<C, E>(offset, data.len())?;
+        if data.is_empty() {
+            return Ok(());
+        }
+        if C::INDIRECT_SRAM {
+            self.write_registers(RegAddr::SramAddress as u8, &[offset])
+                .await?;
+            self.write_registers(RegAddr::SramData as u8, data).await?;
+        } else {
+            self.write_registers(RegAddr::SramStart as u8 + offset, data)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Reads a single byte from the battery-backed SRAM.
+    ///
+    /// # Arguments
+    /// * `offset` - Offset into the SRAM (0-235 on the DS3232, 0-255 on the DS3234)
+    ///
+    /// # Returns
+    /// * `Ok(u8)` - The SRAM byte on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::SramOutOfRange` if the offset is outside the SRAM
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn sram_byte(&mut self, offset: u8) -> Result<u8, DS3231Error<E>> {
+        let mut data = [0];
+        self.read_sram(offset, &mut data).await?;
+        Ok(data[0])
+    }
+
+    /// Writes a single byte to the battery-backed SRAM.
+    ///
+    /// # Arguments
+    /// * `offset` - Offset into the SRAM (0-235 on the DS3232, 0-255 on the DS3234)
+    /// * `value` - The byte to write
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// * Returns `DS3231Error::SramOutOfRange` if the offset is outside the SRAM
+    /// * Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn set_sram_byte(&mut self, offset: u8, value: u8) -> Result<(), DS3231Error<E>> {
+        self.write_sram(offset, &[value]).await
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        DS3231(sync, async = "DS3231Async"),
+        Interface(sync, async = "AsyncInterface")
+    ),
+    sync(),
+    async(feature = "async")
+)]
+impl<IF, E> DS3231<IF, DS3234Chip>
+where
+    IF: Interface<Error = E>,
+{
+    /// Gets the value of the SRAM address register.
+    ///
+    /// # Returns
+    /// * `Ok(u8)` - The SRAM address of the next data register access on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn sram_address(&mut self) -> Result<u8, DS3231Error<E>> {
+        let mut data = [0];
+        self.read_registers(RegAddr::SramAddress as u8, &mut data)
+            .await?;
+        Ok(data[0])
+    }
+
+    /// Sets the value of the SRAM address register.
+    ///
+    /// # Arguments
+    /// * `value` - The SRAM address of the next data register access
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn set_sram_address(&mut self, value: u8) -> Result<(), DS3231Error<E>> {
+        self.write_registers(RegAddr::SramAddress as u8, &[value])
+            .await?;
+        Ok(())
+    }
+
+    /// Gets the value of the SRAM data register.
+    ///
+    /// Reads the SRAM byte at the current SRAM address, which then increments.
+    ///
+    /// # Returns
+    /// * `Ok(u8)` - The SRAM byte on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn sram_data(&mut self) -> Result<u8, DS3231Error<E>> {
+        let mut data = [0];
+        self.read_registers(RegAddr::SramData as u8, &mut data)
+            .await?;
+        Ok(data[0])
+    }
+
+    /// Sets the value of the SRAM data register.
+    ///
+    /// Writes the SRAM byte at the current SRAM address, which then increments.
+    ///
+    /// # Arguments
+    /// * `value` - The byte to write
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(DS3231Error)` on error
+    ///
+    /// # Errors
+    /// Returns `DS3231Error::I2c` if there is a bus communication error
+    pub async fn set_sram_data(&mut self, value: u8) -> Result<(), DS3231Error<E>> {
+        self.write_registers(RegAddr::SramData as u8, &[value])
+            .await?;
+        Ok(())
+    }
+}
+
+impl AlarmId {
+    fn first_register(self) -> RegAddr {
+        match self {
+            AlarmId::Alarm1 => RegAddr::Alarm1Seconds,
+            AlarmId::Alarm2 => RegAddr::Alarm2Minutes,
+        }
+    }
+
+    fn interrupt_enabled(self, control: Control) -> bool {
+        match self {
+            AlarmId::Alarm1 => control.alarm1_interrupt_enable(),
+            AlarmId::Alarm2 => control.alarm2_interrupt_enable(),
+        }
+    }
+
+    fn set_interrupt_enabled(self, control: &mut Control, enabled: bool) {
+        match self {
+            AlarmId::Alarm1 => control.set_alarm1_interrupt_enable(enabled),
+            AlarmId::Alarm2 => control.set_alarm2_interrupt_enable(enabled),
+        }
+    }
+
+    fn flag(self, status: Status) -> bool {
+        match self {
+            AlarmId::Alarm1 => status.alarm1_flag(),
+            AlarmId::Alarm2 => status.alarm2_flag(),
+        }
+    }
+
+    /// Returns the status value that clears this alarm's flag and leaves the other flags set.
+    fn clear_flag(self, status: Status) -> Status {
+        clear_alarm_flags(status, self == AlarmId::Alarm1, self == AlarmId::Alarm2)
+    }
+}
+
+/// Returns the status value that clears the selected alarm flags and leaves the other flags
+/// set.
+///
+/// The flags can only be cleared by writing 0, so writing 1 to the oscillator stop flag and
+/// an alarm flag that is not cleared leaves them as they are.
+fn clear_alarm_flags(mut status: Status, alarm1: bool, alarm2: bool) -> Status {
+    status.set_oscillator_stop_flag(true);
+    status.set_alarm1_flag(!alarm1);
+    status.set_alarm2_flag(!alarm2);
+    status
+}
+
+/// Runs `future` to completion unless `timeout` completes first.
+#[cfg(feature = "async")]
+async fn with_timeout<F: Future>(
+    future: F,
+    mut timeout: Pin<&mut impl Future<Output = ()>>,
+) -> Option<F::Output> {
+    let mut future = pin!(future);
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            Poll::Ready(Some(output))
+        } else if timeout.as_mut().poll(cx).is_ready() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// Checks that an SRAM access of `len` bytes at `offset` stays within the chip's SRAM.
+fn check_sram_range<C: Chip, E>(offset: u8, len: usize) -> Result<(), DS3231Error<E>> {
+    let offset_usize = usize::from(offset);
+    if offset_usize >= C::SRAM_SIZE || offset_usize + len > C::SRAM_SIZE {
+        return Err(DS3231Error::SramOutOfRange);
+    }
+    Ok(())
+}
+
+impl_register_access!(
+    (second, RegAddr::Seconds, Seconds),
+    (minute, RegAddr::Minutes, Minutes),
+    (day, RegAddr::Day, Day),
+    (date, RegAddr::Date, Date),
+    (month, RegAddr::Month, Month),
+    (year, RegAddr::Year, Year),
+    (alarm1_second, RegAddr::Alarm1Seconds, AlarmSeconds),
+    (alarm1_minute, RegAddr::Alarm1Minutes, AlarmMinutes),
+    (alarm1_hour, RegAddr::Alarm1Hours, AlarmHours),
+    (alarm1_day_date, RegAddr::Alarm1DayDate, AlarmDayDate),
+    (alarm2_minute, RegAddr::Alarm2Minutes, AlarmMinutes),
+    (alarm2_hour, RegAddr::Alarm2Hours, AlarmHours),
+    (alarm2_day_date, RegAddr::Alarm2DayDate, AlarmDayDate),
+    (control, RegAddr::Control, Control),
+    (status, RegAddr::ControlStatus, Status),
+    (aging_offset, RegAddr::AgingOffset, AgingOffset),
+    (temperature, RegAddr::MSBTemp, Temperature),
+    (temperature_fraction, RegAddr::LSBTemp, TemperatureFraction)
+);
+
+#[cfg(test)]
+mod register_access_tests {
+    extern crate alloc;
+    use alloc::vec;
+
+    use super::*;
+    use embedded_hal::i2c::ErrorKind as I2cErrorKind;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+
+    const DEVICE_ADDRESS: u8 = 0x68;
+
+    fn setup_mock(expectations: &[I2cTrans]) -> I2cMock {
+        I2cMock::new(expectations)
+    }
+
+    // Test register access macro - generates comprehensive test functions for register operations
+    macro_rules! test_register_access {
+        ($(($name:ident, $regaddr:expr, $typ:ty, $test_read_value:expr, $test_write_value:expr)),+) => {
+            #[cfg(feature = "async")]
+            mod async_tests {
+                use super::*;
+            $(
+                paste! {
+                    #[tokio::test]
+                    async fn [<test_read_ $name>]() {
+                        let expected = $test_read_value;
+                        let mock = setup_mock(&[I2cTrans::write_read(
+                            DEVICE_ADDRESS,
+                            vec![$regaddr as u8],
+                            vec![expected],
+                        )]);
+                        let mut dev = DS3231Async::new(mock, DEVICE_ADDRESS);
+
+                        let result = dev.$name().await.unwrap();
+                        assert_eq!(result.0, expected);
+                        dev.interface.i2c.done();
+                    }
+
+                    #[tokio::test]
+                    async fn [<test_read_ $name _with_error>]() {
+                        let expected = $test_read_value;
+                        let mock = setup_mock(&[I2cTrans::write_read(
+                            DEVICE_ADDRESS,
+                            vec![$regaddr as u8],
+                            vec![expected],
+                        )
+                        .with_error(I2cErrorKind::Other)]);
+                        let mut dev = DS3231Async::new(mock, DEVICE_ADDRESS);
+
+                        let err = dev.$name().await.unwrap_err();
+                        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
+                        dev.interface.i2c.done();
+                    }
+
+                    #[tokio::test]
+                    async fn [<test_write_ $name>]() {
+                        let value = $typ($test_write_value);
+                        let mock = setup_mock(&[I2cTrans::write(
+                            DEVICE_ADDRESS,
+                            vec![$regaddr as u8, $test_write_value],
+                        )]);
+                        let mut dev = DS3231Async::new(mock, DEVICE_ADDRESS);
+
+                        dev.[<set_ $name>](value).await.unwrap();
+                        dev.interface.i2c.done();
+                    }
+
+                    #[tokio::test]
+                    async fn [<test_write_ $name _with_error>]() {
+                        let value = $typ($test_write_value);
+                        let mock = setup_mock(&[I2cTrans::write(
+                            DEVICE_ADDRESS,
+                            vec![$regaddr as u8, $test_write_value],
+                        )
+                        .with_error(I2cErrorKind::Other)]);
+                        let mut dev = DS3231Async::new(mock, DEVICE_ADDRESS);
+
+                        let err = dev.[<set_ $name>](value).await.unwrap_err();
+                        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
+                        dev.interface.i2c.done();
+                    }
+                }
+            )+
+            }
+
+            mod blocking_tests {
+                use super::*;
+            $(
+                paste! {
+                    #[test]
+                    fn [<test_read_ $name>]() {
+                        let expected = $test_read_value;
+                        let mock = setup_mock(&[I2cTrans::write_read(
+                            DEVICE_ADDRESS,
+                            vec![$regaddr as u8],
+                            vec![expected],
+                        )]);
+                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+                        let result = dev.$name().unwrap();
+                        assert_eq!(result.0, expected);
+                        dev.interface.i2c.done();
+                    }
+
+                    #[test]
+                    fn [<test_read_ $name _with_error>]() {
+                        let expected = $test_read_value;
+                        let mock = setup_mock(&[I2cTrans::write_read(
+                            DEVICE_ADDRESS,
+                            vec![$regaddr as u8],
+                            vec![expected],
+                        )
+                        .with_error(I2cErrorKind::Other)]);
+                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+                        let err = dev.$name().unwrap_err();
+                        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
+                        dev.interface.i2c.done();
+                    }
+
+                    #[test]
+                    fn [<test_write_ $name>]() {
+                        let value = $typ($test_write_value);
+                        let mock = setup_mock(&[I2cTrans::write(
+                            DEVICE_ADDRESS,
+                            vec![$regaddr as u8, $test_write_value],
+                        )]);
+                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+                        dev.[<set_ $name>](value).unwrap();
+                        dev.interface.i2c.done();
+                    }
+
+                    #[test]
+                    fn [<test_write_ $name _with_error>]() {
+                        let value = $typ($test_write_value);
+                        let mock = setup_mock(&[I2cTrans::write(
+                            DEVICE_ADDRESS,
+                            vec![$regaddr as u8, $test_write_value],
+                        )
+                        .with_error(I2cErrorKind::Other)]);
+                        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+                        let err = dev.[<set_ $name>](value).unwrap_err();
+                        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
+                        dev.interface.i2c.done();
+                    }
+                }
+            )+
+            }
+        }
+    }
+
+    // Generate comprehensive register tests
+    test_register_access!(
+        (second, RegAddr::Seconds, Seconds, 0x45, 0x30),
+        (minute, RegAddr::Minutes, Minutes, 0x30, 0x45),
+        (day, RegAddr::Day, Day, 0x04, 0x02),
+        (date, RegAddr::Date, Date, 0x14, 0x10),
+        (month, RegAddr::Month, Month, 0x03, 0x06),
+        (year, RegAddr::Year, Year, 0x24, 0x25),
+        (
+            alarm1_second,
+            RegAddr::Alarm1Seconds,
+            AlarmSeconds,
+            0x30,
+            0x00
+        ),
+        (
+            alarm1_minute,
+            RegAddr::Alarm1Minutes,
+            AlarmMinutes,
+            0x45,
+            0x15
+        ),
+        (alarm1_hour, RegAddr::Alarm1Hours, AlarmHours, 0x12, 0x09),
+        (
+            alarm1_day_date,
+            RegAddr::Alarm1DayDate,
             AlarmDayDate,
             0x15,
             0x10
@@ -885,222 +2612,1002 @@ mod tests {
             0x40,
             0x80
         )
-    );
+    );
+}
+
+#[maybe_async_cfg::maybe(
+    idents(DS3231(sync, async = "DS3231Async")),
+    sync(self = "blocking_tests"),
+    async(feature = "async", self = "async_tests")
+)]
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+    #[cfg(feature = "chrono")]
+    use chrono::{Datelike, NaiveDate, Timelike};
+    use embedded_hal::i2c::ErrorKind as I2cErrorKind;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    #[maybe_async_cfg::only_if(async)]
+    use embedded_hal_mock::eh1::digital::{
+        Mock as PinMock, State as PinState, Transaction as PinTrans,
+    };
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+    use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTrans};
+    // Driver tests run as plain tests in the blocking variant and on tokio in the async one
+    #[maybe_async_cfg::only_if(sync)]
+    use core::prelude::v1::test as driver_test;
+    #[maybe_async_cfg::only_if(async)]
+    use tokio::test as driver_test;
+
+    const DEVICE_ADDRESS: u8 = 0x68;
+
+    fn setup_mock(expectations: &[I2cTrans]) -> I2cMock {
+        I2cMock::new(expectations)
+    }
+
+    #[driver_test]
+    async fn test_new_device() {
+        let mock = setup_mock(&[]);
+        let mut _dev = DS3231::new(mock, DEVICE_ADDRESS);
+        // No I2C operations should happen during initialization
+        _dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_configure() {
+        let config = Config {
+            time_representation: TimeRepresentation::TwentyFourHour,
+            square_wave_frequency: SquareWaveFrequency::Hz1,
+            interrupt_control: InterruptControl::SquareWave,
+            battery_backed_square_wave: false,
+            oscillator_enable: Oscillator::Enabled,
+        };
+
+        let mock = setup_mock(&[
+            // Read control register
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0]),
+            // Write control register with Hz1 frequency (0b00 in bits 4,3)
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0b0000_0000]),
+            // Read the time and the alarm hours, already in 24-hour mode
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Seconds as u8], vec![0, 0, 0]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm1Hours as u8], vec![0]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm2Hours as u8], vec![0]),
+        ]);
+
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+        dev.configure(&config).await.unwrap();
+        // After configure, time_representation should be cached
+        assert_eq!(
+            dev.time_representation,
+            Some(TimeRepresentation::TwentyFourHour)
+        );
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_read_datetime() {
+        // 2024-03-14 15:30:00
+        let datetime_registers = [
+            0x00, // seconds
+            0x30, // minutes
+            0x15, // hours (24-hour mode)
+            0x05, // day (Thursday)
+            0x14, // date
+            0x03, // month
+            0x24, // year
+        ];
+
+        let mock = setup_mock(&[I2cTrans::write_read(
+            DEVICE_ADDRESS,
+            vec![RegAddr::Seconds as u8],
+            datetime_registers.to_vec(),
+        )]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let dt = dev.datetime().await.unwrap();
+        assert_eq!(dt.hour(), 15);
+        assert_eq!(dt.minute(), 30);
+        assert_eq!(dt.second(), 0);
+        assert_eq!(dt.day(), 14);
+        assert_eq!(dt.month(), 3);
+        assert_eq!(dt.year(), 2024);
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_read_datetime_with_error() {
+        // 2024-03-14 15:30:00
+        let datetime_registers = [
+            0x00, // seconds
+            0x30, // minutes
+            0x15, // hours (24-hour mode)
+            0x05, // day (Thursday)
+            0x14, // date
+            0x03, // month
+            0x24, // year
+        ];
+
+        let mock = setup_mock(&[I2cTrans::write_read(
+            DEVICE_ADDRESS,
+            vec![RegAddr::Seconds as u8],
+            datetime_registers.to_vec(),
+        )
+        .with_error(I2cErrorKind::Other)]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let err = dev.datetime().await.unwrap_err();
+        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
+
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_set_datetime() {
+        let dt = NaiveDate::from_ymd_opt(2024, 3, 14)
+            .unwrap()
+            .and_hms_opt(15, 30, 0)
+            .unwrap();
+
+        let mock = setup_mock(&[
+            // First, read the hours register to get the time representation
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Hours as u8],
+                vec![0x15], // 24-hour mode (bit 6 = 0)
+            ),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x00, // seconds
+                    0x30, // minutes (BCD for 30)
+                    0x15, // hours (BCD for 15 in 24-hour mode)
+                    0x05, // day (Thursday)
+                    0x14, // date
+                    0x03, // month
+                    0x24, // year
+                ],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        dev.set_datetime(&dt).await.unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_weekday_numbering() {
+        let dt = NaiveDate::from_ymd_opt(2024, 3, 14)
+            .unwrap()
+            .and_hms_opt(15, 30, 0)
+            .unwrap();
+        let sunday = Alarm1Config::AtTimeOnDay {
+            hours: 6,
+            minutes: 30,
+            seconds: 0,
+            day: 1,
+            is_pm: None,
+        };
+
+        let mock = setup_mock(&[
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x15]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x00,
+                    0x30,
+                    0x15,
+                    0x04, // Thursday, 1 = Monday
+                    0x14,
+                    0x03,
+                    0x24,
+                ],
+            ),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x15]),
+            // Sunday is 7 with DY set
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8, 0x00, 0x30, 0x06, 0x47],
+            ),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8],
+                vec![0x00, 0x30, 0x06, 0x47],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+        assert_eq!(dev.weekday_numbering(), WeekdayNumbering::SundayIsOne);
+        dev.set_weekday_numbering(WeekdayNumbering::ISO);
+
+        dev.set_datetime(&dt).await.unwrap();
+        dev.set_alarm1(&sunday).await.unwrap();
+        assert_eq!(dev.alarm1().await.unwrap(), sunday);
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_set_datetime_with_error() {
+        let dt = NaiveDate::from_ymd_opt(2024, 3, 14)
+            .unwrap()
+            .and_hms_opt(15, 30, 0)
+            .unwrap();
+
+        let mock = setup_mock(&[
+            // First, read the hours register to get the time representation
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Hours as u8],
+                vec![0x15], // 24-hour mode (bit 6 = 0)
+            ),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x00, // seconds
+                    0x30, // minutes (BCD for 30)
+                    0x15, // hours (BCD for 15 in 24-hour mode)
+                    0x05, // day (Thursday)
+                    0x14, // date
+                    0x03, // month
+                    0x24, // year
+                ],
+            )
+            .with_error(I2cErrorKind::Other),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let err = dev.set_datetime(&dt).await.unwrap_err();
+        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
+
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_register_operations() {
+        let mock = setup_mock(&[
+            // Test second register
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Seconds as u8], vec![0x45]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Seconds as u8, 0x30]),
+            // Test minute register
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Minutes as u8], vec![0x30]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Minutes as u8, 0x45]),
+            // Test status register
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x80],
+            ),
+        ]);
+
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        // Test seconds
+        let seconds = dev.second().await.unwrap();
+        assert_eq!(seconds.seconds(), 5);
+        assert_eq!(seconds.ten_seconds(), 4);
+        dev.set_second(Seconds(0x30)).await.unwrap();
+
+        // Test minutes
+        let minutes = dev.minute().await.unwrap();
+        assert_eq!(minutes.minutes(), 0);
+        assert_eq!(minutes.ten_minutes(), 3);
+        dev.set_minute(Minutes(0x45)).await.unwrap();
+
+        // Test status
+        let status = dev.status().await.unwrap();
+        assert!(status.oscillator_stop_flag());
+
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_force_temperature_conversion() {
+        let mock = setup_mock(&[
+            // Wait for an automatic conversion to finish
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x0C],
+            ),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x08],
+            ),
+            // Set CONV
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1C]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0x3C]),
+            // Poll until CONV clears
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x3C]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1C]),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::MSBTemp as u8],
+                vec![0x19, 0x40],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let temp = dev
+            .force_temperature_conversion(&mut NoopDelay::new())
+            .await
+            .unwrap();
+        assert_eq!(temp, Celsius::from_quarter_degrees(101));
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_force_temperature_conversion_busy_timeout() {
+        let expectations: Vec<_> = (0..=CONVERSION_MAX_POLLS)
+            .map(|_| {
+                I2cTrans::write_read(
+                    DEVICE_ADDRESS,
+                    vec![RegAddr::ControlStatus as u8],
+                    vec![0x04],
+                )
+            })
+            .collect();
+        let mock = setup_mock(&expectations);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let result = dev
+            .force_temperature_conversion(&mut NoopDelay::new())
+            .await;
+        assert!(matches!(result, Err(DS3231Error::Timeout)));
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_force_temperature_conversion_conv_timeout() {
+        let mut expectations = vec![
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x00],
+            ),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1C]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0x3C]),
+        ];
+        expectations.extend((0..=CONVERSION_MAX_POLLS).map(|_| {
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x3C])
+        }));
+        let mock = setup_mock(&expectations);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let result = dev
+            .force_temperature_conversion(&mut NoopDelay::new())
+            .await;
+        assert!(matches!(result, Err(DS3231Error::Timeout)));
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_local_datetime() {
+        let tz = TimeZone::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        let mock = setup_mock(&[
+            // 2024-07-01 22:30:00 UTC
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x00, 0x30, 0x22, 0x02, 0x01, 0x07, 0x24],
+            ),
+            // Local 2024-01-01 00:30:00 CET is 2023-12-31 23:30:00 UTC
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x00]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x00,
+                    0x30,
+                    0x23,
+                    0x01,
+                    0x31,
+                    0x12,
+                    0x23,
+                ],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        assert_eq!(
+            dev.local_datetime(&tz).await.unwrap(),
+            NaiveDate::from_ymd_opt(2024, 7, 2)
+                .unwrap()
+                .and_hms_opt(0, 30, 0)
+                .unwrap()
+        );
+        let local = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 30, 0)
+            .unwrap();
+        dev.set_local_datetime(&tz, &local).await.unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "time")]
+    #[driver_test]
+    async fn test_time_crate_datetime() {
+        let mock = setup_mock(&[
+            // 2024-03-14 15:30:00
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x00, 0x30, 0x15, 0x05, 0x14, 0x03, 0x24],
+            ),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x00]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x00,
+                    0x30,
+                    0x15,
+                    0x05,
+                    0x14,
+                    0x03,
+                    0x24,
+                ],
+            ),
+            // 2024-03-15 01:30:00+02:00 is 2024-03-14 23:30:00 UTC
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x00]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x00,
+                    0x30,
+                    0x23,
+                    0x05,
+                    0x14,
+                    0x03,
+                    0x24,
+                ],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
 
-    #[test]
-    fn test_new_device() {
-        let mock = setup_mock(&[]);
-        let mut _dev = DS3231::new(mock, DEVICE_ADDRESS);
-        // No I2C operations should happen during initialization
-        _dev.i2c.done();
-    }
+        let datetime = time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::March, 14).unwrap(),
+            time::Time::from_hms(15, 30, 0).unwrap(),
+        );
+        assert_eq!(dev.primitive_datetime().await.unwrap(), datetime);
+        dev.set_primitive_datetime(&datetime).await.unwrap();
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
-    async fn test_configure() {
-        let config = Config {
-            time_representation: TimeRepresentation::TwentyFourHour,
-            square_wave_frequency: SquareWaveFrequency::Hz1,
-            interrupt_control: InterruptControl::SquareWave,
-            battery_backed_square_wave: false,
-            oscillator_enable: Oscillator::Enabled,
-        };
+        let offset = time::UtcOffset::from_hms(2, 0, 0).unwrap();
+        let local = time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::March, 15).unwrap(),
+            time::Time::from_hms(1, 30, 0).unwrap(),
+        )
+        .assume_offset(offset);
+        dev.set_offset_datetime(&local).await.unwrap();
+        dev.interface.i2c.done();
+    }
 
+    #[driver_test]
+    async fn test_datetime_raw() {
         let mock = setup_mock(&[
-            // Read control register
-            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0]),
-            // Write control register with Hz1 frequency (0b00 in bits 4,3)
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0b0000_0000]),
-            // Read hours register
-            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0]),
-            // Write hours register
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0]),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x00, 0x30, 0x15, 0x05, 0x14, 0x03, 0x24],
+            ),
+            // 2024-02-31 is not a date
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x00, 0x30, 0x15, 0x04, 0x31, 0x02, 0x24],
+            ),
+            // 12-hour mode: 3:30 PM
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x40]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x00,
+                    0x30,
+                    0x63,
+                    0x05,
+                    0x14,
+                    0x03,
+                    0x24,
+                ],
+            ),
         ]);
-
         let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
-        dev.configure(&config).await.unwrap();
-        // After configure, time_representation should be cached
-        assert_eq!(
-            dev.time_representation,
-            Some(TimeRepresentation::TwentyFourHour)
-        );
-        dev.i2c.done();
+
+        let datetime = RtcDateTime::new(2024, 3, 14, 15, 30, 0).unwrap();
+        assert_eq!(dev.datetime_raw().await.unwrap(), datetime);
+        let result = dev.datetime_raw().await;
+        assert!(matches!(
+            result,
+            Err(DS3231Error::DateTime(DS3231DateTimeError::InvalidDateTime))
+        ));
+        dev.set_datetime_raw(&datetime).await.unwrap();
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
-    async fn test_read_datetime() {
-        // 2024-03-14 15:30:00
-        let datetime_registers = [
-            0x00, // seconds
-            0x30, // minutes
-            0x15, // hours (24-hour mode)
-            0x04, // day (Thursday)
-            0x14, // date
-            0x03, // month
-            0x24, // year
-        ];
+    #[driver_test]
+    async fn test_timestamp() {
+        let mock = setup_mock(&[
+            // 2023-11-14 22:13:20
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x20, 0x13, 0x22, 0x02, 0x14, 0x11, 0x23],
+            ),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x20, 0x13, 0x22, 0x02, 0x14, 0x11, 0x23],
+            ),
+            // 12-hour mode: 2000-01-01 12:00:01 AM
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x52]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x01,
+                    0x00,
+                    0x52,
+                    0x07,
+                    0x01,
+                    0x01,
+                    0x00,
+                ],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
 
-        let mock = setup_mock(&[I2cTrans::write_read(
-            DEVICE_ADDRESS,
-            vec![RegAddr::Seconds as u8],
-            datetime_registers.to_vec(),
-        )]);
+        assert_eq!(dev.timestamp().await.unwrap(), 1_700_000_000);
+        assert_eq!(dev.seconds_since_2000().await.unwrap(), 753_315_200);
+        dev.set_seconds_since_2000(1).await.unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_timestamp_out_of_range() {
+        let mock = setup_mock(&[
+            // 2150-01-01 00:00:00 does not fit in 32-bit seconds since 2000
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x00, 0x00, 0x00, 0x04, 0x01, 0x81, 0x50],
+            ),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x00]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x00]),
+        ]);
         let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
 
-        let dt = dev.datetime().await.unwrap();
-        assert_eq!(dt.hour(), 15);
-        assert_eq!(dt.minute(), 30);
-        assert_eq!(dt.second(), 0);
-        assert_eq!(dt.day(), 14);
-        assert_eq!(dt.month(), 3);
-        assert_eq!(dt.year(), 2024);
-        dev.i2c.done();
+        let result = dev.seconds_since_2000().await;
+        assert!(matches!(
+            result,
+            Err(DS3231Error::DateTime(
+                DS3231DateTimeError::TimestampOverflow
+            ))
+        ));
+        let result = dev.set_timestamp(946_684_799).await;
+        assert!(matches!(
+            result,
+            Err(DS3231Error::DateTime(DS3231DateTimeError::YearNotAfter1999))
+        ));
+        let result = dev.set_timestamp(7_258_118_400).await;
+        assert!(matches!(
+            result,
+            Err(DS3231Error::DateTime(
+                DS3231DateTimeError::YearNotBefore2200
+            ))
+        ));
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
-    async fn test_read_datetime_with_error() {
-        // 2024-03-14 15:30:00
-        let datetime_registers = [
-            0x00, // seconds
-            0x30, // minutes
-            0x15, // hours (24-hour mode)
-            0x04, // day (Thursday)
-            0x14, // date
-            0x03, // month
-            0x24, // year
-        ];
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_set_local_alarm1() {
+        let tz = TimeZone::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        let mock = setup_mock(&[
+            // 2024-07-01 12:00:00 UTC
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x00, 0x00, 0x12, 0x02, 0x01, 0x07, 0x24],
+            ),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x12]),
+            // 07:00 CEST is 05:00 UTC
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8, 0x00, 0x00, 0x05, 0x80],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
 
-        let mock = setup_mock(&[I2cTrans::write_read(
+        let config = dev
+            .set_local_alarm1(
+                &tz,
+                &Alarm1Config::AtTime {
+                    hours: 7,
+                    minutes: 0,
+                    seconds: 0,
+                    is_pm: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            config,
+            Alarm1Config::AtTime {
+                hours: 5,
+                minutes: 0,
+                seconds: 0,
+                is_pm: None,
+            }
+        );
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_set_alarm_after() {
+        // 2024-01-31 23:59:30, a Wednesday
+        let now = I2cTrans::write_read(
             DEVICE_ADDRESS,
             vec![RegAddr::Seconds as u8],
-            datetime_registers.to_vec(),
-        )
-        .with_error(I2cErrorKind::Other)]);
+            vec![0x30, 0x59, 0x23, 0x04, 0x31, 0x01, 0x24],
+        );
+        let mock = setup_mock(&[
+            now.clone(),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x23]),
+            // 90 seconds and the unread fraction of a second: 00:01:01 on the 1st
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8, 0x01, 0x01, 0x00, 0x01],
+            ),
+            now.clone(),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x23]),
+            // Alarm 2 rounds up to the next minute: 06:00 on the 1st
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm2Minutes as u8, 0x00, 0x06, 0x01],
+            ),
+            // 30 days is March 1st, but February 1st comes first
+            now,
+        ]);
         let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
 
-        let err = dev.datetime().await.unwrap_err();
-        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
-
-        dev.i2c.done();
+        let date = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        assert_eq!(
+            dev.set_alarm1_after(Duration::from_secs(90)).await.unwrap(),
+            date.and_hms_opt(0, 1, 1).unwrap()
+        );
+        assert_eq!(
+            dev.set_alarm2_after(Duration::from_hours(6)).await.unwrap(),
+            date.and_hms_opt(6, 0, 0).unwrap()
+        );
+        let result = dev.set_alarm1_after(Duration::from_hours(30 * 24)).await;
+        assert!(matches!(
+            result,
+            Err(DS3231Error::Alarm(AlarmError::InvalidTime(_)))
+        ));
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
-    async fn test_set_datetime() {
-        let dt = NaiveDate::from_ymd_opt(2024, 3, 14)
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_service_scheduler() {
+        let start = NaiveDate::from_ymd_opt(2024, 7, 1)
             .unwrap()
-            .and_hms_opt(15, 30, 0)
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let mut scheduler: AlarmScheduler<u8, 4> = AlarmScheduler::new(AlarmId::Alarm1);
+        scheduler
+            .add(1, Schedule::Once(start.with_second(10).unwrap()), &start)
+            .unwrap();
+        scheduler
+            .add(
+                2,
+                Schedule::Repeat(Alarm1Config::AtTime {
+                    hours: 13,
+                    minutes: 0,
+                    seconds: 0,
+                    is_pm: None,
+                }),
+                &start,
+            )
             .unwrap();
 
         let mock = setup_mock(&[
-            // First, read the hours register to get the time representation
+            // A1F set: cleared, the other flags are kept
             I2cTrans::write_read(
                 DEVICE_ADDRESS,
-                vec![RegAddr::Hours as u8],
-                vec![0x15], // 24-hour mode (bit 6 = 0)
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x01],
             ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::ControlStatus as u8, 0x82]),
+            // 2024-07-01 12:00:10
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x10, 0x00, 0x12, 0x02, 0x01, 0x07, 0x24],
+            ),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x12]),
+            // Alarm 1 interrupt disabled, so the alarm is written directly: 13:00:00 on the 1st
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1C]),
             I2cTrans::write(
                 DEVICE_ADDRESS,
-                vec![
-                    RegAddr::Seconds as u8,
-                    0x00, // seconds
-                    0x30, // minutes (BCD for 30)
-                    0x15, // hours (BCD for 15 in 24-hour mode)
-                    0x04, // day (Thursday)
-                    0x14, // date
-                    0x03, // month
-                    0x24, // year
-                ],
+                vec![RegAddr::Alarm1Seconds as u8, 0x00, 0x00, 0x13, 0x01],
+            ),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x11, 0x00, 0x12, 0x02, 0x01, 0x07, 0x24],
             ),
         ]);
         let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
 
-        dev.set_datetime(&dt).await.unwrap();
-        dev.i2c.done();
+        let due = dev.service_scheduler(&mut scheduler).await.unwrap();
+        assert_eq!(due.len(), 1);
+        let alarm = due.iter().next().unwrap();
+        assert_eq!(alarm.tag, 1);
+        assert_eq!(alarm.due, start.with_second(10).unwrap());
+        // Never armed before, so it was missed
+        assert!(alarm.missed);
+        assert_eq!(scheduler.len(), 1);
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
-    async fn test_set_datetime_with_error() {
-        let dt = NaiveDate::from_ymd_opt(2024, 3, 14)
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_service_scheduler_rearms_when_time_passes() {
+        let start = NaiveDate::from_ymd_opt(2024, 7, 1)
             .unwrap()
-            .and_hms_opt(15, 30, 0)
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let mut scheduler: AlarmScheduler<u8, 2> = AlarmScheduler::new(AlarmId::Alarm2);
+        scheduler
+            .add(1, Schedule::Once(start.with_minute(1).unwrap()), &start)
+            .unwrap();
+        scheduler
+            .add(2, Schedule::Once(start.with_minute(5).unwrap()), &start)
             .unwrap();
 
         let mock = setup_mock(&[
-            // First, read the hours register to get the time representation
             I2cTrans::write_read(
                 DEVICE_ADDRESS,
-                vec![RegAddr::Hours as u8],
-                vec![0x15], // 24-hour mode (bit 6 = 0)
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x00],
+            ),
+            // 2024-07-01 12:00:59, the alarm for 12:01 is written in 12-hour mode...
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x59, 0x00, 0x72, 0x02, 0x01, 0x07, 0x24],
             ),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x72]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1C]),
             I2cTrans::write(
                 DEVICE_ADDRESS,
-                vec![
-                    RegAddr::Seconds as u8,
-                    0x00, // seconds
-                    0x30, // minutes (BCD for 30)
-                    0x15, // hours (BCD for 15 in 24-hour mode)
-                    0x04, // day (Thursday)
-                    0x14, // date
-                    0x03, // month
-                    0x24, // year
-                ],
-            )
-            .with_error(I2cErrorKind::Other),
+                vec![RegAddr::Alarm2Minutes as u8, 0x01, 0x72, 0x01],
+            ),
+            // ...but the clock is already at 12:01:00, so it is due now and 12:05 is armed
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x00, 0x01, 0x72, 0x02, 0x01, 0x07, 0x24],
+            ),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x72]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1C]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm2Minutes as u8, 0x05, 0x72, 0x01],
+            ),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x01, 0x01, 0x72, 0x02, 0x01, 0x07, 0x24],
+            ),
         ]);
         let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
 
-        let err = dev.set_datetime(&dt).await.unwrap_err();
-        assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
+        let due = dev.service_scheduler(&mut scheduler).await.unwrap();
+        assert_eq!(due.len(), 1);
+        let alarm = due.iter().next().unwrap();
+        assert_eq!(alarm.tag, 1);
+        assert!(!alarm.missed);
+        dev.interface.i2c.done();
+    }
 
-        dev.i2c.done();
+    /// A pin that never changes, to exercise the timeout.
+    #[maybe_async_cfg::only_if(async)]
+    struct IdlePin;
+
+    #[maybe_async_cfg::only_if(async)]
+    impl embedded_hal::digital::ErrorType for IdlePin {
+        type Error = core::convert::Infallible;
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
-    async fn test_register_operations() {
+    #[maybe_async_cfg::only_if(async)]
+    impl Wait for IdlePin {
+        async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+            core::future::pending().await
+        }
+        async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+            core::future::pending().await
+        }
+        async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+            core::future::pending().await
+        }
+        async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+            core::future::pending().await
+        }
+        async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+            core::future::pending().await
+        }
+    }
+
+    #[maybe_async_cfg::only_if(async)]
+    #[driver_test]
+    async fn test_wait_for_alarm() {
+        let mut int_pin = PinMock::new(&[PinTrans::wait_for_state(PinState::Low)]);
         let mock = setup_mock(&[
-            // Test second register
-            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Seconds as u8], vec![0x45]),
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Seconds as u8, 0x30]),
-            // Test minute register
-            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Minutes as u8], vec![0x30]),
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Minutes as u8, 0x45]),
-            // Test status register
+            // Both flags set: cleared in one write, OSF is kept
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x8B],
+            ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::ControlStatus as u8, 0x88]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let event = dev.wait_for_alarm(&mut int_pin).await.unwrap();
+        assert_eq!(event, AlarmEvent::Both);
+        dev.interface.i2c.done();
+        int_pin.done();
+    }
+
+    #[maybe_async_cfg::only_if(async)]
+    #[driver_test]
+    async fn test_wait_for_alarm_without_flag() {
+        let mut int_pin = PinMock::new(&[
+            PinTrans::wait_for_state(PinState::Low),
+            PinTrans::wait_for_state(PinState::High),
+            PinTrans::wait_for_state(PinState::Low),
+        ]);
+        let mock = setup_mock(&[
+            // INT low without an alarm flag: wait for it to go high and low again
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x00],
+            ),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x02],
+            ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::ControlStatus as u8, 0x81]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let event = dev.wait_for_alarm(&mut int_pin).await.unwrap();
+        assert_eq!(event, AlarmEvent::Alarm2);
+        dev.interface.i2c.done();
+        int_pin.done();
+    }
+
+    #[maybe_async_cfg::only_if(async)]
+    #[driver_test]
+    async fn test_wait_for_alarm_timeout() {
+        let mut int_pin = PinMock::new(&[PinTrans::wait_for_state(PinState::Low)]);
+        let mock = setup_mock(&[
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x01],
+            ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::ControlStatus as u8, 0x82]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let event = dev
+            .wait_for_alarm_timeout(&mut int_pin, &mut NoopDelay::new(), 1000)
+            .await
+            .unwrap();
+        assert_eq!(event, Some(AlarmEvent::Alarm1));
+        int_pin.done();
+
+        // No I2C traffic when the pin stays high
+        let event = dev
+            .wait_for_alarm_timeout(&mut IdlePin, &mut NoopDelay::new(), 1000)
+            .await
+            .unwrap();
+        assert_eq!(event, None);
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_aging_offset_calibration() {
+        let reference = NaiveDate::from_ymd_opt(2024, 3, 14)
+            .unwrap()
+            .and_hms_opt(15, 30, 0)
+            .unwrap();
+
+        let mock = setup_mock(&[
+            // Sample: the RTC is exactly on time
             I2cTrans::write_read(
                 DEVICE_ADDRESS,
-                vec![RegAddr::ControlStatus as u8],
-                vec![0x80],
+                vec![RegAddr::Seconds as u8],
+                vec![0x00, 0x30, 0x15, 0x05, 0x14, 0x03, 0x24],
+            ),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::MSBTemp as u8],
+                vec![0x19, 0x00],
             ),
+            // Not enough samples yet
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::AgingOffset as u8], vec![0x02]),
+            // Ten days later the RTC is 2 seconds ahead (~2.3 ppm fast)
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x02, 0x30, 0x15, 0x01, 0x24, 0x03, 0x24],
+            ),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::MSBTemp as u8],
+                vec![0x19, 0x00],
+            ),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::AgingOffset as u8], vec![0x02]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::AgingOffset as u8, 25]),
         ]);
-
         let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+        let mut estimator = DriftEstimator::new();
 
-        // Test seconds
-        let seconds = dev.second().await.unwrap();
-        assert_eq!(seconds.seconds(), 5);
-        assert_eq!(seconds.ten_seconds(), 4);
-        dev.set_second(Seconds(0x30)).await.unwrap();
+        dev.sample_drift(&reference, &mut estimator).await.unwrap();
+        assert_eq!(dev.apply_aging_offset(&estimator).await.unwrap(), None);
 
-        // Test minutes
-        let minutes = dev.minute().await.unwrap();
-        assert_eq!(minutes.minutes(), 0);
-        assert_eq!(minutes.ten_minutes(), 3);
-        dev.set_minute(Minutes(0x45)).await.unwrap();
+        let reference = reference + chrono::TimeDelta::days(10);
+        dev.sample_drift(&reference, &mut estimator).await.unwrap();
+        assert_eq!(
+            estimator.mean_temperature(),
+            Some(Celsius::from_quarter_degrees(100))
+        );
+        assert_eq!(
+            dev.apply_aging_offset(&estimator).await.unwrap(),
+            Some(AgingOffset(25))
+        );
+        dev.interface.i2c.done();
+    }
 
-        // Test status
-        let status = dev.status().await.unwrap();
-        assert!(status.oscillator_stop_flag());
+    #[driver_test]
+    async fn test_temperature_celsius() {
+        // -10.75°C: MSB 0xF5 (-11°C), LSB 0x40 (+0.25°C)
+        let mock = setup_mock(&[I2cTrans::write_read(
+            DEVICE_ADDRESS,
+            vec![RegAddr::MSBTemp as u8],
+            vec![0xF5, 0x40],
+        )]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
 
-        dev.i2c.done();
+        let temp = dev.temperature_celsius().await.unwrap();
+        assert_eq!(temp, Celsius::from_quarter_degrees(-43));
+        assert_eq!(temp.millidegrees(), -10_750);
+        dev.interface.i2c.done();
     }
 
     #[cfg(feature = "temperature_f32")]
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_temperature_f32_positive() {
         // Test positive temperature: 25.25°C
         // MSB: 0x19 (25°C), LSB: 0x40 (0.25°C in upper 2 bits)
@@ -1115,12 +3622,11 @@ mod tests {
 
         let temp = dev.temperature_f32().await.unwrap();
         assert_eq!(temp, 25.25);
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
     #[cfg(feature = "temperature_f32")]
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_temperature_f32_positive_with_error() {
         // Test positive temperature: 25.25°C
         // MSB: 0x19 (25°C), LSB: 0x40 (0.25°C in upper 2 bits)
@@ -1137,12 +3643,11 @@ mod tests {
         let err = dev.temperature_f32().await.unwrap_err();
         assert!(matches!(err, DS3231Error::I2c(I2cErrorKind::Other)));
 
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
     #[cfg(feature = "temperature_f32")]
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_temperature_f32_negative() {
         // Test negative temperature: -10.75°C
         // MSB: 0xF5 (-11°C), LSB: 0x40 (0.25°C in upper 2 bits)
@@ -1158,12 +3663,11 @@ mod tests {
 
         let temp = dev.temperature_f32().await.unwrap();
         assert_eq!(temp, -10.75);
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
     #[cfg(feature = "temperature_f32")]
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_temperature_f32_zero() {
         // Test zero temperature: 0.00°C
         // MSB: 0x00 (0°C), LSB: 0x00 (0.00°C in upper 2 bits)
@@ -1178,12 +3682,11 @@ mod tests {
 
         let temp = dev.temperature_f32().await.unwrap();
         assert_eq!(temp, 0.00);
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
     #[cfg(feature = "temperature_f32")]
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_temperature_f32_all_fractions() {
         // Test all possible fractional values
         let test_cases = [
@@ -1204,13 +3707,12 @@ mod tests {
 
             let temp = dev.temperature_f32().await.unwrap();
             assert_eq!(temp, expected_temp);
-            dev.i2c.done();
+            dev.interface.i2c.done();
         }
     }
 
     #[cfg(feature = "temperature_f32")]
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_temperature_f32_extreme_values() {
         // Test extreme temperature values
         let test_cases = [
@@ -1230,13 +3732,12 @@ mod tests {
 
             let temp = dev.temperature_f32().await.unwrap();
             assert_eq!(temp, expected_temp);
-            dev.i2c.done();
+            dev.interface.i2c.done();
         }
     }
 
     #[cfg(feature = "temperature_f32")]
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_temperature_f32_datasheet_example() {
         // Test the example from the datasheet: 00011001 01b = +25.25°C
         // MSB: 0x19 (25°C), LSB: 0x40 (01 in upper 2 bits = 0.25°C)
@@ -1251,11 +3752,10 @@ mod tests {
 
         let temp = dev.temperature_f32().await.unwrap();
         assert_eq!(temp, 25.25);
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_alarm_registers() {
         let mock = setup_mock(&[
             // Test alarm1 registers
@@ -1324,35 +3824,25 @@ mod tests {
         dev.set_alarm2_hour(AlarmHours(0x14)).await.unwrap();
         dev.set_alarm2_day_date(AlarmDayDate(0x25)).await.unwrap();
 
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_alarm1_high_level_operations() {
         let mock = setup_mock(&[
-            // Read alarm1 registers
+            // Read alarm1 registers in one burst
             I2cTrans::write_read(
                 DEVICE_ADDRESS,
                 vec![RegAddr::Alarm1Seconds as u8],
-                vec![0x30],
+                vec![0x30, 0x45, 0x12, 0x15],
             ),
-            I2cTrans::write_read(
-                DEVICE_ADDRESS,
-                vec![RegAddr::Alarm1Minutes as u8],
-                vec![0x45],
-            ),
-            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm1Hours as u8], vec![0x12]),
-            I2cTrans::write_read(
+            // The alarm matches the hours, so the 12/24 hour setting is read
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x00]),
+            // Set alarm1 configuration in one burst
+            I2cTrans::write(
                 DEVICE_ADDRESS,
-                vec![RegAddr::Alarm1DayDate as u8],
-                vec![0x15],
+                vec![RegAddr::Alarm1Seconds as u8, 0x00, 0x30, 0x09, 0x80],
             ),
-            // Set alarm1 configuration
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Alarm1Seconds as u8, 0x00]),
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Alarm1Minutes as u8, 0x30]),
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Alarm1Hours as u8, 0x09]),
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Alarm1DayDate as u8, 0x80]),
         ]);
 
         let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
@@ -1376,72 +3866,440 @@ mod tests {
             _ => panic!("Expected AtTimeOnDate alarm configuration"),
         }
 
-        // Test setting alarm1
+        // Test setting alarm1
+        let config = Alarm1Config::AtTime {
+            hours: 9,
+            minutes: 30,
+            seconds: 0,
+            is_pm: None,
+        };
+        dev.set_alarm1(&config).await.unwrap();
+
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_raw_alarm_round_trip() {
+        // Minutes masked but hours matched: not one of the documented combinations
+        let registers = [0x15, 0x80, 0x07, 0x80];
+        let mock = setup_mock(&[
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8],
+                registers.to_vec(),
+            ),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8, 0x15, 0x80, 0x07, 0x80],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let alarm = dev.alarm1().await.unwrap();
+        let Alarm1Config::Raw(raw) = alarm else {
+            panic!("expected a raw alarm, got {alarm:?}");
+        };
+        assert_eq!(
+            raw.alarm_match(),
+            AlarmMatch {
+                seconds: FieldMatch::Value(15),
+                minutes: FieldMatch::Any,
+                hours: FieldMatch::Value(7),
+                day: FieldMatch::Any,
+                date: FieldMatch::Any,
+            }
+        );
+        // Written back unchanged, without reading the 12/24 hour format
+        dev.set_alarm1(&alarm).await.unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_alarms_follow_time_representation() {
+        let mock = setup_mock(&[
+            // Clock in 12-hour mode: 21:30:00 is written as 9:30 PM
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x52]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8, 0x00, 0x30, 0x69, 0x80],
+            ),
+            // Clock in 24-hour mode: 9 PM is written as 21
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x15]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm2Minutes as u8, 0x00, 0x21, 0x80],
+            ),
+            // Alarms read back in 24-hour form
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8],
+                vec![0x00, 0x30, 0x69, 0x80],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let alarm1 = Alarm1Config::AtTime {
+            hours: 21,
+            minutes: 30,
+            seconds: 0,
+            is_pm: None,
+        };
+        dev.set_alarm1(&alarm1).await.unwrap();
+        dev.set_alarm2(&Alarm2Config::AtTime {
+            hours: 9,
+            minutes: 0,
+            is_pm: Some(true),
+        })
+        .await
+        .unwrap();
+        assert_eq!(dev.alarm1().await.unwrap(), alarm1);
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_invalid_alarm_registers_read_as_raw() {
+        let mock = setup_mock(&[
+            // All masks clear, but seconds 0x7F, hour 25 and date 32 are never reached
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8],
+                vec![0x7F, 0x00, 0x25, 0x32],
+            ),
+            // Day select with day 0
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm2Minutes as u8],
+                vec![0x30, 0x12, 0x40],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let alarm1 = dev.alarm1().await.unwrap();
+        let Alarm1Config::Raw(raw) = alarm1 else {
+            panic!("expected a raw alarm, got {alarm1:?}");
+        };
+        let alarm_match = raw.alarm_match();
+        assert_eq!(
+            alarm_match,
+            AlarmMatch {
+                seconds: FieldMatch::Never,
+                minutes: FieldMatch::Value(0),
+                hours: FieldMatch::Never,
+                day: FieldMatch::Any,
+                date: FieldMatch::Never,
+            }
+        );
+        assert!(!alarm_match.can_fire());
+
+        let alarm2 = dev.alarm2().await.unwrap();
+        let Alarm2Config::Raw(raw) = alarm2 else {
+            panic!("expected a raw alarm, got {alarm2:?}");
+        };
+        assert_eq!(
+            raw.alarm_match(),
+            AlarmMatch {
+                seconds: FieldMatch::Value(0),
+                minutes: FieldMatch::Value(30),
+                hours: FieldMatch::Value(12),
+                day: FieldMatch::Never,
+                date: FieldMatch::Any,
+            }
+        );
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_alarm_readback_in_both_clock_modes() {
+        let mock = setup_mock(&[
+            // 9:30 PM stored in 12-hour form
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8],
+                vec![0x00, 0x30, 0x69, 0x80],
+            ),
+            // 21:30 stored in 24-hour form
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8],
+                vec![0x00, 0x30, 0x21, 0x80],
+            ),
+            // 12 AM on the 15th stored in 12-hour form
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm2Minutes as u8],
+                vec![0x45, 0x52, 0x15],
+            ),
+            // 00:45 on the 15th stored in 24-hour form
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm2Minutes as u8],
+                vec![0x45, 0x00, 0x15],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let alarm1 = Alarm1Config::AtTime {
+            hours: 21,
+            minutes: 30,
+            seconds: 0,
+            is_pm: None,
+        };
+        assert_eq!(dev.alarm1().await.unwrap(), alarm1);
+        assert_eq!(dev.alarm1().await.unwrap(), alarm1);
+
+        let alarm2 = Alarm2Config::AtTimeOnDate {
+            hours: 0,
+            minutes: 45,
+            date: 15,
+            is_pm: None,
+        };
+        assert_eq!(dev.alarm2().await.unwrap(), alarm2);
+        assert_eq!(dev.alarm2().await.unwrap(), alarm2);
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_alarm2_high_level_operations() {
+        let mock = setup_mock(&[
+            // Read alarm2 registers in one burst
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm2Minutes as u8],
+                vec![0x45, 0x12, 0x15],
+            ),
+            // The alarm matches the hours, so the 12/24 hour setting is read
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x00]),
+            // Set alarm2 configuration in one burst
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm2Minutes as u8, 0x30, 0x14, 0x80],
+            ),
+        ]);
+
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        // Test reading alarm2
+        let alarm2 = dev.alarm2().await.unwrap();
+        match alarm2 {
+            Alarm2Config::AtTimeOnDate {
+                hours,
+                minutes,
+                date,
+                is_pm,
+            } => {
+                assert_eq!(hours, 12);
+                assert_eq!(minutes, 45);
+                assert_eq!(date, 15);
+                assert_eq!(is_pm, None);
+            }
+            _ => panic!("Expected AtTimeOnDate alarm configuration"),
+        }
+
+        // Test setting alarm2
+        let config = Alarm2Config::AtTime {
+            hours: 14,
+            minutes: 30,
+            is_pm: None,
+        };
+        dev.set_alarm2(&config).await.unwrap();
+
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_set_alarm1_masks_interrupt() {
+        let mock = setup_mock(&[
+            // The alarm matches the hours, so the 12/24 hour setting is read
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x00]),
+            // Alarm 1 interrupt enabled, no flags set
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1D]),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x08],
+            ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0x1C]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8, 0x00, 0x30, 0x09, 0x80],
+            ),
+            // The partially written alarm matched: the flag is cleared, others are kept
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x09],
+            ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::ControlStatus as u8, 0x8A]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0x1D]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let config = Alarm1Config::AtTime {
+            hours: 9,
+            minutes: 30,
+            seconds: 0,
+            is_pm: None,
+        };
+        dev.set_alarm1_with(&config, AlarmUpdate::MaskInterrupt)
+            .await
+            .unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_alarms_fired_and_clear_alarm() {
+        let mock = setup_mock(&[
+            // A1F set with EN32kHz: only A1F is cleared
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x09],
+            ),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x09],
+            ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::ControlStatus as u8, 0x8A]),
+            // A2F is not set: nothing to write
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x08],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let fired = dev.alarms_fired().await.unwrap();
+        assert!(fired.alarm1);
+        assert!(!fired.alarm2);
+        dev.clear_alarm(AlarmId::Alarm1).await.unwrap();
+        dev.clear_alarm(AlarmId::Alarm2).await.unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_enable_alarm_interrupt() {
+        let mock = setup_mock(&[
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1C]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0x1E]),
+            // Already enabled: nothing to write
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1E]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        dev.enable_alarm_interrupt(AlarmId::Alarm2, true)
+            .await
+            .unwrap();
+        dev.enable_alarm_interrupt(AlarmId::Alarm2, true)
+            .await
+            .unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_arm_alarm1() {
+        let mock = setup_mock(&[
+            // The alarm matches the hours, so the 12/24 hour setting is read
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x00]),
+            // Disable the interrupt
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1D]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0x1C]),
+            // Program the alarm
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8, 0x00, 0x30, 0x09, 0x80],
+            ),
+            // Clear a stale A1F, keeping A2F, OSF and EN32kHz
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x8B],
+            ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::ControlStatus as u8, 0x8A]),
+            // Enable the interrupt
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1C]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0x1D]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
         let config = Alarm1Config::AtTime {
             hours: 9,
             minutes: 30,
             seconds: 0,
             is_pm: None,
         };
-        dev.set_alarm1(&config).await.unwrap();
+        dev.arm_alarm1(&config).await.unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_arm_alarm2_invalid_config() {
+        let mock = setup_mock(&[]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
 
-        dev.i2c.done();
+        let config = Alarm2Config::AtMinutes { minutes: 60 };
+        let result = dev.arm_alarm2(&config).await;
+        assert!(matches!(result, Err(DS3231Error::Alarm(_))));
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
-    async fn test_alarm2_high_level_operations() {
+    #[driver_test]
+    async fn test_set_alarm2_masks_interrupt_keeps_pending_flag() {
         let mock = setup_mock(&[
-            // Read alarm2 registers
+            // The alarm matches the hours, so the 12/24 hour setting is read
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x00]),
+            // Alarm 2 interrupt enabled, alarm 2 flag already pending
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x06]),
             I2cTrans::write_read(
                 DEVICE_ADDRESS,
-                vec![RegAddr::Alarm2Minutes as u8],
-                vec![0x45],
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x02],
+            ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0x04]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm2Minutes as u8, 0x30, 0x14, 0x80],
             ),
-            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm2Hours as u8], vec![0x12]),
             I2cTrans::write_read(
                 DEVICE_ADDRESS,
-                vec![RegAddr::Alarm2DayDate as u8],
-                vec![0x15],
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x02],
             ),
-            // Set alarm2 configuration
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Alarm2Minutes as u8, 0x30]),
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Alarm2Hours as u8, 0x14]),
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Alarm2DayDate as u8, 0x80]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0x06]),
         ]);
-
         let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
 
-        // Test reading alarm2
-        let alarm2 = dev.alarm2().await.unwrap();
-        match alarm2 {
-            Alarm2Config::AtTimeOnDate {
-                hours,
-                minutes,
-                date,
-                is_pm,
-            } => {
-                assert_eq!(hours, 12);
-                assert_eq!(minutes, 45);
-                assert_eq!(date, 15);
-                assert_eq!(is_pm, None);
-            }
-            _ => panic!("Expected AtTimeOnDate alarm configuration"),
-        }
-
-        // Test setting alarm2
         let config = Alarm2Config::AtTime {
             hours: 14,
             minutes: 30,
             is_pm: None,
         };
-        dev.set_alarm2(&config).await.unwrap();
+        dev.set_alarm2_with(&config, AlarmUpdate::MaskInterrupt)
+            .await
+            .unwrap();
+        dev.interface.i2c.done();
+    }
 
-        dev.i2c.done();
+    #[driver_test]
+    async fn test_set_alarm_mask_interrupt_when_disabled() {
+        let mock = setup_mock(&[
+            // Alarm 1 interrupt disabled: the alarm is written directly
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1C]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm1Seconds as u8, 0x80, 0x80, 0x80, 0x80],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        dev.set_alarm1_with(&Alarm1Config::EverySecond, AlarmUpdate::MaskInterrupt)
+            .await
+            .unwrap();
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_alarm_error_handling() {
         let mock = setup_mock(&[]);
         let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
@@ -1465,11 +4323,11 @@ mod tests {
         let result = dev.set_alarm2(&invalid_config).await;
         assert!(matches!(result, Err(DS3231Error::Alarm(_))));
 
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[cfg(feature = "chrono")]
+    #[driver_test]
     async fn test_datetime_error_handling() {
         // Test invalid datetime conversion
         let invalid_datetime_data = [
@@ -1492,9 +4350,10 @@ mod tests {
         let result = dev.datetime().await;
         assert!(matches!(result, Err(DS3231Error::DateTime(_))));
 
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
+    #[maybe_async_cfg::only_if(sync)]
     #[test]
     fn test_error_type_coverage() {
         use crate::alarm::AlarmError;
@@ -1509,8 +4368,8 @@ mod tests {
         assert!(matches!(alarm_error, DS3231Error::Alarm(_)));
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[cfg(feature = "chrono")]
+    #[driver_test]
     async fn test_twelve_hour_mode_datetime() {
         // Test setting datetime in 12-hour mode
         let dt = NaiveDate::from_ymd_opt(2024, 3, 14)
@@ -1522,9 +4381,13 @@ mod tests {
             // Configure to 12-hour mode first
             I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0]),
             I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0]),
-            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0]),
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0x40]), // 12-hour mode
-            // Set datetime
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Seconds as u8], vec![0, 0, 0]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0x52]), // 12 AM
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Minutes as u8], vec![0]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm1Hours as u8], vec![0x52]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm2Hours as u8], vec![0x52]),
+            // Set datetime, checking the 12/24 hour setting first
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x52]),
             I2cTrans::write(
                 DEVICE_ADDRESS,
                 vec![
@@ -1532,7 +4395,7 @@ mod tests {
                     0x00, // seconds
                     0x30, // minutes
                     0x63, // hours (3 PM in 12-hour mode with PM bit set)
-                    0x04, // day
+                    0x05, // day
                     0x14, // date
                     0x03, // month
                     0x24, // year
@@ -1555,9 +4418,10 @@ mod tests {
         // Set datetime in 12-hour mode
         dev.set_datetime(&dt).await.unwrap();
 
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
+    #[maybe_async_cfg::only_if(sync)]
     #[test]
     fn test_enum_u8_conversions() {
         // Test TimeRepresentation conversions
@@ -1592,6 +4456,7 @@ mod tests {
         assert_eq!(u8::from(SquareWaveFrequency::Hz8192), 0b11);
     }
 
+    #[maybe_async_cfg::only_if(sync)]
     #[test]
     fn test_error_conversions() {
         // Test DS3231Error::from for I2C errors
@@ -1603,8 +4468,7 @@ mod tests {
         assert!(matches!(ds3231_error, DS3231Error::I2c(MockI2cError)));
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_individual_registers() {
         let mock = setup_mock(&[
             // Test all register reads
@@ -1650,11 +4514,10 @@ mod tests {
         dev.set_year(Year(0x25)).await.unwrap();
         dev.set_aging_offset(AgingOffset(0x0A)).await.unwrap();
 
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_twelve_hour_mode() {
         let config = Config {
             time_representation: TimeRepresentation::TwelveHour,
@@ -1669,10 +4532,16 @@ mod tests {
             I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0]),
             // Write control register
             I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0]),
-            // Read hours register
-            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0]),
-            // Write hours register with 12-hour mode bit set
-            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0x40]), // Bit 6 set for 12-hour mode
+            // Read the time, 00:00:00 in 24-hour mode
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Seconds as u8], vec![0, 0, 0]),
+            // Write 12 AM in 12-hour mode and check the minutes did not wrap
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0x52]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Minutes as u8], vec![0]),
+            // Alarm 1 hours masked at 0, alarm 2 hours at 15
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm1Hours as u8], vec![0x80]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Alarm1Hours as u8, 0xD2]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm2Hours as u8], vec![0x15]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Alarm2Hours as u8, 0x63]),
         ]);
 
         let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
@@ -1681,11 +4550,63 @@ mod tests {
             dev.time_representation,
             Some(TimeRepresentation::TwelveHour)
         );
-        dev.i2c.done();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_set_time_representation() {
+        let mock = setup_mock(&[
+            // 12:30:00 PM to 24-hour mode
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x00, 0x30, 0x72],
+            ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0x12]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Minutes as u8], vec![0x30]),
+            // Alarm 1 hours hold no valid hour, only the mode bit changes
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm1Hours as u8], vec![0xFF]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Alarm1Hours as u8, 0xBF]),
+            // Alarm 2 hours are already in 24-hour mode
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm2Hours as u8], vec![0x23]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        dev.set_time_representation(TimeRepresentation::TwentyFourHour)
+            .await
+            .unwrap();
+        assert_eq!(
+            dev.time_representation,
+            Some(TimeRepresentation::TwentyFourHour)
+        );
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_set_time_representation_across_rollover() {
+        let mock = setup_mock(&[
+            // 15:59:59 to 12-hour mode
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x59, 0x59, 0x15],
+            ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0x63]),
+            // The minutes wrapped, so 4 PM is written
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Minutes as u8], vec![0x00]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0x64]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm1Hours as u8], vec![0x41]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Alarm2Hours as u8], vec![0x41]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        dev.set_time_representation(TimeRepresentation::TwelveHour)
+            .await
+            .unwrap();
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_read_alarm_mask_bits_and_dydt() {
         let mock = setup_mock(&[
             // Test reading alarm registers with mask bits set
@@ -1745,11 +4666,10 @@ mod tests {
         assert_eq!(alarm2_day_date.day_or_date(), 5); // Date 5
         assert_eq!(alarm2_day_date.ten_date(), 1); // Ten date 1
 
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_write_alarm_mask_bits_and_dydt() {
         let mock = setup_mock(&[
             // Test writing alarm registers with mask bits
@@ -1793,9 +4713,10 @@ mod tests {
         alarm2_day_date.set_day_date_select(DayDateSelect::Date); // Set to date mode
         dev.set_alarm2_day_date(alarm2_day_date).await.unwrap();
 
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
+    #[maybe_async_cfg::only_if(sync)]
     #[test]
     fn test_ds3231_error_display_coverage() {
         // Test DS3231Error Debug implementation for different error types
@@ -1816,8 +4737,8 @@ mod tests {
         assert!(debug_str.contains("Alarm"));
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[cfg(feature = "chrono")]
+    #[driver_test]
     async fn test_set_datetime_reads_time_representation_once() {
         let dt1 = NaiveDate::from_ymd_opt(2024, 3, 14)
             .unwrap()
@@ -1842,7 +4763,7 @@ mod tests {
                     0x00, // seconds
                     0x30, // minutes (BCD for 30)
                     0x15, // hours (BCD for 15 in 24-hour mode)
-                    0x04, // day (Thursday)
+                    0x05, // day (Thursday)
                     0x14, // date
                     0x03, // month
                     0x24, // year
@@ -1856,7 +4777,7 @@ mod tests {
                     0x00, // seconds
                     0x45, // minutes (BCD for 45)
                     0x16, // hours (BCD for 16 in 24-hour mode)
-                    0x05, // day (Friday)
+                    0x06, // day (Friday)
                     0x15, // date
                     0x03, // month
                     0x24, // year
@@ -1864,6 +4785,7 @@ mod tests {
             ),
         ]);
         let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+        dev.set_cache_policy(CachePolicy::TrustCache);
 
         // First call should read the hours register
         dev.set_datetime(&dt1).await.unwrap();
@@ -1879,11 +4801,184 @@ mod tests {
             Some(TimeRepresentation::TwentyFourHour)
         );
 
-        dev.i2c.done();
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_set_datetime_follows_time_representation_change() {
+        let dt = NaiveDate::from_ymd_opt(2024, 3, 14)
+            .unwrap()
+            .and_hms_opt(15, 30, 0)
+            .unwrap();
+
+        let mock = setup_mock(&[
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x15]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x00,
+                    0x30,
+                    0x15,
+                    0x05,
+                    0x14,
+                    0x03,
+                    0x24,
+                ],
+            ),
+            // Someone else switched the device to 12-hour mode
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x63]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x00,
+                    0x30,
+                    0x63,
+                    0x05,
+                    0x14,
+                    0x03,
+                    0x24,
+                ],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+        assert_eq!(dev.cache_policy(), CachePolicy::AlwaysRead);
+
+        dev.set_datetime(&dt).await.unwrap();
+        dev.set_datetime(&dt).await.unwrap();
+        assert_eq!(
+            dev.time_representation,
+            Some(TimeRepresentation::TwelveHour)
+        );
+
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_invalidate_rereads_time_representation() {
+        let dt = NaiveDate::from_ymd_opt(2024, 3, 14)
+            .unwrap()
+            .and_hms_opt(15, 30, 0)
+            .unwrap();
+
+        let mock = setup_mock(&[
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0x15]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x63]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x00,
+                    0x30,
+                    0x63,
+                    0x05,
+                    0x14,
+                    0x03,
+                    0x24,
+                ],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+        dev.set_cache_policy(CachePolicy::TrustCache);
+
+        dev.set_hour(Hours(0x15)).await.unwrap();
+        dev.invalidate();
+        assert_eq!(dev.time_representation, None);
+        dev.set_datetime(&dt).await.unwrap();
+
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_trust_cache_serves_configuration_registers() {
+        let mock = setup_mock(&[
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1C]),
+            // The 12/24 hour setting is not known yet
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x00]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Alarm2Minutes as u8, 0x30, 0x08, 0x80],
+            ),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::AgingOffset as u8, 0x05]),
+            // The status register is never served from the shadow
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x88],
+            ),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::ControlStatus as u8],
+                vec![0x89],
+            ),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+        dev.set_cache_policy(CachePolicy::TrustCache);
+
+        assert_eq!(dev.control().await.unwrap(), Control(0x1C));
+        assert_eq!(dev.control().await.unwrap(), Control(0x1C));
+        dev.set_alarm2(&Alarm2Config::AtTime {
+            hours: 8,
+            minutes: 30,
+            is_pm: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(
+            dev.alarm2().await.unwrap(),
+            Alarm2Config::AtTime {
+                hours: 8,
+                minutes: 30,
+                is_pm: None,
+            }
+        );
+        dev.set_aging_offset(AgingOffset(0x05)).await.unwrap();
+        assert_eq!(dev.aging_offset().await.unwrap(), AgingOffset(0x05));
+        assert_eq!(dev.status().await.unwrap(), Status(0x88));
+        assert_eq!(dev.status().await.unwrap(), Status(0x89));
+
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_always_read_ignores_shadow() {
+        let mock = setup_mock(&[
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0x1C]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x04]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        dev.set_control(Control(0x1C)).await.unwrap();
+        assert_eq!(dev.control().await.unwrap(), Control(0x04));
+
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_invalidate_on_error() {
+        let mock = setup_mock(&[
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1C]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Control as u8, 0x1D])
+                .with_error(I2cErrorKind::Other),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Control as u8], vec![0x1D]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+        dev.set_cache_policy(CachePolicy::InvalidateOnError);
+
+        assert_eq!(dev.control().await.unwrap(), Control(0x1C));
+        let result = dev.set_control(Control(0x1D)).await;
+        assert!(matches!(result, Err(DS3231Error::I2c(_))));
+        // The failed write may or may not have reached the device
+        assert_eq!(dev.control().await.unwrap(), Control(0x1D));
+
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[cfg(feature = "chrono")]
+    #[driver_test]
     async fn test_set_datetime_detects_twelve_hour_mode() {
         let dt = NaiveDate::from_ymd_opt(2024, 3, 14)
             .unwrap()
@@ -1904,7 +4999,7 @@ mod tests {
                     0x00, // seconds
                     0x30, // minutes (BCD for 30)
                     0x63, // hours (BCD for 3 PM in 12-hour mode)
-                    0x04, // day (Thursday)
+                    0x05, // day (Thursday)
                     0x14, // date
                     0x03, // month
                     0x24, // year
@@ -1918,11 +5013,10 @@ mod tests {
             dev.time_representation,
             Some(TimeRepresentation::TwelveHour)
         );
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_hour_caches_time_representation() {
         let mock = setup_mock(&[I2cTrans::write_read(
             DEVICE_ADDRESS,
@@ -1944,11 +5038,10 @@ mod tests {
             Some(TimeRepresentation::TwelveHour)
         );
 
-        dev.i2c.done();
+        dev.interface.i2c.done();
     }
 
-    #[cfg_attr(feature = "async", tokio::test)]
-    #[cfg_attr(not(feature = "async"), test)]
+    #[driver_test]
     async fn test_set_hour_caches_time_representation() {
         let mut hours = Hours::default();
         hours.set_time_representation(TimeRepresentation::TwelveHour);
@@ -1973,6 +5066,314 @@ mod tests {
             Some(TimeRepresentation::TwelveHour)
         );
 
-        dev.i2c.done();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_ds3232_read_sram() {
+        let mock = setup_mock(&[I2cTrans::write_read(
+            DEVICE_ADDRESS,
+            vec![0x14 + 10],
+            vec![0xDE, 0xAD, 0xBE, 0xEF],
+        )]);
+        let mut dev = DS3231::new_ds3232(mock, DEVICE_ADDRESS);
+
+        let mut data = [0; 4];
+        dev.read_sram(10, &mut data).await.unwrap();
+        assert_eq!(data, [0xDE, 0xAD, 0xBE, 0xEF]);
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_ds3232_write_sram() {
+        let mock = setup_mock(&[I2cTrans::write(DEVICE_ADDRESS, vec![0xFE, 0x12, 0x34])]);
+        let mut dev = DS3231::new_ds3232(mock, DEVICE_ADDRESS);
+
+        dev.write_sram(234, &[0x12, 0x34]).await.unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_ds3232_write_sram_long() {
+        let data = [0xA5; 32];
+        let mock = setup_mock(&[
+            I2cTrans::transaction_start(DEVICE_ADDRESS),
+            I2cTrans::write(DEVICE_ADDRESS, vec![0x14]),
+            I2cTrans::write(DEVICE_ADDRESS, data.to_vec()),
+            I2cTrans::transaction_end(DEVICE_ADDRESS),
+        ]);
+        let mut dev = DS3231::new_ds3232(mock, DEVICE_ADDRESS);
+
+        dev.write_sram(0, &data).await.unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_ds3232_sram_out_of_range() {
+        let mock = setup_mock(&[]);
+        let mut dev = DS3231::new_ds3232(mock, DEVICE_ADDRESS);
+
+        let mut data = [0; 2];
+        let err = dev.read_sram(235, &mut data).await.unwrap_err();
+        assert!(matches!(err, DS3231Error::SramOutOfRange));
+        let err = dev.write_sram(230, &[0; 7]).await.unwrap_err();
+        assert!(matches!(err, DS3231Error::SramOutOfRange));
+        let err = dev.sram_byte(236).await.unwrap_err();
+        assert!(matches!(err, DS3231Error::SramOutOfRange));
+        let err = dev.set_sram_byte(255, 0).await.unwrap_err();
+        assert!(matches!(err, DS3231Error::SramOutOfRange));
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_ds3232_sram_byte() {
+        let mock = setup_mock(&[
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x14], vec![0x5A]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![0xFF, 0xA5]),
+        ]);
+        let mut dev = DS3231::new_ds3232(mock, DEVICE_ADDRESS);
+
+        assert_eq!(dev.sram_byte(0).await.unwrap(), 0x5A);
+        dev.set_sram_byte(235, 0xA5).await.unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_ds3232_temperature_control() {
+        let mock = setup_mock(&[
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::TemperatureControl as u8],
+                vec![0x01],
+            ),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::TemperatureControl as u8, 0x00],
+            ),
+        ]);
+        let mut dev = DS3231::new_ds3232(mock, DEVICE_ADDRESS);
+
+        let mut control = dev.temperature_control().await.unwrap();
+        assert!(control.battery_backed_temperature_disable());
+        control.set_battery_backed_temperature_disable(false);
+        dev.set_temperature_control(control).await.unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_ds3232_shares_ds3231_registers() {
+        let mock = setup_mock(&[I2cTrans::write_read(
+            DEVICE_ADDRESS,
+            vec![RegAddr::ControlStatus as u8],
+            vec![0x68],
+        )]);
+        let mut dev = DS3231::new_ds3232(mock, DEVICE_ADDRESS);
+
+        let status = dev.status().await.unwrap();
+        assert!(status.battery_backed_32khz_output());
+        assert_eq!(
+            status.conversion_rate(),
+            TemperatureConversionRate::Seconds256
+        );
+        assert!(status.enable_32khz_output());
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_ds3234_read_datetime() {
+        let spi = SpiMock::new(&[
+            SpiTrans::transaction_start(),
+            SpiTrans::write(RegAddr::Seconds as u8),
+            SpiTrans::read_vec(vec![0x56, 0x34, 0x12, 0x03, 0x15, 0x03, 0x24]),
+            SpiTrans::transaction_end(),
+        ]);
+        let mut dev = DS3231::new_ds3234(spi);
+
+        let dt = dev.datetime().await.unwrap();
+        assert_eq!(dt.hour(), 12);
+        assert_eq!(dt.minute(), 34);
+        assert_eq!(dt.second(), 56);
+        assert_eq!(dt.year(), 2024);
+        assert_eq!(dt.month(), 3);
+        assert_eq!(dt.day(), 15);
+        dev.interface.spi.done();
+    }
+
+    #[driver_test]
+    async fn test_ds3234_register_write() {
+        let spi = SpiMock::new(&[
+            SpiTrans::transaction_start(),
+            SpiTrans::write(0x80 | RegAddr::Control as u8),
+            SpiTrans::write_vec(vec![0x1C]),
+            SpiTrans::transaction_end(),
+        ]);
+        let mut dev = DS3231::new_ds3234(spi);
+
+        dev.set_control(Control(0x1C)).await.unwrap();
+        dev.interface.spi.done();
+    }
+
+    #[driver_test]
+    async fn test_ds3234_sram() {
+        let spi = SpiMock::new(&[
+            // write_sram(250, ..)
+            SpiTrans::transaction_start(),
+            SpiTrans::write(0x80 | RegAddr::SramAddress as u8),
+            SpiTrans::write_vec(vec![250]),
+            SpiTrans::transaction_end(),
+            SpiTrans::transaction_start(),
+            SpiTrans::write(0x80 | RegAddr::SramData as u8),
+            SpiTrans::write_vec(vec![1, 2, 3, 4, 5, 6]),
+            SpiTrans::transaction_end(),
+            // read_sram(250, ..)
+            SpiTrans::transaction_start(),
+            SpiTrans::write(0x80 | RegAddr::SramAddress as u8),
+            SpiTrans::write_vec(vec![250]),
+            SpiTrans::transaction_end(),
+            SpiTrans::transaction_start(),
+            SpiTrans::write(RegAddr::SramData as u8),
+            SpiTrans::read_vec(vec![1, 2, 3, 4, 5, 6]),
+            SpiTrans::transaction_end(),
+        ]);
+        let mut dev = DS3231::new_ds3234(spi);
+
+        dev.write_sram(250, &[1, 2, 3, 4, 5, 6]).await.unwrap();
+        let mut data = [0; 6];
+        dev.read_sram(250, &mut data).await.unwrap();
+        assert_eq!(data, [1, 2, 3, 4, 5, 6]);
+
+        let err = dev.read_sram(251, &mut data).await.unwrap_err();
+        assert!(matches!(err, DS3231Error::SramOutOfRange));
+        dev.interface.spi.done();
+    }
+
+    #[driver_test]
+    async fn test_ds3234_sram_registers() {
+        let spi = SpiMock::new(&[
+            SpiTrans::transaction_start(),
+            SpiTrans::write(0x80 | RegAddr::SramAddress as u8),
+            SpiTrans::write_vec(vec![0x10]),
+            SpiTrans::transaction_end(),
+            SpiTrans::transaction_start(),
+            SpiTrans::write(0x80 | RegAddr::SramData as u8),
+            SpiTrans::write_vec(vec![0x42]),
+            SpiTrans::transaction_end(),
+            SpiTrans::transaction_start(),
+            SpiTrans::write(RegAddr::SramAddress as u8),
+            SpiTrans::read_vec(vec![0x11]),
+            SpiTrans::transaction_end(),
+            SpiTrans::transaction_start(),
+            SpiTrans::write(RegAddr::SramData as u8),
+            SpiTrans::read_vec(vec![0x99]),
+            SpiTrans::transaction_end(),
+        ]);
+        let mut dev = DS3231::new_ds3234(spi);
+
+        dev.set_sram_address(0x10).await.unwrap();
+        dev.set_sram_data(0x42).await.unwrap();
+        assert_eq!(dev.sram_address().await.unwrap(), 0x11);
+        assert_eq!(dev.sram_data().await.unwrap(), 0x99);
+        dev.interface.spi.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_snapshot_single_burst() {
+        let mock = setup_mock(&[I2cTrans::write_read(
+            DEVICE_ADDRESS,
+            vec![RegAddr::Seconds as u8],
+            vec![
+                0x59, 0x59, 0x23, 0x03, 0x31, 0x12, 0x24, 0x00, 0x00, 0x00, 0x80,
+            ],
+        )]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let snapshot = dev
+            .snapshot(RegisterGroups::TIME | RegisterGroups::ALARM1)
+            .await
+            .unwrap();
+        let datetime = snapshot.datetime().unwrap().unwrap();
+        assert_eq!(datetime.year(), 2024);
+        assert_eq!(datetime.hour(), 23);
+        assert_eq!(datetime.second(), 59);
+        assert_eq!(
+            snapshot.alarm1().unwrap().to_config(),
+            Alarm1Config::AtTime {
+                hours: 0,
+                minutes: 0,
+                seconds: 0,
+                is_pm: None,
+            }
+        );
+        assert_eq!(snapshot.status(), None);
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_snapshot_verifies_separate_bursts() {
+        let time = vec![0x59, 0x59, 0x23, 0x03, 0x31, 0x12, 0x24];
+        let mock = setup_mock(&[
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x00], time.clone()),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x0F], vec![0x00]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x00], time.clone()),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x0F], vec![0x00]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let snapshot = dev
+            .snapshot(RegisterGroups::TIME | RegisterGroups::STATUS)
+            .await
+            .unwrap();
+        assert_eq!(snapshot.status(), Some(Status(0x00)));
+        dev.interface.i2c.done();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_snapshot_retries_torn_read() {
+        // The clock rolls over and sets the alarm 1 flag between the two bursts of the
+        // first pass
+        let before = vec![0x59, 0x59, 0x23, 0x03, 0x31, 0x12, 0x24];
+        let after = vec![0x00, 0x00, 0x00, 0x04, 0x01, 0x81, 0x25];
+        let mock = setup_mock(&[
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x00], before),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x0F], vec![0x01]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x00], after.clone()),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x0F], vec![0x01]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x00], after),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x0F], vec![0x01]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let snapshot = dev
+            .snapshot(RegisterGroups::TIME | RegisterGroups::STATUS)
+            .await
+            .unwrap();
+        let datetime = snapshot.datetime().unwrap().unwrap();
+        assert_eq!(datetime.year(), 2125);
+        assert_eq!(datetime.hour(), 0);
+        assert!(snapshot.status().unwrap().alarm1_flag());
+        dev.interface.i2c.done();
+    }
+
+    #[driver_test]
+    async fn test_snapshot_unstable() {
+        let mock = setup_mock(&[
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x0E], vec![0x1C]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x11], vec![0x19, 0x00]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x0E], vec![0x1C]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x11], vec![0x19, 0x40]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x0E], vec![0x1C]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![0x11], vec![0x19, 0x80]),
+        ]);
+        let mut dev = DS3231::new(mock, DEVICE_ADDRESS);
+
+        let err = dev
+            .snapshot(RegisterGroups::CONTROL | RegisterGroups::TEMPERATURE)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DS3231Error::SnapshotUnstable));
+        dev.interface.i2c.done();
     }
 }
@@ -48,6 +48,14 @@ pub enum RegAddr {
     MSBTemp = 0x11,
     /// Temperature LSB register
     LSBTemp = 0x12,
+    /// Temperature control register (DS3232 and DS3234 only)
+    TemperatureControl = 0x13,
+    /// First byte of the battery-backed SRAM (DS3232 only, 0x14-0xFF)
+    SramStart = 0x14,
+    /// SRAM address register (DS3234 only)
+    SramAddress = 0x18,
+    /// SRAM data register (DS3234 only)
+    SramData = 0x19,
 }
 
 /// Time representation format for the DS3231.
@@ -172,6 +180,44 @@ impl From<SquareWaveFrequency> for u8 {
     }
 }
 
+/// Temperature conversion rate (DS3232 only).
+///
+/// Selects how often the DS3232 performs a temperature conversion and adjusts the
+/// oscillator. The DS3231 always converts every 64 seconds and ignores these bits.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TemperatureConversionRate {
+    /// Convert every 64 seconds
+    Seconds64 = 0b00,
+    /// Convert every 128 seconds
+    Seconds128 = 0b01,
+    /// Convert every 256 seconds
+    Seconds256 = 0b10,
+    /// Convert every 512 seconds
+    Seconds512 = 0b11,
+}
+impl From<u8> for TemperatureConversionRate {
+    /// Creates a `TemperatureConversionRate` from a raw register value.
+    ///
+    /// # Panics
+    /// Panics if the value is not 0b00, 0b01, 0b10, or 0b11.
+    fn from(v: u8) -> Self {
+        match v {
+            0b00 => TemperatureConversionRate::Seconds64,
+            0b01 => TemperatureConversionRate::Seconds128,
+            0b10 => TemperatureConversionRate::Seconds256,
+            0b11 => TemperatureConversionRate::Seconds512,
+            _ => panic!("Invalid value for TemperatureConversionRate: {}", v),
+        }
+    }
+}
+impl From<TemperatureConversionRate> for u8 {
+    /// Converts a `TemperatureConversionRate` to its raw register value.
+    fn from(v: TemperatureConversionRate) -> Self {
+        v as u8
+    }
+}
+
 /// Day/Date select for alarm registers (DY/DT bit).
 ///
 /// This controls whether the alarm day/date register matches against
@@ -440,6 +486,10 @@ bitfield! {
     impl Debug;
     /// Oscillator stop flag
     pub oscillator_stop_flag, set_oscillator_stop_flag: 7;
+    /// Enable 32kHz output on battery power (`BB32kHz`, DS3232 only)
+    pub battery_backed_32khz_output, set_battery_backed_32khz_output: 6;
+    /// Temperature conversion rate (`CRATE1`/`CRATE0`, DS3232 only)
+    pub from into TemperatureConversionRate, conversion_rate, set_conversion_rate: 5, 4;
     /// Enable 32kHz output
     pub enable_32khz_output, set_enable_32khz_output: 3;
     /// Device busy flag
@@ -460,6 +510,20 @@ impl defmt::Format for Status {
             defmt::write!(f, "OSF");
             first = false;
         }
+        if self.battery_backed_32khz_output() {
+            if !first {
+                defmt::write!(f, ", ");
+            }
+            defmt::write!(f, "BB32kHz");
+            first = false;
+        }
+        if self.conversion_rate() != TemperatureConversionRate::Seconds64 {
+            if !first {
+                defmt::write!(f, ", ");
+            }
+            defmt::write!(f, "CRATE={}", self.conversion_rate());
+            first = false;
+        }
         if self.enable_32khz_output() {
             if !first {
                 defmt::write!(f, ", ");
@@ -556,6 +620,31 @@ impl defmt::Format for TemperatureFraction {
     }
 }
 
+bitfield! {
+    /// Temperature control register (DS3232 only).
+    #[derive(Clone, Copy, Default, PartialEq)]
+    pub struct TemperatureControl(u8);
+    impl Debug;
+    /// Disable temperature conversions on battery power (`BB_TD`)
+    pub battery_backed_temperature_disable, set_battery_backed_temperature_disable: 0;
+}
+from_register_u8!(TemperatureControl);
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for TemperatureControl {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "TemperatureControl({})",
+            if self.battery_backed_temperature_disable() {
+                "BB_TD"
+            } else {
+                "clear"
+            }
+        );
+    }
+}
+
 // Alarm register types with mask bits and special control bits
 
 bitfield! {
@@ -920,6 +1009,61 @@ mod tests {
         assert_eq!(u8::from(status), 0x88);
     }
 
+    #[test]
+    fn test_status_register_ds3232_bits() {
+        // BB32kHz and CRATE = 512 seconds
+        let status = Status::from(0x70);
+        assert!(status.battery_backed_32khz_output());
+        assert_eq!(
+            status.conversion_rate(),
+            TemperatureConversionRate::Seconds512
+        );
+        assert!(!status.oscillator_stop_flag());
+        assert!(!status.enable_32khz_output());
+
+        let mut status = Status::from(0x88);
+        assert!(!status.battery_backed_32khz_output());
+        assert_eq!(
+            status.conversion_rate(),
+            TemperatureConversionRate::Seconds64
+        );
+        status.set_conversion_rate(TemperatureConversionRate::Seconds128);
+        assert_eq!(u8::from(status), 0x98);
+        status.set_battery_backed_32khz_output(true);
+        assert_eq!(u8::from(status), 0xD8);
+    }
+
+    #[test]
+    fn test_temperature_conversion_rate_conversions() {
+        for (value, rate) in [
+            (0b00, TemperatureConversionRate::Seconds64),
+            (0b01, TemperatureConversionRate::Seconds128),
+            (0b10, TemperatureConversionRate::Seconds256),
+            (0b11, TemperatureConversionRate::Seconds512),
+        ] {
+            assert_eq!(TemperatureConversionRate::from(value), rate);
+            assert_eq!(u8::from(rate), value);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value for TemperatureConversionRate: 4")]
+    fn test_invalid_temperature_conversion_rate_conversion() {
+        let _ = TemperatureConversionRate::from(4);
+    }
+
+    #[test]
+    fn test_temperature_control_register_conversions() {
+        let control = TemperatureControl::from(0x01);
+        assert!(control.battery_backed_temperature_disable());
+        assert_eq!(u8::from(control), 0x01);
+
+        let mut control = TemperatureControl::default();
+        assert!(!control.battery_backed_temperature_disable());
+        control.set_battery_backed_temperature_disable(true);
+        assert_eq!(control.0, 0x01);
+    }
+
     #[test]
     fn test_aging_offset_register_conversions() {
         // Test positive aging offset
@@ -0,0 +1,415 @@
+//! Implementation of the [`rtcc`](https://docs.rs/rtcc) real-time clock traits.
+//!
+//! With the `rtcc` feature enabled, the blocking [`DS3231`] implements `rtcc::DateTimeAccess`
+//! and `rtcc::Rtcc`, so it can be used by HAL-agnostic code written against those traits (for
+//! example in place of the `ds323x` driver). The trait methods map onto the driver's register
+//! accessors and use [`DS3231Error`] as their error type.
+//!
+//! # Conventions
+//!
+//! - `weekday`/`set_weekday` use the raw day-of-week register value (1-7), which the date
+//!   setters write in the driver's `WeekdayNumbering`
+//! - `set_hours` with `Hours::AM`/`Hours::PM` switches the device to 12-hour mode, and with
+//!   `Hours::H24` to 24-hour mode
+//! - Values outside the range of a register are rejected with `DS3231Error::DateTime`
+
+use ::rtcc::{DateTimeAccess, Hours as RtccHours, NaiveDate, NaiveDateTime, NaiveTime, Rtcc};
+
+use crate::chip::Chip;
+use crate::datetime::{DS3231DateTime, DS3231DateTimeError};
+use crate::interface::Interface;
+use crate::registers::RegAddr;
+use crate::{DS3231Error, Date, Day, Minutes, Month, Seconds, TimeRepresentation, Year, DS3231};
+
+/// Combines the tens and ones digits of a BCD register field.
+fn from_bcd(tens: u8, ones: u8) -> u8 {
+    10 * tens + ones
+}
+
+/// Splits `value` into (ones, tens) BCD digits after checking it is within `min..=max`.
+fn to_bcd<E>(value: u8, min: u8, max: u8) -> Result<(u8, u8), DS3231Error<E>> {
+    if value < min {
+        return Err(DS3231Error::DateTime(DS3231DateTimeError::InvalidDateTime));
+    }
+    DS3231DateTime::make_bcd(u32::from(value), u32::from(max)).map_err(DS3231Error::DateTime)
+}
+
+impl<IF, E, C> DateTimeAccess for DS3231<IF, C>
+where
+    IF: Interface<Error = E>,
+    C: Chip,
+{
+    type Error = DS3231Error<E>;
+
+    fn datetime(&mut self) -> Result<NaiveDateTime, Self::Error> {
+        DS3231::datetime(self)
+    }
+
+    fn set_datetime(&mut self, datetime: &NaiveDateTime) -> Result<(), Self::Error> {
+        DS3231::set_datetime(self, datetime)
+    }
+}
+
+impl<IF, E, C> Rtcc for DS3231<IF, C>
+where
+    IF: Interface<Error = E>,
+    C: Chip,
+{
+    fn seconds(&mut self) -> Result<u8, Self::Error> {
+        let seconds = self.second()?;
+        Ok(from_bcd(seconds.ten_seconds(), seconds.seconds()))
+    }
+
+    fn minutes(&mut self) -> Result<u8, Self::Error> {
+        let minutes = self.minute()?;
+        Ok(from_bcd(minutes.ten_minutes(), minutes.minutes()))
+    }
+
+    fn hours(&mut self) -> Result<RtccHours, Self::Error> {
+        let hours = self.hour()?;
+        let value = from_bcd(hours.ten_hours(), hours.hours());
+        Ok(match hours.time_representation() {
+            TimeRepresentation::TwentyFourHour => {
+                RtccHours::H24(value + 20 * hours.pm_or_twenty_hours())
+            }
+            TimeRepresentation::TwelveHour if hours.pm_or_twenty_hours() != 0 => {
+                RtccHours::PM(value)
+            }
+            TimeRepresentation::TwelveHour => RtccHours::AM(value),
+        })
+    }
+
+    fn time(&mut self) -> Result<NaiveTime, Self::Error> {
+        // Read all registers in one burst so the fields can not roll over between reads
+        Ok(DS3231::datetime(self)?.time())
+    }
+
+    fn weekday(&mut self) -> Result<u8, Self::Error> {
+        Ok(DS3231::day(self)?.day())
+    }
+
+    fn day(&mut self) -> Result<u8, Self::Error> {
+        let date = DS3231::date(self)?;
+        Ok(from_bcd(date.ten_date(), date.date()))
+    }
+
+    fn month(&mut self) -> Result<u8, Self::Error> {
+        let month = DS3231::month(self)?;
+        Ok(from_bcd(month.ten_month(), month.month()))
+    }
+
+    fn year(&mut self) -> Result<u16, Self::Error> {
+        let mut data = [0; 2];
+        self.read_registers(RegAddr::Month as u8, &mut data)?;
+        let month = Month(data[0]);
+        let year = Year(data[1]);
+        Ok(2000
+            + 100 * u16::from(month.century())
+            + u16::from(from_bcd(year.ten_year(), year.year())))
+    }
+
+    fn date(&mut self) -> Result<NaiveDate, Self::Error> {
+        // Read all registers in one burst so the fields can not roll over between reads
+        Ok(DS3231::datetime(self)?.date())
+    }
+
+    fn set_seconds(&mut self, seconds: u8) -> Result<(), Self::Error> {
+        let (ones, tens) = to_bcd(seconds, 0, 59)?;
+        let mut value = Seconds::default();
+        value.set_seconds(ones);
+        value.set_ten_seconds(tens);
+        self.set_second(value)
+    }
+
+    fn set_minutes(&mut self, minutes: u8) -> Result<(), Self::Error> {
+        let (ones, tens) = to_bcd(minutes, 0, 59)?;
+        let mut value = Minutes::default();
+        value.set_minutes(ones);
+        value.set_ten_minutes(tens);
+        self.set_minute(value)
+    }
+
+    fn set_hours(&mut self, hours: RtccHours) -> Result<(), Self::Error> {
+        let (hour, time_representation) = match hours {
+            RtccHours::H24(hour) => (hour, TimeRepresentation::TwentyFourHour),
+            RtccHours::AM(hour) if (1..=12).contains(&hour) => {
+                (hour % 12, TimeRepresentation::TwelveHour)
+            }
+            RtccHours::PM(hour) if (1..=12).contains(&hour) => {
+                (hour % 12 + 12, TimeRepresentation::TwelveHour)
+            }
+            RtccHours::AM(_) | RtccHours::PM(_) => {
+                return Err(DS3231Error::DateTime(DS3231DateTimeError::InvalidDateTime))
+            }
+        };
+        let value = DS3231DateTime::convert_hours(u32::from(hour), time_representation)
+            .map_err(DS3231Error::DateTime)?;
+        self.set_hour(value)
+    }
+
+    fn set_time(&mut self, time: &NaiveTime) -> Result<(), Self::Error> {
+        let time_representation = self.current_time_representation()?;
+        // Only the time registers are written, the date is a placeholder
+        let datetime = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_time(*time);
+        let raw = DS3231DateTime::from_datetime(&datetime, time_representation)
+            .map_err(DS3231Error::DateTime)?;
+        let registers: [u8; 7] = (&raw).into();
+        self.write_registers(RegAddr::Seconds as u8, &registers[..3])?;
+        Ok(())
+    }
+
+    fn set_weekday(&mut self, weekday: u8) -> Result<(), Self::Error> {
+        if !(1..=7).contains(&weekday) {
+            return Err(DS3231Error::DateTime(DS3231DateTimeError::InvalidDateTime));
+        }
+        let mut value = Day::default();
+        value.set_day(weekday);
+        DS3231::set_day(self, value)
+    }
+
+    fn set_day(&mut self, day: u8) -> Result<(), Self::Error> {
+        let (ones, tens) = to_bcd(day, 1, 31)?;
+        let mut value = Date::default();
+        value.set_date(ones);
+        value.set_ten_date(tens);
+        DS3231::set_date(self, value)
+    }
+
+    fn set_month(&mut self, month: u8) -> Result<(), Self::Error> {
+        let (ones, tens) = to_bcd(month, 1, 12)?;
+        // Keep the century flag
+        let mut value = DS3231::month(self)?;
+        value.set_month(ones);
+        value.set_ten_month(tens);
+        DS3231::set_month(self, value)
+    }
+
+    fn set_year(&mut self, year: u16) -> Result<(), Self::Error> {
+        if year < 2000 {
+            return Err(DS3231Error::DateTime(DS3231DateTimeError::YearNotAfter1999));
+        }
+        if year > 2199 {
+            return Err(DS3231Error::DateTime(
+                DS3231DateTimeError::YearNotBefore2200,
+            ));
+        }
+        let offset = u8::try_from(year - 2000).unwrap();
+        let (ones, tens) = to_bcd(offset % 100, 0, 99)?;
+        let mut value = Year::default();
+        value.set_year(ones);
+        value.set_ten_year(tens);
+        let mut month = DS3231::month(self)?;
+        month.set_century(offset >= 100);
+        // The month and year registers are adjacent, so the century flag and the year are
+        // written together
+        self.write_registers(RegAddr::Month as u8, &[month.into(), value.into()])?;
+        Ok(())
+    }
+
+    fn set_date(&mut self, date: &NaiveDate) -> Result<(), Self::Error> {
+        // Only the date registers are written, the time is a placeholder
+        let raw = DS3231DateTime::from_datetime(
+            &date.and_time(NaiveTime::MIN),
+            TimeRepresentation::TwentyFourHour,
+        )
+        .map_err(DS3231Error::DateTime)?
+        .with_weekday_numbering(self.weekday_numbering);
+        let registers: [u8; 7] = (&raw).into();
+        self.write_registers(RegAddr::Day as u8, &registers[3..])?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::vec;
+
+    use super::*;
+    use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTrans};
+
+    const DEVICE_ADDRESS: u8 = 0x68;
+
+    fn setup(expectations: &[I2cTrans]) -> DS3231<crate::I2cInterface<I2cMock>> {
+        DS3231::new(I2cMock::new(expectations), DEVICE_ADDRESS)
+    }
+
+    #[test]
+    fn test_datetime_access() {
+        let mut dev = setup(&[
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x56, 0x34, 0x12, 0x06, 0x15, 0x03, 0x24],
+            ),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x12]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![
+                    RegAddr::Seconds as u8,
+                    0x56,
+                    0x34,
+                    0x12,
+                    0x06,
+                    0x15,
+                    0x03,
+                    0x24,
+                ],
+            ),
+        ]);
+
+        let dt = DateTimeAccess::datetime(&mut dev).unwrap();
+        assert_eq!(
+            dt,
+            NaiveDate::from_ymd_opt(2024, 3, 15)
+                .unwrap()
+                .and_hms_opt(12, 34, 56)
+                .unwrap()
+        );
+        DateTimeAccess::set_datetime(&mut dev, &dt).unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[test]
+    fn test_read_fields() {
+        let mut dev = setup(&[
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Seconds as u8], vec![0x59]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Minutes as u8], vec![0x42]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Day as u8], vec![0x07]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Date as u8], vec![0x31]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Month as u8], vec![0x12]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Month as u8], vec![0x81, 0x05]),
+        ]);
+
+        assert_eq!(dev.seconds().unwrap(), 59);
+        assert_eq!(dev.minutes().unwrap(), 42);
+        assert_eq!(dev.weekday().unwrap(), 7);
+        assert_eq!(Rtcc::day(&mut dev).unwrap(), 31);
+        assert_eq!(Rtcc::month(&mut dev).unwrap(), 12);
+        assert_eq!(Rtcc::year(&mut dev).unwrap(), 2105);
+        dev.interface.i2c.done();
+    }
+
+    #[test]
+    fn test_hours() {
+        let mut dev = setup(&[
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x23]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x71]),
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x52]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0x21]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0x72]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Hours as u8, 0x52]),
+        ]);
+
+        assert_eq!(dev.hours().unwrap(), RtccHours::H24(23));
+        assert_eq!(dev.hours().unwrap(), RtccHours::PM(11));
+        assert_eq!(dev.hours().unwrap(), RtccHours::AM(12));
+        dev.set_hours(RtccHours::H24(21)).unwrap();
+        dev.set_hours(RtccHours::PM(12)).unwrap();
+        dev.set_hours(RtccHours::AM(12)).unwrap();
+        assert!(matches!(
+            dev.set_hours(RtccHours::AM(13)),
+            Err(DS3231Error::DateTime(DS3231DateTimeError::InvalidDateTime))
+        ));
+        assert!(matches!(
+            dev.set_hours(RtccHours::H24(24)),
+            Err(DS3231Error::DateTime(DS3231DateTimeError::InvalidDateTime))
+        ));
+        dev.interface.i2c.done();
+    }
+
+    #[test]
+    fn test_set_fields() {
+        let mut dev = setup(&[
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Seconds as u8, 0x45]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Minutes as u8, 0x07]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Day as u8, 0x03]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Date as u8, 0x29]),
+            // set_month keeps the century flag
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Month as u8], vec![0x81]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Month as u8, 0x92]),
+            // set_year updates the century flag with the year
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Month as u8], vec![0x92]),
+            I2cTrans::write(DEVICE_ADDRESS, vec![RegAddr::Month as u8, 0x12, 0x99]),
+        ]);
+
+        dev.set_seconds(45).unwrap();
+        dev.set_minutes(7).unwrap();
+        dev.set_weekday(3).unwrap();
+        Rtcc::set_day(&mut dev, 29).unwrap();
+        Rtcc::set_month(&mut dev, 12).unwrap();
+        Rtcc::set_year(&mut dev, 2099).unwrap();
+        dev.interface.i2c.done();
+    }
+
+    #[test]
+    fn test_set_fields_out_of_range() {
+        let mut dev = setup(&[]);
+
+        let invalid = |r: Result<(), DS3231Error<_>>| {
+            matches!(
+                r,
+                Err(DS3231Error::DateTime(DS3231DateTimeError::InvalidDateTime))
+            )
+        };
+        assert!(invalid(dev.set_seconds(60)));
+        assert!(invalid(dev.set_minutes(60)));
+        assert!(invalid(dev.set_weekday(0)));
+        assert!(invalid(dev.set_weekday(8)));
+        assert!(invalid(Rtcc::set_day(&mut dev, 0)));
+        assert!(invalid(Rtcc::set_day(&mut dev, 32)));
+        assert!(invalid(Rtcc::set_month(&mut dev, 0)));
+        assert!(invalid(Rtcc::set_month(&mut dev, 13)));
+        assert!(matches!(
+            Rtcc::set_year(&mut dev, 1999),
+            Err(DS3231Error::DateTime(DS3231DateTimeError::YearNotAfter1999))
+        ));
+        assert!(matches!(
+            Rtcc::set_year(&mut dev, 2200),
+            Err(DS3231Error::DateTime(
+                DS3231DateTimeError::YearNotBefore2200
+            ))
+        ));
+        dev.interface.i2c.done();
+    }
+
+    #[test]
+    fn test_time_and_date() {
+        let mut dev = setup(&[
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x05, 0x30, 0x08, 0x02, 0x01, 0x87, 0x01],
+            ),
+            I2cTrans::write_read(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8],
+                vec![0x05, 0x30, 0x08, 0x02, 0x01, 0x87, 0x01],
+            ),
+            // set_time reads the time representation once, then writes only the time
+            I2cTrans::write_read(DEVICE_ADDRESS, vec![RegAddr::Hours as u8], vec![0x48]),
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Seconds as u8, 0x00, 0x15, 0x61],
+            ),
+            // set_date writes only the date
+            I2cTrans::write(
+                DEVICE_ADDRESS,
+                vec![RegAddr::Day as u8, 0x05, 0x29, 0x02, 0x24],
+            ),
+        ]);
+
+        assert_eq!(
+            dev.time().unwrap(),
+            NaiveTime::from_hms_opt(8, 30, 5).unwrap()
+        );
+        assert_eq!(
+            Rtcc::date(&mut dev).unwrap(),
+            NaiveDate::from_ymd_opt(2101, 7, 1).unwrap()
+        );
+        dev.set_time(&NaiveTime::from_hms_opt(13, 15, 0).unwrap())
+            .unwrap();
+        Rtcc::set_date(&mut dev, &NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()).unwrap();
+        dev.interface.i2c.done();
+    }
+}
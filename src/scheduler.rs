@@ -0,0 +1,470 @@
+//! Software alarms multiplexed over one hardware alarm.
+//!
+//! The DS3231 has two alarms. An [`AlarmScheduler`] holds up to `N` logical alarms, each with
+//! a tag chosen by the application, and keeps the earliest pending one programmed into Alarm 1
+//! or Alarm 2. Call `service_scheduler` on the driver at startup, after changing the alarms and
+//! whenever the hardware alarm fires (A1F/A2F, or the INT pin): it clears the alarm flag,
+//! reports the logical alarms that are due, and programs the next one.
+//!
+//! ```rust,ignore
+//! use ds3231::{AlarmId, AlarmScheduler, Schedule};
+//!
+//! let mut scheduler: AlarmScheduler<Task, 8> = AlarmScheduler::new(AlarmId::Alarm1);
+//! let now = rtc.datetime()?;
+//! scheduler.add(Task::Irrigate, Schedule::Repeat(Alarm1Config::AtTime { hours: 6, minutes: 0, seconds: 0, is_pm: None }), &now)?;
+//! scheduler.add(Task::Upload, Schedule::Once(upload_time), &now)?;
+//! rtc.service_scheduler(&mut scheduler)?;
+//!
+//! // When INT goes low:
+//! for alarm in &rtc.service_scheduler(&mut scheduler)? {
+//!     run(alarm.tag, alarm.missed);
+//! }
+//! ```
+//!
+//! # Missed Alarms
+//!
+//! The scheduler lives in RAM, so after a power loss the application has to add its alarms
+//! again. Adding them after the time of the last service, rather than after the current time,
+//! makes the occurrences that passed in between due at once, and they are reported with
+//! [`DueAlarm::missed`] set. [`AlarmScheduler::last_serviced`] gives that time; store it
+//! somewhere that survives a power loss, e.g. with `seconds_since_2000` in flash or in the
+//! SRAM of a DS3232/DS3234.
+//!
+//! In general an alarm is reported as missed if the hardware alarm was not programmed for it
+//! when it became due, for example because it was added without servicing the scheduler.
+
+use chrono::{Datelike, NaiveDateTime, TimeDelta, Timelike};
+
+use crate::alarm::{Alarm1Config, Alarm2Config, AlarmError, AlarmId};
+use crate::trigger::field;
+
+/// When a logical alarm is due.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Schedule {
+    /// Due once, at the given time
+    Once(NaiveDateTime),
+    /// Due every time the alarm 1 configuration matches (see `Alarm1Config::matches`)
+    Repeat(Alarm1Config),
+}
+
+/// Error type for scheduler operations.
+#[derive(Debug)]
+pub enum SchedulerError {
+    /// All `N` logical alarms are in use
+    Full,
+    /// The schedule has no occurrence after the given time
+    NoOccurrence,
+    /// The repeating alarm configuration is invalid
+    Alarm(AlarmError),
+}
+
+/// A logical alarm that became due, reported by `service_scheduler`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DueAlarm<T> {
+    /// The tag the alarm was added with
+    pub tag: T,
+    /// When the alarm was due. For a repeating alarm that was due several times since the last
+    /// service, this is the first of those occurrences.
+    pub due: NaiveDateTime,
+    /// The hardware alarm was not programmed for this alarm when it became due, e.g. because
+    /// the system was off
+    pub missed: bool,
+}
+
+/// The logical alarms reported by one call to `service_scheduler`.
+#[derive(Debug, Clone)]
+pub struct DueAlarms<T, const N: usize> {
+    alarms: [Option<DueAlarm<T>>; N],
+}
+
+impl<T, const N: usize> DueAlarms<T, N> {
+    pub(crate) fn new() -> Self {
+        Self {
+            alarms: [const { None }; N],
+        }
+    }
+
+    /// Returns the due alarms, in the order of the scheduler's slots.
+    pub fn iter(&self) -> impl Iterator<Item = &DueAlarm<T>> {
+        self.alarms.iter().flatten()
+    }
+
+    /// Gets the number of due alarms.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns `true` if no alarm was due.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.alarms.iter().all(Option::is_none)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a DueAlarms<T, N> {
+    type Item = &'a DueAlarm<T>;
+    type IntoIter = core::iter::Flatten<core::slice::Iter<'a, Option<DueAlarm<T>>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.alarms.iter().flatten()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry<T> {
+    tag: T,
+    schedule: Schedule,
+    /// The next time the alarm is due
+    next: NaiveDateTime,
+}
+
+/// A fixed-capacity set of logical alarms served by one hardware alarm.
+///
+/// `T` is the tag reported when an alarm is due, typically a small enum or an index.
+#[derive(Debug, Clone)]
+pub struct AlarmScheduler<T, const N: usize> {
+    alarm: AlarmId,
+    entries: [Option<Entry<T>>; N],
+    /// The due time the hardware alarm is programmed for
+    armed: Option<NaiveDateTime>,
+    serviced: Option<NaiveDateTime>,
+}
+
+impl<T: Clone, const N: usize> AlarmScheduler<T, N> {
+    /// Creates a scheduler without alarms.
+    ///
+    /// # Arguments
+    /// * `alarm` - The hardware alarm the scheduler programs. With Alarm 2, which has no
+    ///   seconds register, alarms are reported at the start of the minute after they are due
+    ///   unless they are due at second 00.
+    #[must_use]
+    pub const fn new(alarm: AlarmId) -> Self {
+        Self {
+            alarm,
+            entries: [const { None }; N],
+            armed: None,
+            serviced: None,
+        }
+    }
+
+    /// Gets the hardware alarm the scheduler programs.
+    #[must_use]
+    pub fn alarm(&self) -> AlarmId {
+        self.alarm
+    }
+
+    /// Adds a logical alarm.
+    ///
+    /// The alarm is due at the first occurrence of `schedule` after `after`. Service the
+    /// scheduler afterwards so that the hardware alarm is programmed for it.
+    ///
+    /// # Arguments
+    /// * `tag` - Reported when the alarm is due
+    /// * `schedule` - When the alarm is due
+    /// * `after` - Usually the current time; see the [module documentation](self) for
+    ///   reporting alarms missed while the system was off
+    ///
+    /// # Errors
+    /// * Returns `SchedulerError::Full` if all `N` slots are in use
+    /// * Returns `SchedulerError::NoOccurrence` if the schedule has no occurrence after `after`
+    /// * Returns `SchedulerError::Alarm` if a repeating configuration is invalid
+    pub fn add(
+        &mut self,
+        tag: T,
+        schedule: Schedule,
+        after: &NaiveDateTime,
+    ) -> Result<(), SchedulerError> {
+        let slot = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.is_none())
+            .ok_or(SchedulerError::Full)?;
+        let next = match &schedule {
+            Schedule::Once(at) => Some(*at).filter(|at| at > after),
+            Schedule::Repeat(config) => {
+                config.validate().map_err(SchedulerError::Alarm)?;
+                config.next_trigger(after)
+            }
+        }
+        .ok_or(SchedulerError::NoOccurrence)?;
+        *slot = Some(Entry {
+            tag,
+            schedule,
+            next,
+        });
+        Ok(())
+    }
+
+    /// Removes the logical alarms with the given tag.
+    ///
+    /// Returns `true` if any alarm was removed.
+    pub fn remove(&mut self, tag: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut removed = false;
+        for entry in &mut self.entries {
+            if entry.as_ref().is_some_and(|entry| entry.tag == *tag) {
+                *entry = None;
+                removed = true;
+            }
+        }
+        removed
+    }
+
+    /// Removes all logical alarms.
+    pub fn clear(&mut self) {
+        self.entries = [const { None }; N];
+    }
+
+    /// Gets the number of logical alarms.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.iter().flatten().count()
+    }
+
+    /// Returns `true` if there are no logical alarms.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(Option::is_none)
+    }
+
+    /// Gets the earliest time a logical alarm is due, with its tag.
+    #[must_use]
+    pub fn next_due(&self) -> Option<(&T, NaiveDateTime)> {
+        self.entries
+            .iter()
+            .flatten()
+            .min_by_key(|entry| entry.next)
+            .map(|entry| (&entry.tag, entry.next))
+    }
+
+    /// Gets the time of the last service, as read from the device.
+    #[must_use]
+    pub fn last_serviced(&self) -> Option<NaiveDateTime> {
+        self.serviced
+    }
+
+    /// Moves the alarms due at `now` to `due` and advances them to their next occurrence.
+    ///
+    /// An alarm already reported in `due` is advanced without being reported again.
+    pub(crate) fn take_due(&mut self, now: &NaiveDateTime, due: &mut DueAlarms<T, N>) {
+        for (slot, reported) in self.entries.iter_mut().zip(due.alarms.iter_mut()) {
+            let Some(entry) = slot.as_mut().filter(|entry| entry.next <= *now) else {
+                continue;
+            };
+            if reported.is_none() {
+                *reported = Some(DueAlarm {
+                    tag: entry.tag.clone(),
+                    due: entry.next,
+                    missed: self.armed.is_none_or(|armed| entry.next < armed),
+                });
+            }
+            let next = match &entry.schedule {
+                Schedule::Once(_) => None,
+                Schedule::Repeat(config) => config.next_trigger(now),
+            };
+            match next {
+                Some(next) => entry.next = next,
+                None => *slot = None,
+            }
+        }
+        self.serviced = Some(*now);
+    }
+
+    /// Records the earliest due time as armed and returns the time to program into the
+    /// hardware alarm, if there is an alarm.
+    pub(crate) fn arm(&mut self) -> Option<NaiveDateTime> {
+        self.armed = self.next_due().map(|(_, next)| next);
+        let next = self.armed?;
+        match self.alarm {
+            AlarmId::Alarm1 => Some(next),
+            // Alarm 2 matches at second 00, so round up to the next whole minute
+            AlarmId::Alarm2 => {
+                let minute = next.with_second(0)?.with_nanosecond(0)?;
+                if minute < next {
+                    minute.checked_add_signed(TimeDelta::minutes(1))
+                } else {
+                    Some(minute)
+                }
+            }
+        }
+    }
+}
+
+/// Builds the alarm 1 configuration that fires at `at` (within a month).
+pub(crate) fn alarm1_at(at: &NaiveDateTime) -> Alarm1Config {
+    Alarm1Config::on_date(field(at.day()), at.time())
+}
+
+/// Builds the alarm 2 configuration that fires at `at` (within a month, at second 00).
+pub(crate) fn alarm2_at(at: &NaiveDateTime) -> Alarm2Config {
+    Alarm2Config::on_date(field(at.day()), at.time())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(day: u32, hour: u32, minute: u32, second: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 3, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, second)
+            .unwrap()
+    }
+
+    fn daily(hours: u8) -> Schedule {
+        Schedule::Repeat(Alarm1Config::AtTime {
+            hours,
+            minutes: 0,
+            seconds: 0,
+            is_pm: None,
+        })
+    }
+
+    fn tags<const N: usize>(due: &DueAlarms<u8, N>) -> ([u8; N], usize) {
+        let mut tags = [0; N];
+        for (tag, alarm) in tags.iter_mut().zip(due) {
+            *tag = alarm.tag;
+        }
+        (tags, due.len())
+    }
+
+    #[test]
+    fn test_add_and_capacity() {
+        let now = at(1, 12, 0, 0);
+        let mut scheduler: AlarmScheduler<u8, 2> = AlarmScheduler::new(AlarmId::Alarm1);
+        assert!(scheduler.is_empty());
+        scheduler.add(1, daily(6), &now).unwrap();
+        scheduler
+            .add(2, Schedule::Once(at(1, 13, 0, 0)), &now)
+            .unwrap();
+        assert_eq!(scheduler.len(), 2);
+        assert!(matches!(
+            scheduler.add(3, daily(7), &now),
+            Err(SchedulerError::Full)
+        ));
+        assert_eq!(scheduler.next_due(), Some((&2, at(1, 13, 0, 0))));
+
+        assert!(scheduler.remove(&2));
+        assert!(!scheduler.remove(&2));
+        assert_eq!(scheduler.next_due(), Some((&1, at(2, 6, 0, 0))));
+        scheduler.clear();
+        assert_eq!(scheduler.next_due(), None);
+    }
+
+    #[test]
+    fn test_add_errors() {
+        let now = at(1, 12, 0, 0);
+        let mut scheduler: AlarmScheduler<u8, 2> = AlarmScheduler::new(AlarmId::Alarm1);
+        assert!(matches!(
+            scheduler.add(1, Schedule::Once(now), &now),
+            Err(SchedulerError::NoOccurrence)
+        ));
+        assert!(matches!(
+            scheduler.add(1, daily(24), &now),
+            Err(SchedulerError::Alarm(AlarmError::InvalidTime(_)))
+        ));
+        assert!(scheduler.is_empty());
+    }
+
+    #[test]
+    fn test_take_due_and_arm() {
+        let now = at(1, 5, 0, 0);
+        let mut scheduler: AlarmScheduler<u8, 4> = AlarmScheduler::new(AlarmId::Alarm1);
+        scheduler.add(1, daily(6), &now).unwrap();
+        scheduler
+            .add(2, Schedule::Once(at(1, 6, 0, 0)), &now)
+            .unwrap();
+        scheduler.add(3, daily(7), &now).unwrap();
+
+        let mut due = DueAlarms::new();
+        scheduler.take_due(&now, &mut due);
+        assert!(due.is_empty());
+        assert_eq!(scheduler.arm(), Some(at(1, 6, 0, 0)));
+
+        let mut due = DueAlarms::new();
+        scheduler.take_due(&at(1, 6, 0, 0), &mut due);
+        assert_eq!(tags(&due), ([1, 2, 0, 0], 2));
+        assert!(due
+            .iter()
+            .all(|alarm| !alarm.missed && alarm.due == at(1, 6, 0, 0)));
+        assert_eq!(scheduler.len(), 2);
+        assert_eq!(scheduler.arm(), Some(at(1, 7, 0, 0)));
+        assert_eq!(scheduler.last_serviced(), Some(at(1, 6, 0, 0)));
+    }
+
+    #[test]
+    fn test_missed_after_power_loss() {
+        // Last serviced at 05:00, back on at 09:30 with a fresh scheduler
+        let last = at(1, 5, 0, 0);
+        let mut scheduler: AlarmScheduler<u8, 4> = AlarmScheduler::new(AlarmId::Alarm1);
+        scheduler.add(1, daily(6), &last).unwrap();
+        scheduler
+            .add(2, Schedule::Once(at(1, 7, 0, 0)), &last)
+            .unwrap();
+        scheduler.add(3, daily(10), &last).unwrap();
+
+        let mut due = DueAlarms::new();
+        scheduler.take_due(&at(1, 9, 30, 0), &mut due);
+        assert_eq!(tags(&due), ([1, 2, 0, 0], 2));
+        assert!(due.iter().all(|alarm| alarm.missed));
+        assert_eq!(scheduler.arm(), Some(at(1, 10, 0, 0)));
+        assert_eq!(scheduler.next_due(), Some((&3, at(1, 10, 0, 0))));
+    }
+
+    #[test]
+    fn test_repeat_due_several_times() {
+        let now = at(1, 12, 0, 0);
+        let mut scheduler: AlarmScheduler<u8, 1> = AlarmScheduler::new(AlarmId::Alarm1);
+        scheduler.add(1, daily(6), &now).unwrap();
+        scheduler.arm();
+
+        // Three days later: reported once, with the first occurrence
+        let mut due = DueAlarms::new();
+        scheduler.take_due(&at(4, 12, 0, 0), &mut due);
+        let alarm = due.iter().next().unwrap();
+        assert_eq!(alarm.due, at(2, 6, 0, 0));
+        assert!(!alarm.missed);
+        assert_eq!(scheduler.next_due(), Some((&1, at(5, 6, 0, 0))));
+    }
+
+    #[test]
+    fn test_alarm2_rounds_up() {
+        let now = at(1, 12, 0, 0);
+        let mut scheduler: AlarmScheduler<u8, 2> = AlarmScheduler::new(AlarmId::Alarm2);
+        scheduler
+            .add(1, Schedule::Once(at(1, 12, 30, 15)), &now)
+            .unwrap();
+        assert_eq!(scheduler.arm(), Some(at(1, 12, 31, 0)));
+        scheduler
+            .add(2, Schedule::Once(at(1, 12, 20, 0)), &now)
+            .unwrap();
+        assert_eq!(scheduler.arm(), Some(at(1, 12, 20, 0)));
+    }
+
+    #[test]
+    fn test_hardware_configs() {
+        assert_eq!(
+            alarm1_at(&at(31, 18, 5, 9)),
+            Alarm1Config::AtTimeOnDate {
+                hours: 18,
+                minutes: 5,
+                seconds: 9,
+                date: 31,
+                is_pm: None
+            }
+        );
+        assert_eq!(
+            alarm2_at(&at(2, 0, 45, 0)),
+            Alarm2Config::AtTimeOnDate {
+                hours: 0,
+                minutes: 45,
+                date: 2,
+                is_pm: None
+            }
+        );
+    }
+}
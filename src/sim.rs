@@ -0,0 +1,1262 @@
+//! Software model of the DS3231 for host-side testing.
+//!
+//! This module provides [`DS3231Sim`], a register-accurate simulation of the DS3231 that
+//! implements the `embedded-hal` I²C traits. Unlike the transaction scripts used with
+//! `embedded-hal-mock`, the simulator models the behaviour of the chip, so the real
+//! [`DS3231`](crate::DS3231) driver (and application code built on it) can be exercised
+//! against a clock that actually ticks.
+//!
+//! # Modelled Behaviour
+//!
+//! - Register file 0x00-0x12 with an auto-incrementing register pointer that wraps to 0x00
+//! - Optionally the DS3232 register map, adding the temperature control register, the
+//!   `BB32kHz`/`CRATE` status bits and 236 bytes of SRAM (0x14-0xFF)
+//! - Time keeping with BCD rollover in both 12-hour and 24-hour modes, month lengths,
+//!   leap years (every fourth year, as on the real chip) and the century bit
+//! - Alarm 1 and Alarm 2 matching according to the mask bits, setting A1F/A2F
+//! - Oscillator stop flag (OSF) on power loss or when the oscillator is disabled on battery
+//! - Temperature conversions every 64 seconds, and user-forced conversions (CONV/BSY)
+//! - Write semantics of the status register (flags can only be cleared) and the
+//!   read-only temperature registers
+//!
+//! Time only advances when [`DS3231Sim::tick`] or [`DS3231Sim::advance`] is called, which
+//! makes tests fully deterministic.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use ds3231::{sim::DS3231Sim, DS3231};
+//!
+//! let mut sim = DS3231Sim::new();
+//! sim.advance(59);
+//!
+//! let mut rtc = DS3231::new(&mut sim, 0x68);
+//! let datetime = rtc.datetime()?;
+//! ```
+
+use embedded_hal::i2c::{ErrorKind, ErrorType, NoAcknowledgeSource, Operation};
+
+use crate::registers::RegAddr;
+use crate::{AlarmDayDate, Control, DayDateSelect, Oscillator, Status};
+
+/// Default I2C address of the DS3231.
+pub const DEFAULT_ADDRESS: u8 = 0x68;
+
+/// Number of registers in the DS3231 register map (0x00-0x12).
+pub const REGISTER_COUNT: usize = 0x13;
+
+/// Number of registers in the DS3232 register map (0x00-0xFF, including SRAM).
+pub const DS3232_REGISTER_COUNT: usize = 0x100;
+
+/// Interval between automatic temperature conversions, in seconds.
+const TEMPERATURE_CONVERSION_INTERVAL: u32 = 64;
+
+/// Bits of each register that can be written by the bus master.
+///
+/// Bits that are not writable always read back as 0 (or keep their internal value for the
+/// status and temperature registers, which are handled separately).
+const WRITE_MASK: [u8; REGISTER_COUNT] = [
+    0x7F, // Seconds
+    0x7F, // Minutes
+    0x7F, // Hours
+    0x07, // Day
+    0x3F, // Date
+    0x9F, // Month/Century
+    0xFF, // Year
+    0xFF, // Alarm 1 Seconds
+    0xFF, // Alarm 1 Minutes
+    0xFF, // Alarm 1 Hours
+    0xFF, // Alarm 1 Day/Date
+    0xFF, // Alarm 2 Minutes
+    0xFF, // Alarm 2 Hours
+    0xFF, // Alarm 2 Day/Date
+    0xFF, // Control
+    0x08, // Control/Status (only EN32kHz is freely writable)
+    0xFF, // Aging Offset
+    0x00, // MSB of Temp
+    0x00, // LSB of Temp
+];
+
+/// Error type for the simulated I2C bus.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SimError {
+    /// The transaction was addressed to a different device
+    NoAcknowledge,
+    /// The register pointer was set beyond the end of the register map
+    InvalidRegister(u8),
+}
+
+impl embedded_hal::i2c::Error for SimError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            SimError::NoAcknowledge => ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address),
+            SimError::InvalidRegister(_) => ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data),
+        }
+    }
+}
+
+/// Simulated DS3231 device.
+///
+/// The simulator powers up with the power-on reset values from the datasheet:
+/// 00:00:00 on 2000-01-01 (day register 1) in 24-hour mode, with the oscillator stop flag
+/// set, the 32kHz output enabled and the INT/SQW pin configured for alarm interrupts.
+#[derive(Clone, Debug)]
+pub struct DS3231Sim {
+    address: u8,
+    registers: [u8; DS3232_REGISTER_COUNT],
+    register_count: usize,
+    pointer: u8,
+    temperature: i16,
+    seconds_since_conversion: u32,
+    conversion_transactions: u8,
+    conversion_pending: u8,
+    on_battery: bool,
+}
+
+impl Default for DS3231Sim {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DS3231Sim {
+    /// Creates a new simulated device at the default address (0x68).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_address(DEFAULT_ADDRESS)
+    }
+
+    /// Creates a new simulated device responding to the given I2C address.
+    ///
+    /// # Arguments
+    /// * `address` - The 7-bit I2C address the device responds to
+    #[must_use]
+    pub fn with_address(address: u8) -> Self {
+        Self::with_register_count(address, REGISTER_COUNT)
+    }
+
+    /// Creates a new simulated DS3232 at the default address (0x68).
+    ///
+    /// The DS3232 model adds the temperature control register (0x13), the `BB32kHz` and
+    /// `CRATE1`/`CRATE0` status bits, which select the automatic conversion interval, and
+    /// 236 bytes of SRAM at 0x14-0xFF.
+    #[must_use]
+    pub fn new_ds3232() -> Self {
+        Self::with_register_count(DEFAULT_ADDRESS, DS3232_REGISTER_COUNT)
+    }
+
+    fn with_register_count(address: u8, register_count: usize) -> Self {
+        let mut sim = Self {
+            address,
+            registers: [0; DS3232_REGISTER_COUNT],
+            register_count,
+            pointer: 0,
+            temperature: 25 * 4,
+            seconds_since_conversion: 0,
+            conversion_transactions: 2,
+            conversion_pending: 0,
+            on_battery: false,
+        };
+        sim.registers[RegAddr::Day as usize] = 0x01;
+        sim.registers[RegAddr::Date as usize] = 0x01;
+        sim.registers[RegAddr::Month as usize] = 0x01;
+        sim.registers[RegAddr::Control as usize] = 0x1C;
+        sim.registers[RegAddr::ControlStatus as usize] = 0x88;
+        sim.update_temperature_registers();
+        sim
+    }
+
+    /// Returns the I2C address the device responds to.
+    #[must_use]
+    pub fn address(&self) -> u8 {
+        self.address
+    }
+
+    /// Returns the current contents of the register file.
+    #[must_use]
+    pub fn registers(&self) -> &[u8] {
+        &self.registers[..self.register_count]
+    }
+
+    fn is_ds3232(&self) -> bool {
+        self.register_count == DS3232_REGISTER_COUNT
+    }
+
+    fn conversion_interval(&self) -> u32 {
+        if self.is_ds3232() {
+            let status = Status(self.registers[RegAddr::ControlStatus as usize]);
+            TEMPERATURE_CONVERSION_INTERVAL << u8::from(status.conversion_rate())
+        } else {
+            TEMPERATURE_CONVERSION_INTERVAL
+        }
+    }
+
+    fn write_mask(&self, addr: usize) -> u8 {
+        match addr {
+            // BB32kHz and CRATE1/CRATE0 are writable on the DS3232
+            a if a == RegAddr::ControlStatus as usize && self.is_ds3232() => 0x78,
+            a if a < REGISTER_COUNT => WRITE_MASK[a],
+            // Temperature control register, only BB_TD is implemented
+            a if a == RegAddr::TemperatureControl as usize => 0x01,
+            _ => 0xFF,
+        }
+    }
+
+    /// Returns the current value of a single register.
+    ///
+    /// # Panics
+    /// Panics if `addr` is outside the register map.
+    #[must_use]
+    pub fn register(&self, addr: u8) -> u8 {
+        self.registers()[addr as usize]
+    }
+
+    /// Sets a register directly, bypassing the write semantics of the I2C interface.
+    ///
+    /// This is intended for putting the device into states that cannot be reached through
+    /// the bus, such as setting the alarm flags.
+    ///
+    /// # Panics
+    /// Panics if `addr` is outside the register map.
+    pub fn set_register(&mut self, addr: u8, value: u8) {
+        self.registers[..self.register_count][addr as usize] = value;
+    }
+
+    /// Sets the temperature that the simulated sensor measures.
+    ///
+    /// The value is copied into the temperature registers on the next conversion, either the
+    /// automatic one every 64 seconds or one forced via the CONV bit.
+    ///
+    /// # Arguments
+    /// * `quarter_degrees` - The temperature in units of 0.25°C
+    pub fn set_temperature(&mut self, quarter_degrees: i16) {
+        self.temperature = quarter_degrees;
+    }
+
+    /// Sets the number of bus transactions a forced temperature conversion takes.
+    ///
+    /// While a conversion is in progress both BSY and CONV read as 1. The default is 2, so
+    /// a single status poll after setting CONV observes the busy state.
+    pub fn set_conversion_transactions(&mut self, transactions: u8) {
+        self.conversion_transactions = transactions.max(1);
+    }
+
+    /// Simulates switching between main power and battery backup.
+    ///
+    /// When running from the battery with EOSC set in the control register the oscillator
+    /// stops: time no longer advances and OSF is set.
+    pub fn set_battery_powered(&mut self, on_battery: bool) {
+        self.on_battery = on_battery;
+        if self.oscillator_stopped() {
+            self.set_oscillator_stop_flag();
+        }
+    }
+
+    /// Simulates a complete loss of power (both VCC and VBAT).
+    ///
+    /// The oscillator stop flag is set; the register contents are otherwise preserved so that
+    /// tests can verify that the driver detects the invalid time.
+    pub fn power_loss(&mut self) {
+        self.set_oscillator_stop_flag();
+    }
+
+    /// Returns whether the active-low INT/SQW pin is currently asserted.
+    ///
+    /// The pin is only asserted in interrupt mode (INTCN = 1) when an alarm flag is set and
+    /// the corresponding alarm interrupt is enabled.
+    #[must_use]
+    pub fn interrupt_asserted(&self) -> bool {
+        let control = Control(self.registers[RegAddr::Control as usize]);
+        let status = Status(self.registers[RegAddr::ControlStatus as usize]);
+        control.interrupt_control() == crate::InterruptControl::Interrupt
+            && ((control.alarm1_interrupt_enable() && status.alarm1_flag())
+                || (control.alarm2_interrupt_enable() && status.alarm2_flag()))
+    }
+
+    /// Advances the clock by one second.
+    pub fn tick(&mut self) {
+        self.advance(1);
+    }
+
+    /// Advances the clock by the given number of seconds.
+    ///
+    /// Each second is simulated individually so that every alarm match and every automatic
+    /// temperature conversion along the way is observed.
+    pub fn advance(&mut self, seconds: u32) {
+        if self.oscillator_stopped() {
+            return;
+        }
+        for _ in 0..seconds {
+            self.increment_time();
+            self.check_alarms();
+            self.seconds_since_conversion += 1;
+            if self.seconds_since_conversion >= self.conversion_interval() {
+                self.seconds_since_conversion = 0;
+                self.update_temperature_registers();
+            }
+        }
+    }
+
+    fn oscillator_stopped(&self) -> bool {
+        let control = Control(self.registers[RegAddr::Control as usize]);
+        self.on_battery && control.oscillator_enable() == Oscillator::Disabled
+    }
+
+    fn set_oscillator_stop_flag(&mut self) {
+        let mut status = Status(self.registers[RegAddr::ControlStatus as usize]);
+        status.set_oscillator_stop_flag(true);
+        self.registers[RegAddr::ControlStatus as usize] = status.0;
+    }
+
+    fn update_temperature_registers(&mut self) {
+        // Two's complement, integer part in the MSB and the quarters in bits 7:6 of the LSB.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        {
+            self.registers[RegAddr::MSBTemp as usize] = (self.temperature >> 2) as u8;
+            self.registers[RegAddr::LSBTemp as usize] = ((self.temperature & 0x03) as u8) << 6;
+        }
+    }
+
+    fn hours_24(reg: u8) -> u8 {
+        if reg & 0x40 == 0 {
+            bcd_to_bin(reg & 0x3F)
+        } else {
+            let hour = bcd_to_bin(reg & 0x1F) % 12;
+            if reg & 0x20 == 0 {
+                hour
+            } else {
+                hour + 12
+            }
+        }
+    }
+
+    fn encode_hours(hour: u8, twelve_hour: bool) -> u8 {
+        if twelve_hour {
+            let pm = if hour >= 12 { 0x20 } else { 0x00 };
+            let hour12 = match hour % 12 {
+                0 => 12,
+                h => h,
+            };
+            0x40 | pm | bin_to_bcd(hour12)
+        } else {
+            bin_to_bcd(hour)
+        }
+    }
+
+    fn increment_time(&mut self) {
+        let regs = &mut self.registers;
+
+        let seconds = bcd_to_bin(regs[RegAddr::Seconds as usize] & 0x7F) + 1;
+        if seconds < 60 {
+            regs[RegAddr::Seconds as usize] = bin_to_bcd(seconds);
+            return;
+        }
+        regs[RegAddr::Seconds as usize] = 0;
+
+        let minutes = bcd_to_bin(regs[RegAddr::Minutes as usize] & 0x7F) + 1;
+        if minutes < 60 {
+            regs[RegAddr::Minutes as usize] = bin_to_bcd(minutes);
+            return;
+        }
+        regs[RegAddr::Minutes as usize] = 0;
+
+        let hours_reg = regs[RegAddr::Hours as usize];
+        let twelve_hour = hours_reg & 0x40 != 0;
+        let hours = Self::hours_24(hours_reg) + 1;
+        if hours < 24 {
+            regs[RegAddr::Hours as usize] = Self::encode_hours(hours, twelve_hour);
+            return;
+        }
+        regs[RegAddr::Hours as usize] = Self::encode_hours(0, twelve_hour);
+
+        let day = regs[RegAddr::Day as usize] & 0x07;
+        regs[RegAddr::Day as usize] = if day >= 7 { 1 } else { day + 1 };
+
+        let month_reg = regs[RegAddr::Month as usize];
+        let century = month_reg & 0x80;
+        let month = bcd_to_bin(month_reg & 0x1F);
+        let year = bcd_to_bin(regs[RegAddr::Year as usize]);
+
+        let date = bcd_to_bin(regs[RegAddr::Date as usize] & 0x3F) + 1;
+        if date <= days_in_month(month, year) {
+            regs[RegAddr::Date as usize] = bin_to_bcd(date);
+            return;
+        }
+        regs[RegAddr::Date as usize] = 1;
+
+        if month < 12 {
+            regs[RegAddr::Month as usize] = century | bin_to_bcd(month + 1);
+            return;
+        }
+
+        if year < 99 {
+            regs[RegAddr::Month as usize] = century | 1;
+            regs[RegAddr::Year as usize] = bin_to_bcd(year + 1);
+        } else {
+            // The century bit toggles when the year register rolls over from 99 to 00.
+            regs[RegAddr::Month as usize] = (century ^ 0x80) | 1;
+            regs[RegAddr::Year as usize] = 0;
+        }
+    }
+
+    fn day_date_matches(&self, reg: u8) -> bool {
+        let day_date = AlarmDayDate(reg);
+        if day_date.day_date_select() == DayDateSelect::Day {
+            day_date.day_or_date() == self.registers[RegAddr::Day as usize] & 0x07
+        } else {
+            bcd_to_bin(reg & 0x3F) == bcd_to_bin(self.registers[RegAddr::Date as usize] & 0x3F)
+        }
+    }
+
+    fn hours_match(&self, reg: u8) -> bool {
+        // The device compares the register bits, 12/24 and AM/PM bits included, so an alarm
+        // written in the other time representation never matches.
+        reg & 0x7F == self.registers[RegAddr::Hours as usize] & 0x7F
+    }
+
+    fn field_matches(&self, alarm: u8, time: RegAddr) -> bool {
+        bcd_to_bin(alarm & 0x7F) == bcd_to_bin(self.registers[time as usize] & 0x7F)
+    }
+
+    fn check_alarms(&mut self) {
+        let regs = self.registers;
+        let masked = |addr: RegAddr| regs[addr as usize] & 0x80 != 0;
+
+        let alarm1 = (masked(RegAddr::Alarm1Seconds)
+            || self.field_matches(regs[RegAddr::Alarm1Seconds as usize], RegAddr::Seconds))
+            && (masked(RegAddr::Alarm1Minutes)
+                || self.field_matches(regs[RegAddr::Alarm1Minutes as usize], RegAddr::Minutes))
+            && (masked(RegAddr::Alarm1Hours)
+                || self.hours_match(regs[RegAddr::Alarm1Hours as usize]))
+            && (masked(RegAddr::Alarm1DayDate)
+                || self.day_date_matches(regs[RegAddr::Alarm1DayDate as usize]));
+
+        // Alarm 2 has no seconds register and only matches at 00 seconds.
+        let alarm2 = bcd_to_bin(regs[RegAddr::Seconds as usize] & 0x7F) == 0
+            && (masked(RegAddr::Alarm2Minutes)
+                || self.field_matches(regs[RegAddr::Alarm2Minutes as usize], RegAddr::Minutes))
+            && (masked(RegAddr::Alarm2Hours)
+                || self.hours_match(regs[RegAddr::Alarm2Hours as usize]))
+            && (masked(RegAddr::Alarm2DayDate)
+                || self.day_date_matches(regs[RegAddr::Alarm2DayDate as usize]));
+
+        let mut status = Status(self.registers[RegAddr::ControlStatus as usize]);
+        if alarm1 {
+            status.set_alarm1_flag(true);
+        }
+        if alarm2 {
+            status.set_alarm2_flag(true);
+        }
+        self.registers[RegAddr::ControlStatus as usize] = status.0;
+    }
+
+    fn set_pointer(&mut self, addr: u8) -> Result<(), SimError> {
+        if addr as usize >= self.register_count {
+            return Err(SimError::InvalidRegister(addr));
+        }
+        self.pointer = addr;
+        Ok(())
+    }
+
+    fn advance_pointer(&mut self) {
+        self.pointer = if self.pointer as usize + 1 >= self.register_count {
+            0
+        } else {
+            self.pointer + 1
+        };
+    }
+
+    fn read_byte(&mut self) -> u8 {
+        let value = self.registers[self.pointer as usize];
+        self.advance_pointer();
+        value
+    }
+
+    fn write_byte(&mut self, value: u8) {
+        let addr = self.pointer as usize;
+        let current = self.registers[addr];
+        let mask = self.write_mask(addr);
+
+        if addr == RegAddr::ControlStatus as usize {
+            // OSF, A2F and A1F can only be cleared; writing a 1 leaves them unchanged.
+            let clearable = 0x83;
+            let cleared = current & !(clearable & !value);
+            self.registers[addr] = (cleared & !mask) | (value & mask);
+        } else {
+            self.registers[addr] = (current & !mask) | (value & mask);
+        }
+
+        if addr == RegAddr::Control as usize {
+            self.start_conversion_if_requested();
+        }
+        self.advance_pointer();
+    }
+
+    fn start_conversion_if_requested(&mut self) {
+        let control = Control(self.registers[RegAddr::Control as usize]);
+        if !control.convert_temperature() {
+            return;
+        }
+        if self.conversion_pending > 0 {
+            // A conversion is already in progress; CONV stays set until it completes.
+            return;
+        }
+        self.conversion_pending = self.conversion_transactions;
+        let mut status = Status(self.registers[RegAddr::ControlStatus as usize]);
+        status.set_busy(true);
+        self.registers[RegAddr::ControlStatus as usize] = status.0;
+    }
+
+    fn progress_conversion(&mut self) {
+        if self.conversion_pending == 0 {
+            return;
+        }
+        self.conversion_pending -= 1;
+        if self.conversion_pending == 0 {
+            self.update_temperature_registers();
+            let mut control = Control(self.registers[RegAddr::Control as usize]);
+            control.set_convert_temperature(false);
+            self.registers[RegAddr::Control as usize] = control.0;
+            let mut status = Status(self.registers[RegAddr::ControlStatus as usize]);
+            status.set_busy(false);
+            self.registers[RegAddr::ControlStatus as usize] = status.0;
+        }
+    }
+
+    fn process(&mut self, address: u8, operations: &mut [Operation<'_>]) -> Result<(), SimError> {
+        if address != self.address {
+            return Err(SimError::NoAcknowledge);
+        }
+        self.progress_conversion();
+
+        // The first byte written after a START (or repeated START) is the register pointer.
+        // Adjacent write operations are merged on the bus and continue the same write.
+        let mut expect_pointer = true;
+        for operation in operations.iter_mut() {
+            match operation {
+                Operation::Write(bytes) => {
+                    for &byte in &**bytes {
+                        if expect_pointer {
+                            self.set_pointer(byte)?;
+                            expect_pointer = false;
+                        } else {
+                            self.write_byte(byte);
+                        }
+                    }
+                }
+                Operation::Read(buffer) => {
+                    for byte in buffer.iter_mut() {
+                        *byte = self.read_byte();
+                    }
+                    expect_pointer = true;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn bcd_to_bin(value: u8) -> u8 {
+    (value >> 4) * 10 + (value & 0x0F)
+}
+
+fn bin_to_bcd(value: u8) -> u8 {
+    ((value / 10) << 4) | (value % 10)
+}
+
+fn days_in_month(month: u8, year: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl ErrorType for DS3231Sim {
+    type Error = SimError;
+}
+
+impl embedded_hal::i2c::I2c for DS3231Sim {
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.process(address, operations)
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::i2c::I2c for DS3231Sim {
+    async fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.process(address, operations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_hal::i2c::I2c as _;
+
+    fn read_registers(sim: &mut DS3231Sim, addr: RegAddr, data: &mut [u8]) {
+        embedded_hal::i2c::I2c::write_read(sim, DEFAULT_ADDRESS, &[addr as u8], data).unwrap();
+    }
+
+    #[test]
+    fn test_power_on_state() {
+        let sim = DS3231Sim::new();
+        assert_eq!(sim.address(), DEFAULT_ADDRESS);
+        assert_eq!(
+            &sim.registers()[..7],
+            &[0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x00]
+        );
+        assert_eq!(sim.register(RegAddr::Control as u8), 0x1C);
+        assert_eq!(sim.register(RegAddr::ControlStatus as u8), 0x88);
+        assert_eq!(sim.register(RegAddr::MSBTemp as u8), 25);
+        assert_eq!(sim.register(RegAddr::LSBTemp as u8), 0x00);
+    }
+
+    #[test]
+    fn test_wrong_address_is_not_acknowledged() {
+        let mut sim = DS3231Sim::new();
+        let mut data = [0];
+        let err = sim.write_read(0x57, &[0x00], &mut data).unwrap_err();
+        assert_eq!(err, SimError::NoAcknowledge);
+        assert_eq!(
+            embedded_hal::i2c::Error::kind(&err),
+            ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)
+        );
+    }
+
+    #[test]
+    fn test_invalid_register_pointer() {
+        let mut sim = DS3231Sim::new();
+        let err = sim.write(DEFAULT_ADDRESS, &[0x13, 0x00]).unwrap_err();
+        assert_eq!(err, SimError::InvalidRegister(0x13));
+        assert_eq!(
+            embedded_hal::i2c::Error::kind(&err),
+            ErrorKind::NoAcknowledge(NoAcknowledgeSource::Data)
+        );
+    }
+
+    #[test]
+    fn test_register_pointer_auto_increment_and_wrap() {
+        let mut sim = DS3231Sim::new();
+        sim.write(DEFAULT_ADDRESS, &[0x10, 0x05]).unwrap();
+
+        // Reading from 0x10 crosses the end of the register map and wraps to 0x00
+        let mut data = [0; 4];
+        sim.write_read(DEFAULT_ADDRESS, &[0x10], &mut data).unwrap();
+        assert_eq!(data, [0x05, 25, 0x00, 0x00]);
+
+        // A read without a pointer write continues where the last one stopped
+        let mut data = [0; 2];
+        sim.read(DEFAULT_ADDRESS, &mut data).unwrap();
+        assert_eq!(data, [0x00, 0x00]);
+        let mut data = [0];
+        sim.read(DEFAULT_ADDRESS, &mut data).unwrap();
+        assert_eq!(data, [0x01]);
+    }
+
+    #[test]
+    fn test_unused_bits_read_as_zero() {
+        let mut sim = DS3231Sim::new();
+        sim.write(DEFAULT_ADDRESS, &[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])
+            .unwrap();
+        assert_eq!(&sim.registers()[..6], &[0x7F, 0x7F, 0x7F, 0x07, 0x3F, 0x9F]);
+    }
+
+    #[test]
+    fn test_status_flags_can_only_be_cleared() {
+        let mut sim = DS3231Sim::new();
+        sim.set_register(RegAddr::ControlStatus as u8, 0x8B);
+
+        // Writing ones leaves the flags alone, BSY is read-only
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0xFF])
+            .unwrap();
+        assert_eq!(sim.register(RegAddr::ControlStatus as u8), 0x8B);
+
+        // Clear A1F only, and disable the 32kHz output
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x82])
+            .unwrap();
+        assert_eq!(sim.register(RegAddr::ControlStatus as u8), 0x82);
+
+        // Clear everything
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x00])
+            .unwrap();
+        assert_eq!(sim.register(RegAddr::ControlStatus as u8), 0x00);
+    }
+
+    #[test]
+    fn test_temperature_registers_are_read_only() {
+        let mut sim = DS3231Sim::new();
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::MSBTemp as u8, 0x12, 0xC0])
+            .unwrap();
+        assert_eq!(sim.register(RegAddr::MSBTemp as u8), 25);
+        assert_eq!(sim.register(RegAddr::LSBTemp as u8), 0x00);
+    }
+
+    #[test]
+    fn test_tick_rollover_24_hour() {
+        let mut sim = DS3231Sim::new();
+        // 23:59:59 on Saturday 2024-08-31
+        sim.write(
+            DEFAULT_ADDRESS,
+            &[0x00, 0x59, 0x59, 0x23, 0x07, 0x31, 0x08, 0x24],
+        )
+        .unwrap();
+        sim.tick();
+        assert_eq!(
+            &sim.registers()[..7],
+            &[0x00, 0x00, 0x00, 0x01, 0x01, 0x09, 0x24]
+        );
+    }
+
+    #[test]
+    fn test_tick_rollover_12_hour() {
+        let mut sim = DS3231Sim::new();
+        // 11:59:59 AM
+        sim.write(DEFAULT_ADDRESS, &[0x00, 0x59, 0x59, 0x51])
+            .unwrap();
+        sim.tick();
+        // 12:00:00 PM
+        assert_eq!(sim.register(RegAddr::Hours as u8), 0x72);
+
+        // 12:59:59 PM -> 01:00:00 PM
+        sim.write(DEFAULT_ADDRESS, &[0x00, 0x59, 0x59]).unwrap();
+        sim.tick();
+        assert_eq!(sim.register(RegAddr::Hours as u8), 0x61);
+
+        // 11:59:59 PM -> 12:00:00 AM on the next day
+        sim.write(DEFAULT_ADDRESS, &[0x00, 0x59, 0x59, 0x71])
+            .unwrap();
+        sim.tick();
+        assert_eq!(sim.register(RegAddr::Hours as u8), 0x52);
+        assert_eq!(sim.register(RegAddr::Day as u8), 0x02);
+        assert_eq!(sim.register(RegAddr::Date as u8), 0x02);
+    }
+
+    #[test]
+    fn test_leap_year_rollover() {
+        let mut sim = DS3231Sim::new();
+        // 2024-02-28 23:59:59
+        sim.write(
+            DEFAULT_ADDRESS,
+            &[0x00, 0x59, 0x59, 0x23, 0x04, 0x28, 0x02, 0x24],
+        )
+        .unwrap();
+        sim.tick();
+        assert_eq!(&sim.registers()[4..7], &[0x29, 0x02, 0x24]);
+        sim.advance(24 * 60 * 60);
+        assert_eq!(&sim.registers()[4..7], &[0x01, 0x03, 0x24]);
+
+        // 2023-02-28 23:59:59 is followed by March 1st
+        sim.write(
+            DEFAULT_ADDRESS,
+            &[0x00, 0x59, 0x59, 0x23, 0x03, 0x28, 0x02, 0x23],
+        )
+        .unwrap();
+        sim.tick();
+        assert_eq!(&sim.registers()[4..7], &[0x01, 0x03, 0x23]);
+    }
+
+    #[test]
+    fn test_century_rollover() {
+        let mut sim = DS3231Sim::new();
+        // 2099-12-31 23:59:59
+        sim.write(
+            DEFAULT_ADDRESS,
+            &[0x00, 0x59, 0x59, 0x23, 0x05, 0x31, 0x12, 0x99],
+        )
+        .unwrap();
+        sim.tick();
+        assert_eq!(
+            &sim.registers()[..7],
+            &[0x00, 0x00, 0x00, 0x06, 0x01, 0x81, 0x00]
+        );
+
+        // The century bit is preserved across month rollovers
+        sim.advance(31 * 24 * 60 * 60);
+        assert_eq!(&sim.registers()[4..7], &[0x01, 0x82, 0x00]);
+    }
+
+    #[test]
+    fn test_alarm1_sets_flag_on_match() {
+        let mut sim = DS3231Sim::new();
+        // Alarm 1 at 00:00:05 on any day
+        sim.write(
+            DEFAULT_ADDRESS,
+            &[RegAddr::Alarm1Seconds as u8, 0x05, 0x00, 0x00, 0x80],
+        )
+        .unwrap();
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x00])
+            .unwrap();
+
+        sim.advance(4);
+        assert!(!Status(sim.register(RegAddr::ControlStatus as u8)).alarm1_flag());
+        sim.tick();
+        assert!(Status(sim.register(RegAddr::ControlStatus as u8)).alarm1_flag());
+        assert!(!Status(sim.register(RegAddr::ControlStatus as u8)).alarm2_flag());
+    }
+
+    #[test]
+    fn test_alarm1_day_and_date_matching() {
+        let mut sim = DS3231Sim::new();
+        // Alarm 1 at 00:00:00 on day 3
+        sim.write(
+            DEFAULT_ADDRESS,
+            &[RegAddr::Alarm1Seconds as u8, 0x00, 0x00, 0x00, 0x43],
+        )
+        .unwrap();
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x00])
+            .unwrap();
+        sim.advance(24 * 60 * 60);
+        assert!(!Status(sim.register(RegAddr::ControlStatus as u8)).alarm1_flag());
+        sim.advance(24 * 60 * 60);
+        assert!(Status(sim.register(RegAddr::ControlStatus as u8)).alarm1_flag());
+
+        // Alarm 1 at 00:00:00 on the 12th
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::Alarm1DayDate as u8, 0x12])
+            .unwrap();
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x00])
+            .unwrap();
+        sim.advance(8 * 24 * 60 * 60);
+        assert!(!Status(sim.register(RegAddr::ControlStatus as u8)).alarm1_flag());
+        sim.advance(24 * 60 * 60);
+        assert!(Status(sim.register(RegAddr::ControlStatus as u8)).alarm1_flag());
+    }
+
+    #[test]
+    fn test_alarm2_matches_at_zero_seconds() {
+        let mut sim = DS3231Sim::new();
+        // Alarm 2 every minute
+        sim.write(
+            DEFAULT_ADDRESS,
+            &[RegAddr::Alarm2Minutes as u8, 0x80, 0x80, 0x80],
+        )
+        .unwrap();
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x00])
+            .unwrap();
+        sim.advance(59);
+        assert!(!Status(sim.register(RegAddr::ControlStatus as u8)).alarm2_flag());
+        sim.tick();
+        assert!(Status(sim.register(RegAddr::ControlStatus as u8)).alarm2_flag());
+    }
+
+    #[test]
+    fn test_alarm_hours_in_other_mode() {
+        let mut sim = DS3231Sim::new();
+        // Clock in 24-hour mode at 13:59:59, alarm 2 at 02:00 PM in 12-hour mode
+        sim.write(DEFAULT_ADDRESS, &[0x00, 0x59, 0x59, 0x13])
+            .unwrap();
+        sim.write(
+            DEFAULT_ADDRESS,
+            &[RegAddr::Alarm2Minutes as u8, 0x00, 0x62, 0x80],
+        )
+        .unwrap();
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x00])
+            .unwrap();
+        sim.tick();
+        assert!(!Status(sim.register(RegAddr::ControlStatus as u8)).alarm2_flag());
+        // Clock in 12-hour mode at 01:59:59 PM
+        sim.write(DEFAULT_ADDRESS, &[0x00, 0x59, 0x59, 0x61])
+            .unwrap();
+        sim.tick();
+        assert!(Status(sim.register(RegAddr::ControlStatus as u8)).alarm2_flag());
+    }
+
+    #[test]
+    fn test_interrupt_asserted() {
+        let mut sim = DS3231Sim::new();
+        sim.set_register(RegAddr::ControlStatus as u8, 0x01);
+        // INTCN set but A1IE clear
+        assert!(!sim.interrupt_asserted());
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::Control as u8, 0x05])
+            .unwrap();
+        assert!(sim.interrupt_asserted());
+        // Square wave mode never asserts the interrupt
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::Control as u8, 0x01])
+            .unwrap();
+        assert!(!sim.interrupt_asserted());
+    }
+
+    #[test]
+    fn test_oscillator_stop() {
+        let mut sim = DS3231Sim::new();
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x00])
+            .unwrap();
+
+        // EOSC only stops the oscillator on battery power
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::Control as u8, 0x9C])
+            .unwrap();
+        sim.tick();
+        assert_eq!(sim.register(RegAddr::Seconds as u8), 0x01);
+        assert!(!Status(sim.register(RegAddr::ControlStatus as u8)).oscillator_stop_flag());
+
+        sim.set_battery_powered(true);
+        sim.advance(10);
+        assert_eq!(sim.register(RegAddr::Seconds as u8), 0x01);
+        assert!(Status(sim.register(RegAddr::ControlStatus as u8)).oscillator_stop_flag());
+
+        sim.set_battery_powered(false);
+        sim.tick();
+        assert_eq!(sim.register(RegAddr::Seconds as u8), 0x02);
+    }
+
+    #[test]
+    fn test_power_loss_sets_osf() {
+        let mut sim = DS3231Sim::new();
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x00])
+            .unwrap();
+        sim.power_loss();
+        assert!(Status(sim.register(RegAddr::ControlStatus as u8)).oscillator_stop_flag());
+    }
+
+    #[test]
+    fn test_automatic_temperature_conversion() {
+        let mut sim = DS3231Sim::new();
+        // -10.75°C
+        sim.set_temperature(-43);
+        sim.advance(63);
+        assert_eq!(sim.register(RegAddr::MSBTemp as u8), 25);
+        sim.tick();
+        assert_eq!(sim.register(RegAddr::MSBTemp as u8), 0xF5);
+        assert_eq!(sim.register(RegAddr::LSBTemp as u8), 0x40);
+    }
+
+    #[test]
+    fn test_forced_temperature_conversion() {
+        let mut sim = DS3231Sim::new();
+        sim.set_temperature(30 * 4 + 2);
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::Control as u8, 0x3C])
+            .unwrap();
+
+        let mut data = [0; 2];
+        read_registers(&mut sim, RegAddr::Control, &mut data);
+        assert!(Control(data[0]).convert_temperature());
+        assert!(Status(data[1]).busy());
+
+        read_registers(&mut sim, RegAddr::Control, &mut data);
+        assert!(!Control(data[0]).convert_temperature());
+        assert!(!Status(data[1]).busy());
+
+        let mut data = [0; 2];
+        read_registers(&mut sim, RegAddr::MSBTemp, &mut data);
+        assert_eq!(data, [30, 0x80]);
+    }
+
+    #[test]
+    fn test_ds3232_sram_access() {
+        let mut sim = DS3231Sim::new_ds3232();
+        assert_eq!(sim.registers().len(), DS3232_REGISTER_COUNT);
+
+        sim.write(DEFAULT_ADDRESS, &[0xFE, 0x11, 0x22, 0x33])
+            .unwrap();
+        // The write wrapped from 0xFF to the seconds register
+        assert_eq!(sim.register(0xFE), 0x11);
+        assert_eq!(sim.register(0xFF), 0x22);
+        assert_eq!(sim.register(RegAddr::Seconds as u8), 0x33);
+
+        let mut data = [0; 2];
+        sim.write_read(DEFAULT_ADDRESS, &[0xFE], &mut data).unwrap();
+        assert_eq!(data, [0x11, 0x22]);
+    }
+
+    #[test]
+    fn test_ds3232_registers_not_on_ds3231() {
+        let mut sim = DS3231Sim::new();
+        let err = sim.write(DEFAULT_ADDRESS, &[0x14, 0x00]).unwrap_err();
+        assert_eq!(err, SimError::InvalidRegister(0x14));
+    }
+
+    #[test]
+    fn test_ds3232_status_and_temperature_control_bits() {
+        let mut sim = DS3231Sim::new_ds3232();
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x7F])
+            .unwrap();
+        assert_eq!(sim.register(RegAddr::ControlStatus as u8), 0x78);
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::TemperatureControl as u8, 0xFF])
+            .unwrap();
+        assert_eq!(sim.register(RegAddr::TemperatureControl as u8), 0x01);
+
+        // The DS3231 ignores the DS3232 status bits
+        let mut sim = DS3231Sim::new();
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x7F])
+            .unwrap();
+        assert_eq!(sim.register(RegAddr::ControlStatus as u8), 0x08);
+    }
+
+    #[test]
+    fn test_ds3232_conversion_rate() {
+        let mut sim = DS3231Sim::new_ds3232();
+        // CRATE = 128 seconds
+        sim.write(DEFAULT_ADDRESS, &[RegAddr::ControlStatus as u8, 0x18])
+            .unwrap();
+        sim.set_temperature(-4);
+        sim.advance(64);
+        assert_eq!(sim.register(RegAddr::MSBTemp as u8), 25);
+        sim.advance(64);
+        assert_eq!(sim.register(RegAddr::MSBTemp as u8), 0xFF);
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(DS3231(sync, async = "DS3231Async")),
+    sync(self = "blocking_driver_tests"),
+    async(feature = "async", self = "async_driver_tests")
+)]
+#[cfg(test)]
+mod driver_tests {
+    use super::*;
+    #[cfg(feature = "chrono")]
+    use crate::{
+        Alarm1Config, Alarm2Config, AlarmId, AlarmScheduler, DS3231Error, Schedule,
+        TimeRepresentation,
+    };
+    use crate::{Celsius, TemperatureControl, DS3231};
+    #[cfg(feature = "chrono")]
+    use chrono::{NaiveDate, NaiveDateTime};
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    // Driver tests run as plain tests in the blocking variant and on tokio in the async one
+    #[maybe_async_cfg::only_if(sync)]
+    use core::prelude::v1::test as driver_test;
+    #[maybe_async_cfg::only_if(async)]
+    use tokio::test as driver_test;
+
+    #[cfg(feature = "chrono")]
+    fn datetime(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, mi, s)
+            .unwrap()
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_driver_datetime_rollover() {
+        let mut dev = DS3231::new(DS3231Sim::new(), DEFAULT_ADDRESS);
+        dev.set_datetime(&datetime(2099, 12, 31, 23, 59, 58))
+            .await
+            .unwrap();
+
+        dev.interface.i2c.advance(2);
+        assert_eq!(dev.datetime().await.unwrap(), datetime(2100, 1, 1, 0, 0, 0));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_driver_twelve_hour_mode() {
+        let mut dev = DS3231::new(DS3231Sim::new(), DEFAULT_ADDRESS);
+        let mut hours = dev.hour().await.unwrap();
+        hours.set_time_representation(TimeRepresentation::TwelveHour);
+        dev.set_hour(hours).await.unwrap();
+
+        dev.set_datetime(&datetime(2024, 3, 14, 11, 59, 59))
+            .await
+            .unwrap();
+        dev.interface.i2c.tick();
+        assert_eq!(
+            dev.datetime().await.unwrap(),
+            datetime(2024, 3, 14, 12, 0, 0)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_driver_set_time_representation() {
+        let mut dev = DS3231::new(DS3231Sim::new(), DEFAULT_ADDRESS);
+        dev.set_datetime(&datetime(2024, 3, 14, 15, 0, 0))
+            .await
+            .unwrap();
+        dev.set_alarm1(&Alarm1Config::AtTime {
+            hours: 23,
+            minutes: 30,
+            seconds: 0,
+            is_pm: None,
+        })
+        .await
+        .unwrap();
+
+        dev.set_time_representation(TimeRepresentation::TwelveHour)
+            .await
+            .unwrap();
+        assert_eq!(
+            dev.datetime().await.unwrap(),
+            datetime(2024, 3, 14, 15, 0, 0)
+        );
+        // 11 PM
+        assert_eq!(u8::from(dev.alarm1_hour().await.unwrap()), 0x71);
+        assert_eq!(
+            dev.alarm1().await.unwrap(),
+            Alarm1Config::AtTime {
+                hours: 23,
+                minutes: 30,
+                seconds: 0,
+                is_pm: None,
+            }
+        );
+
+        dev.set_time_representation(TimeRepresentation::TwentyFourHour)
+            .await
+            .unwrap();
+        assert_eq!(
+            dev.datetime().await.unwrap(),
+            datetime(2024, 3, 14, 15, 0, 0)
+        );
+        assert_eq!(
+            dev.alarm1().await.unwrap(),
+            Alarm1Config::AtTime {
+                hours: 23,
+                minutes: 30,
+                seconds: 0,
+                is_pm: None,
+            }
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_driver_alarm_after() {
+        let mut dev = DS3231::new(DS3231Sim::new(), DEFAULT_ADDRESS);
+        dev.set_datetime(&datetime(2024, 2, 29, 23, 59, 0))
+            .await
+            .unwrap();
+        let at = dev
+            .set_alarm1_after(core::time::Duration::from_secs(90))
+            .await
+            .unwrap();
+        assert_eq!(at, datetime(2024, 3, 1, 0, 0, 31));
+        dev.set_status(Status(0)).await.unwrap();
+
+        // Never early: the alarm allows for the unread part of the second
+        dev.interface.i2c.advance(90);
+        assert!(!dev.status().await.unwrap().alarm1_flag());
+        dev.interface.i2c.advance(1);
+        assert!(dev.status().await.unwrap().alarm1_flag());
+        assert_eq!(dev.datetime().await.unwrap(), at);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_driver_scheduler_wakes_early() {
+        let mut dev = DS3231::new(DS3231Sim::new(), DEFAULT_ADDRESS);
+        let now = datetime(2024, 3, 15, 5, 59, 59);
+        dev.set_datetime(&now).await.unwrap();
+        let mut scheduler: AlarmScheduler<u8, 1> = AlarmScheduler::new(AlarmId::Alarm1);
+        let once = datetime(2024, 4, 15, 6, 0, 0);
+        scheduler.add(1, Schedule::Once(once), &now).unwrap();
+        assert!(dev
+            .service_scheduler(&mut scheduler)
+            .await
+            .unwrap()
+            .is_empty());
+
+        // The hardware alarm matches the date of the month, so it also fires a month early
+        dev.interface.i2c.advance(1);
+        assert!(dev.status().await.unwrap().alarm1_flag());
+        assert!(dev
+            .service_scheduler(&mut scheduler)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(!dev.status().await.unwrap().alarm1_flag());
+        assert_eq!(scheduler.next_due(), Some((&1, once)));
+
+        dev.set_datetime(&datetime(2024, 4, 15, 5, 59, 59))
+            .await
+            .unwrap();
+        dev.interface.i2c.advance(1);
+        assert!(dev.status().await.unwrap().alarm1_flag());
+        let due = dev.service_scheduler(&mut scheduler).await.unwrap();
+        let alarm = due.iter().next().unwrap();
+        assert_eq!((alarm.tag, alarm.due, alarm.missed), (1, once, false));
+        assert!(scheduler.is_empty());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_driver_alarms() {
+        let mut dev = DS3231::new(DS3231Sim::new(), DEFAULT_ADDRESS);
+        dev.set_datetime(&datetime(2024, 3, 14, 6, 29, 0))
+            .await
+            .unwrap();
+        dev.set_alarm1(&Alarm1Config::AtTime {
+            hours: 6,
+            minutes: 30,
+            seconds: 15,
+            is_pm: None,
+        })
+        .await
+        .unwrap();
+        dev.set_alarm2(&Alarm2Config::AtMinutes { minutes: 30 })
+            .await
+            .unwrap();
+        dev.set_status(Status(0)).await.unwrap();
+
+        dev.interface.i2c.advance(60);
+        let status = dev.status().await.unwrap();
+        assert!(!status.alarm1_flag());
+        assert!(status.alarm2_flag());
+
+        dev.interface.i2c.advance(15);
+        let mut status = dev.status().await.unwrap();
+        assert!(status.alarm1_flag());
+
+        status.set_alarm1_flag(false);
+        status.set_alarm2_flag(false);
+        dev.set_status(status).await.unwrap();
+        let status = dev.status().await.unwrap();
+        assert!(!status.alarm1_flag());
+        assert!(!status.alarm2_flag());
+
+        assert_eq!(
+            dev.alarm2().await.unwrap(),
+            Alarm2Config::AtMinutes { minutes: 30 }
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[driver_test]
+    async fn test_driver_wrong_address() {
+        let mut dev = DS3231::new(DS3231Sim::new(), 0x57);
+        let err = dev.datetime().await.unwrap_err();
+        assert!(matches!(err, DS3231Error::I2c(SimError::NoAcknowledge)));
+    }
+
+    #[driver_test]
+    async fn test_driver_force_temperature_conversion() {
+        let mut dev = DS3231::new(DS3231Sim::new(), DEFAULT_ADDRESS);
+        dev.interface.i2c.set_temperature(-43);
+        dev.interface.i2c.set_conversion_transactions(3);
+
+        let temp = dev
+            .force_temperature_conversion(&mut NoopDelay::new())
+            .await
+            .unwrap();
+        assert_eq!(temp, Celsius::from_quarter_degrees(-43));
+    }
+
+    #[driver_test]
+    async fn test_driver_ds3232_sram() {
+        let mut dev = DS3231::new_ds3232(DS3231Sim::new_ds3232(), DEFAULT_ADDRESS);
+        dev.write_sram(0, b"hello").await.unwrap();
+        dev.set_sram_byte(235, 0x42).await.unwrap();
+
+        let mut data = [0; 5];
+        dev.read_sram(0, &mut data).await.unwrap();
+        assert_eq!(&data, b"hello");
+        assert_eq!(dev.sram_byte(235).await.unwrap(), 0x42);
+        assert_eq!(dev.interface.i2c.register(0x14 + 235), 0x42);
+
+        let mut control = TemperatureControl::default();
+        control.set_battery_backed_temperature_disable(true);
+        dev.set_temperature_control(control).await.unwrap();
+        assert_eq!(dev.temperature_control().await.unwrap(), control);
+    }
+}
@@ -0,0 +1,380 @@
+//! Consistent multi-register reads for the DS3231 RTC.
+//!
+//! Reading the time with the per-register accessors (`second()`, `minute()`, `hour()`, ...)
+//! can tear across a rollover: reading the minutes at 12:59:59 and the hours at 13:00:00 gives
+//! 13:59. A [`Snapshot`] captures a chosen set of [`RegisterGroups`] so that the values belong
+//! together.
+//!
+//! # Guarantees
+//!
+//! - Groups that are adjacent in the register map are read in a single burst. The device
+//!   copies the timekeeping registers to a read buffer at the start of each bus transaction,
+//!   so a burst never tears across a rollover.
+//! - When the requested groups are not adjacent (for example [`RegisterGroups::TIME`] and
+//!   [`RegisterGroups::STATUS`]), each run of adjacent groups is read in its own burst and the
+//!   whole set is read again. The snapshot is only returned once two consecutive passes
+//!   are identical, i.e. no captured register changed while it was being read. If the
+//!   registers keep changing the read fails with `DS3231Error::SnapshotUnstable`.
+//! - Registers outside the requested groups are not read.
+
+use core::ops::{BitOr, BitOrAssign};
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDateTime;
+#[cfg(feature = "time")]
+use time::PrimitiveDateTime;
+
+use crate::datetime::{DS3231DateTime, DS3231DateTimeError};
+use crate::registers::RegAddr;
+use crate::{
+    AgingOffset, AlarmDayDate, AlarmHours, AlarmMinutes, AlarmSeconds, Control, DS3231Alarm1,
+    DS3231Alarm2, Hours, Status, Temperature, TemperatureFraction,
+};
+
+/// Number of registers shared by the whole DS3231 family (0x00-0x12).
+pub(crate) const SNAPSHOT_REGISTERS: usize = 0x13;
+
+/// Register ranges (first register, number of registers) of each group, in bit order.
+const GROUP_RANGES: [(u8, u8); 7] = [
+    (0x00, 7), // TIME
+    (0x07, 4), // ALARM1
+    (0x0B, 3), // ALARM2
+    (0x0E, 1), // CONTROL
+    (0x0F, 1), // STATUS
+    (0x10, 1), // AGING_OFFSET
+    (0x11, 2), // TEMPERATURE
+];
+
+/// A set of register groups to capture in a [`Snapshot`].
+///
+/// Groups are combined with `|`:
+///
+/// ```rust,ignore
+/// let groups = RegisterGroups::TIME | RegisterGroups::STATUS;
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RegisterGroups(u8);
+
+impl RegisterGroups {
+    /// Seconds, minutes, hours, day, date, month and year (0x00-0x06)
+    pub const TIME: Self = Self(1 << 0);
+    /// Alarm 1 registers (0x07-0x0A)
+    pub const ALARM1: Self = Self(1 << 1);
+    /// Alarm 2 registers (0x0B-0x0D)
+    pub const ALARM2: Self = Self(1 << 2);
+    /// Control register (0x0E)
+    pub const CONTROL: Self = Self(1 << 3);
+    /// Control/status register (0x0F)
+    pub const STATUS: Self = Self(1 << 4);
+    /// Aging offset register (0x10)
+    pub const AGING_OFFSET: Self = Self(1 << 5);
+    /// Temperature registers (0x11-0x12)
+    pub const TEMPERATURE: Self = Self(1 << 6);
+    /// All of the above (0x00-0x12)
+    pub const ALL: Self = Self(0x7F);
+
+    /// Returns an empty set.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns `true` if no group is selected.
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all groups in `other` are selected.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if `register` belongs to one of the selected groups.
+    pub(crate) fn contains_register(self, register: u8) -> bool {
+        GROUP_RANGES.iter().enumerate().any(|(bit, &(start, len))| {
+            self.0 & (1 << bit) != 0 && (start..start + len).contains(&register)
+        })
+    }
+
+    /// Returns the bursts (first register, number of registers) needed to read the groups.
+    pub(crate) fn bursts(self) -> Bursts {
+        Bursts {
+            groups: self,
+            next: 0,
+        }
+    }
+}
+
+impl BitOr for RegisterGroups {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for RegisterGroups {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Iterator over the runs of adjacent registers selected by a [`RegisterGroups`].
+pub(crate) struct Bursts {
+    groups: RegisterGroups,
+    next: u8,
+}
+
+impl Iterator for Bursts {
+    type Item = (u8, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The shared registers end where the DS3232 temperature control register starts
+        let end = RegAddr::TemperatureControl as u8;
+        while self.next < end && !self.groups.contains_register(self.next) {
+            self.next += 1;
+        }
+        if self.next == end {
+            return None;
+        }
+        let start = self.next;
+        while self.next < end && self.groups.contains_register(self.next) {
+            self.next += 1;
+        }
+        Some((start, usize::from(self.next - start)))
+    }
+}
+
+/// A consistent capture of a set of DS3231 registers.
+///
+/// Created by `snapshot`; see the [module documentation](self) for the guarantees it gives.
+/// Accessors return `None` for registers whose group was not captured.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    groups: RegisterGroups,
+    pub(crate) registers: [u8; SNAPSHOT_REGISTERS],
+}
+
+impl Snapshot {
+    pub(crate) fn new(groups: RegisterGroups) -> Self {
+        Self {
+            groups,
+            registers: [0; SNAPSHOT_REGISTERS],
+        }
+    }
+
+    fn group(&self, group: RegisterGroups) -> Option<&[u8; SNAPSHOT_REGISTERS]> {
+        self.groups.contains(group).then_some(&self.registers)
+    }
+
+    /// Gets the captured register groups.
+    #[must_use]
+    pub fn groups(&self) -> RegisterGroups {
+        self.groups
+    }
+
+    /// Gets the captured date and time registers.
+    fn raw_datetime(&self) -> Option<DS3231DateTime> {
+        self.group(RegisterGroups::TIME).map(|r| {
+            let mut data = [0; 7];
+            data.copy_from_slice(&r[..7]);
+            DS3231DateTime::from(data)
+        })
+    }
+
+    /// Gets the captured date and time.
+    ///
+    /// # Returns
+    /// * `None` if [`RegisterGroups::TIME`] was not captured
+    /// * `Some(Err(DS3231DateTimeError))` if the registers hold an invalid date/time
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn datetime(&self) -> Option<Result<NaiveDateTime, DS3231DateTimeError>> {
+        self.raw_datetime().map(DS3231DateTime::into_datetime)
+    }
+
+    /// Gets the captured date and time as a `time` crate `PrimitiveDateTime`.
+    ///
+    /// # Returns
+    /// * `None` if [`RegisterGroups::TIME`] was not captured
+    /// * `Some(Err(DS3231DateTimeError))` if the registers hold an invalid date/time
+    #[cfg(feature = "time")]
+    #[must_use]
+    pub fn primitive_datetime(&self) -> Option<Result<PrimitiveDateTime, DS3231DateTimeError>> {
+        self.raw_datetime()
+            .map(DS3231DateTime::into_primitive_datetime)
+    }
+
+    /// Gets the captured date and time as a Unix timestamp.
+    ///
+    /// # Returns
+    /// * `None` if [`RegisterGroups::TIME`] was not captured
+    /// * `Some(Err(DS3231DateTimeError))` if the registers hold an invalid date/time
+    #[must_use]
+    pub fn timestamp(&self) -> Option<Result<i64, DS3231DateTimeError>> {
+        self.raw_datetime().map(DS3231DateTime::into_timestamp)
+    }
+
+    /// Gets the captured hours register, which holds the 12/24 hour setting.
+    #[must_use]
+    pub fn hours(&self) -> Option<Hours> {
+        self.group(RegisterGroups::TIME).map(|r| Hours(r[0x02]))
+    }
+
+    /// Gets the captured alarm 1 registers.
+    #[must_use]
+    pub fn alarm1(&self) -> Option<DS3231Alarm1> {
+        self.group(RegisterGroups::ALARM1).map(|r| {
+            DS3231Alarm1::from_registers(
+                AlarmSeconds(r[0x07]),
+                AlarmMinutes(r[0x08]),
+                AlarmHours(r[0x09]),
+                AlarmDayDate(r[0x0A]),
+            )
+        })
+    }
+
+    /// Gets the captured alarm 2 registers.
+    #[must_use]
+    pub fn alarm2(&self) -> Option<DS3231Alarm2> {
+        self.group(RegisterGroups::ALARM2).map(|r| {
+            DS3231Alarm2::from_registers(
+                AlarmMinutes(r[0x0B]),
+                AlarmHours(r[0x0C]),
+                AlarmDayDate(r[0x0D]),
+            )
+        })
+    }
+
+    /// Gets the captured control register.
+    #[must_use]
+    pub fn control(&self) -> Option<Control> {
+        self.group(RegisterGroups::CONTROL)
+            .map(|r| Control(r[0x0E]))
+    }
+
+    /// Gets the captured control/status register.
+    #[must_use]
+    pub fn status(&self) -> Option<Status> {
+        self.group(RegisterGroups::STATUS).map(|r| Status(r[0x0F]))
+    }
+
+    /// Gets the captured aging offset register.
+    #[must_use]
+    pub fn aging_offset(&self) -> Option<AgingOffset> {
+        self.group(RegisterGroups::AGING_OFFSET)
+            .map(|r| AgingOffset(r[0x10]))
+    }
+
+    /// Gets the captured temperature registers (integer and fractional part).
+    #[must_use]
+    pub fn temperature(&self) -> Option<(Temperature, TemperatureFraction)> {
+        self.group(RegisterGroups::TEMPERATURE)
+            .map(|r| (Temperature(r[0x11]), TemperatureFraction(r[0x12])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn test_group_operations() {
+        let mut groups = RegisterGroups::TIME | RegisterGroups::STATUS;
+        assert!(groups.contains(RegisterGroups::TIME));
+        assert!(groups.contains(RegisterGroups::STATUS));
+        assert!(!groups.contains(RegisterGroups::CONTROL));
+        groups |= RegisterGroups::CONTROL;
+        assert!(groups.contains(RegisterGroups::TIME | RegisterGroups::CONTROL));
+        assert!(RegisterGroups::empty().is_empty());
+        assert!(!RegisterGroups::ALL.is_empty());
+        assert!(RegisterGroups::ALL.contains(groups));
+    }
+
+    #[test]
+    fn test_bursts() {
+        let bursts = |groups: RegisterGroups| groups.bursts().collect::<Vec<_>>();
+
+        assert_eq!(bursts(RegisterGroups::empty()), []);
+        assert_eq!(bursts(RegisterGroups::ALL), [(0x00, 19)]);
+        assert_eq!(bursts(RegisterGroups::TIME), [(0x00, 7)]);
+        assert_eq!(
+            bursts(RegisterGroups::TIME | RegisterGroups::ALARM1 | RegisterGroups::ALARM2),
+            [(0x00, 14)]
+        );
+        assert_eq!(
+            bursts(RegisterGroups::TIME | RegisterGroups::STATUS),
+            [(0x00, 7), (0x0F, 1)]
+        );
+        assert_eq!(
+            bursts(RegisterGroups::ALARM1 | RegisterGroups::CONTROL | RegisterGroups::TEMPERATURE),
+            [(0x07, 4), (0x0E, 1), (0x11, 2)]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_accessors() {
+        let mut snapshot = Snapshot::new(RegisterGroups::TIME | RegisterGroups::STATUS);
+        snapshot.registers[..7].copy_from_slice(&[0x59, 0x59, 0x23, 0x01, 0x31, 0x12, 0x24]);
+        snapshot.registers[0x0F] = 0x83;
+
+        // 2024-12-31 23:59:59
+        assert_eq!(snapshot.timestamp().unwrap().unwrap(), 1_735_689_599);
+        assert_eq!(snapshot.hours(), Some(Hours(0x23)));
+        assert_eq!(snapshot.status(), Some(Status(0x83)));
+        assert_eq!(snapshot.control(), None);
+        assert_eq!(snapshot.alarm1(), None);
+        assert_eq!(snapshot.alarm2(), None);
+        assert_eq!(snapshot.aging_offset(), None);
+        assert_eq!(snapshot.temperature(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_snapshot_datetime() {
+        let mut snapshot = Snapshot::new(RegisterGroups::TIME);
+        snapshot.registers[..7].copy_from_slice(&[0x59, 0x59, 0x23, 0x01, 0x31, 0x12, 0x24]);
+        assert_eq!(
+            snapshot.datetime().unwrap().unwrap(),
+            chrono::NaiveDate::from_ymd_opt(2024, 12, 31)
+                .unwrap()
+                .and_hms_opt(23, 59, 59)
+                .unwrap()
+        );
+        assert!(Snapshot::new(RegisterGroups::STATUS).datetime().is_none());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_snapshot_primitive_datetime() {
+        let mut snapshot = Snapshot::new(RegisterGroups::TIME);
+        snapshot.registers[..7].copy_from_slice(&[0x59, 0x59, 0x23, 0x01, 0x31, 0x12, 0x24]);
+        assert_eq!(
+            snapshot.primitive_datetime().unwrap().unwrap(),
+            PrimitiveDateTime::new(
+                time::Date::from_calendar_date(2024, time::Month::December, 31).unwrap(),
+                time::Time::from_hms(23, 59, 59).unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_snapshot_invalid_datetime() {
+        let snapshot = Snapshot::new(RegisterGroups::TIME);
+        assert!(matches!(
+            snapshot.timestamp(),
+            Some(Err(DS3231DateTimeError::InvalidDateTime))
+        ));
+        #[cfg(feature = "chrono")]
+        assert!(matches!(
+            snapshot.datetime(),
+            Some(Err(DS3231DateTimeError::InvalidDateTime))
+        ));
+    }
+}
@@ -0,0 +1,145 @@
+//! Fixed-point temperature readings.
+//!
+//! The DS3231 reports the temperature as a 10-bit two's complement value with 0.25°C
+//! resolution, split over the temperature MSB (0x11) and LSB (0x12) registers. [`Celsius`]
+//! holds that value as quarter degrees, so it can be used, compared and printed on targets
+//! without a floating point unit.
+
+use core::fmt;
+
+use crate::{Temperature, TemperatureFraction};
+
+/// A temperature in degrees Celsius with 0.25°C resolution.
+///
+/// Stored as a number of quarter degrees, e.g. `Celsius::from_quarter_degrees(103)` is
+/// 25.75°C. Displays as `25.75°C`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Celsius(i16);
+
+impl Celsius {
+    /// Creates a temperature from a number of quarter degrees Celsius.
+    #[must_use]
+    pub const fn from_quarter_degrees(quarter_degrees: i16) -> Self {
+        Self(quarter_degrees)
+    }
+
+    /// Creates a temperature from the temperature MSB and LSB registers.
+    #[must_use]
+    pub fn from_registers(msb: Temperature, lsb: TemperatureFraction) -> Self {
+        Self((i16::from(msb.temperature()) << 2) | i16::from(lsb.temperature_fraction()))
+    }
+
+    /// Gets the temperature in quarter degrees Celsius.
+    #[must_use]
+    pub const fn quarter_degrees(self) -> i16 {
+        self.0
+    }
+
+    /// Gets the temperature in thousandths of a degree Celsius.
+    #[must_use]
+    pub fn millidegrees(self) -> i32 {
+        i32::from(self.0) * 250
+    }
+
+    /// Gets the temperature in thousandths of a degree Fahrenheit.
+    #[must_use]
+    pub fn fahrenheit_millidegrees(self) -> i32 {
+        // 0.25°C is exactly 0.45°F
+        i32::from(self.0) * 450 + 32_000
+    }
+
+    /// Gets the temperature in degrees Celsius as an `f32`.
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        f32::from(self.0) / 4.0
+    }
+
+    /// Gets the temperature in degrees Fahrenheit as an `f32`.
+    #[must_use]
+    pub fn to_fahrenheit_f32(self) -> f32 {
+        self.to_f32() * 1.8 + 32.0
+    }
+
+    /// Splits the temperature into sign, whole degrees and hundredths for display.
+    fn parts(self) -> (&'static str, u16, &'static str) {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let quarters = self.0.unsigned_abs();
+        let hundredths = match quarters % 4 {
+            0 => "00",
+            1 => "25",
+            2 => "50",
+            _ => "75",
+        };
+        (sign, quarters / 4, hundredths)
+    }
+}
+
+impl From<Celsius> for f32 {
+    fn from(celsius: Celsius) -> Self {
+        celsius.to_f32()
+    }
+}
+
+impl fmt::Display for Celsius {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (sign, degrees, hundredths) = self.parts();
+        write!(f, "{sign}{degrees}.{hundredths}°C")
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Celsius {
+    fn format(&self, f: defmt::Formatter) {
+        let (sign, degrees, hundredths) = self.parts();
+        defmt::write!(f, "{=str}{}.{=str}°C", sign, degrees, hundredths);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_from_registers() {
+        let celsius =
+            |msb, lsb| Celsius::from_registers(Temperature(msb), TemperatureFraction(lsb));
+
+        assert_eq!(celsius(0x19, 0x40).quarter_degrees(), 101); // 25.25°C
+        assert_eq!(celsius(0x00, 0x00).quarter_degrees(), 0);
+        assert_eq!(celsius(0xFF, 0xC0).quarter_degrees(), -1); // -0.25°C
+        assert_eq!(celsius(0xE7, 0x00).quarter_degrees(), -100); // -25.00°C
+        assert_eq!(celsius(0x7F, 0xC0).quarter_degrees(), 511); // 127.75°C
+        assert_eq!(celsius(0x80, 0x00).quarter_degrees(), -512); // -128.00°C
+    }
+
+    #[test]
+    fn test_conversions() {
+        let t = Celsius::from_quarter_degrees(103);
+        assert_eq!(t.millidegrees(), 25_750);
+        assert_eq!(t.fahrenheit_millidegrees(), 78_350);
+        assert!((t.to_f32() - 25.75).abs() < f32::EPSILON);
+        assert!((t.to_fahrenheit_f32() - 78.35).abs() < 0.001);
+        assert!((f32::from(t) - 25.75).abs() < f32::EPSILON);
+
+        let t = Celsius::from_quarter_degrees(-160);
+        assert_eq!(t.millidegrees(), -40_000);
+        assert_eq!(t.fahrenheit_millidegrees(), -40_000);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Celsius::from_quarter_degrees(-1) < Celsius::default());
+        assert!(Celsius::from_quarter_degrees(101) > Celsius::from_quarter_degrees(100));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Celsius::from_quarter_degrees(103).to_string(), "25.75°C");
+        assert_eq!(Celsius::from_quarter_degrees(0).to_string(), "0.00°C");
+        assert_eq!(Celsius::from_quarter_degrees(-1).to_string(), "-0.25°C");
+        assert_eq!(Celsius::from_quarter_degrees(-102).to_string(), "-25.50°C");
+    }
+}
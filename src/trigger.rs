@@ -0,0 +1,593 @@
+//! When alarm configurations fire.
+//!
+//! The DS3231 compares the unmasked alarm registers with the time registers once per second
+//! and sets the alarm flag on a match. [`Alarm1Config::matches`] and [`Alarm2Config::matches`]
+//! apply the same comparison to a `NaiveDateTime`, and `next_trigger`/`triggers` search
+//! forward for the times that match:
+//!
+//! ```rust,ignore
+//! let alarm = Alarm1Config::AtTimeOnDate { hours: 7, minutes: 0, seconds: 0, date: 31, is_pm: None };
+//! let next = alarm.next_trigger(&now); // skips months without a 31st
+//! for wake in alarm.triggers(&now).take(3) { /* ... */ }
+//! ```
+//!
+//! Days of the week are 1-7 with 1 = Sunday, as in the alarm configurations. Alarm 2 has no
+//! seconds register and fires at second 00 of the matching minute.
+
+use core::time::Duration;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, TimeDelta, Timelike};
+
+use crate::alarm::{Alarm1Config, Alarm2Config, AlarmMatch, FieldMatch};
+
+/// Longest gap in days between two matching days: a date such as the 31st can be two months
+/// away.
+const MAX_SEARCH_DAYS: u64 = 62;
+
+/// The register fields an alarm compares; `None` is a masked field.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Pattern {
+    second: Option<u8>,
+    minute: Option<u8>,
+    /// Hour in 24-hour form
+    hour: Option<u8>,
+    day: DayMatch,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum DayMatch {
+    Any,
+    /// Day of the month
+    Date(u8),
+    /// Day of the week (1 = Sunday)
+    Weekday(u8),
+}
+
+impl Pattern {
+    /// Builds the pattern of raw alarm registers, reading the Day register as 1 = Sunday.
+    ///
+    /// Returns `None` if the alarm never fires.
+    fn from_match(alarm: AlarmMatch) -> Option<Self> {
+        let value = |field: FieldMatch| match field {
+            FieldMatch::Any => Some(None),
+            FieldMatch::Value(value) => Some(Some(value)),
+            FieldMatch::Never => None,
+        };
+        let day = match (value(alarm.day)?, value(alarm.date)?) {
+            (Some(day), _) => DayMatch::Weekday(day),
+            (None, Some(date)) => DayMatch::Date(date),
+            (None, None) => DayMatch::Any,
+        };
+        Some(Pattern {
+            second: value(alarm.seconds)?,
+            minute: value(alarm.minutes)?,
+            hour: value(alarm.hours)?,
+            day,
+        })
+    }
+
+    fn matches(self, datetime: &NaiveDateTime) -> bool {
+        self.matches_day(datetime.date())
+            && field_matches(self.hour, datetime.hour())
+            && field_matches(self.minute, datetime.minute())
+            && field_matches(self.second, datetime.second())
+    }
+
+    /// Finds the first matching time after `after`, looking at most `MAX_SEARCH_DAYS` ahead.
+    fn next_after(self, after: &NaiveDateTime) -> Option<NaiveDateTime> {
+        let start = after
+            .with_nanosecond(0)?
+            .checked_add_signed(TimeDelta::seconds(1))?;
+        let mut from = (start.hour(), start.minute(), start.second());
+        let mut date = start.date();
+        for _ in 0..=MAX_SEARCH_DAYS {
+            if self.matches_day(date) {
+                if let Some((hour, minute, second)) = self.time_from(from) {
+                    return date.and_hms_opt(hour, minute, second);
+                }
+            }
+            date = date.succ_opt()?;
+            from = (0, 0, 0);
+        }
+        None
+    }
+
+    fn matches_day(self, date: NaiveDate) -> bool {
+        match self.day {
+            DayMatch::Any => true,
+            DayMatch::Date(day) => date.day() == u32::from(day),
+            DayMatch::Weekday(day) => date.weekday().number_from_sunday() == u32::from(day),
+        }
+    }
+
+    /// Finds the first matching time of day at or after `from`.
+    fn time_from(self, from: (u32, u32, u32)) -> Option<(u32, u32, u32)> {
+        for hour in (from.0..24).filter(|&h| field_matches(self.hour, h)) {
+            let first_minute = if hour == from.0 { from.1 } else { 0 };
+            for minute in (first_minute..60).filter(|&m| field_matches(self.minute, m)) {
+                let first_second = if (hour, minute) == (from.0, from.1) {
+                    from.2
+                } else {
+                    0
+                };
+                if let Some(second) = (first_second..60).find(|&s| field_matches(self.second, s)) {
+                    return Some((hour, minute, second));
+                }
+            }
+        }
+        None
+    }
+}
+
+fn field_matches(pattern: Option<u8>, value: u32) -> bool {
+    pattern.is_none_or(|p| u32::from(p) == value)
+}
+
+/// Converts an alarm hour to 24-hour form.
+fn to_24_hour(hours: u8, is_pm: Option<bool>) -> u8 {
+    match is_pm {
+        None => hours,
+        Some(pm) => hours % 12 + if pm { 12 } else { 0 },
+    }
+}
+
+/// Converts a 24-hour hour to alarm hours and PM flag, in 12-hour form if `twelve_hour`.
+pub(crate) fn from_24_hour(hours: u32, twelve_hour: bool) -> (u8, Option<bool>) {
+    let hours = field(hours);
+    if twelve_hour {
+        let hours_12 = match hours % 12 {
+            0 => 12,
+            h => h,
+        };
+        (hours_12, Some(hours >= 12))
+    } else {
+        (hours, None)
+    }
+}
+
+/// Narrows a time or date field, which chrono keeps in range, to `u8`.
+pub(crate) fn field(value: u32) -> u8 {
+    u8::try_from(value).unwrap_or(0)
+}
+
+/// Gets the earliest time a one-shot alarm can fire so that at least `duration` has passed
+/// since the clock read `now`, on a whole minute if `whole_minute`.
+///
+/// The clock can be up to a second past the time it reads, so the delay is rounded up to
+/// whole seconds and one second is added. Returns `None` outside chrono's range.
+pub(crate) fn one_shot_time(
+    now: &NaiveDateTime,
+    duration: Duration,
+    whole_minute: bool,
+) -> Option<NaiveDateTime> {
+    let at = now
+        .with_nanosecond(0)?
+        .checked_add_signed(TimeDelta::from_std(duration).ok()?)?;
+    let round_up = if at.nanosecond() > 0 { 2 } else { 1 };
+    let at = at
+        .with_nanosecond(0)?
+        .checked_add_signed(TimeDelta::seconds(round_up))?;
+    match at.second() {
+        second if whole_minute && second > 0 => {
+            at.checked_add_signed(TimeDelta::seconds(i64::from(60 - second)))
+        }
+        _ => Some(at),
+    }
+}
+
+/// Iterator over the times an alarm fires, created by [`Alarm1Config::triggers`] or
+/// [`Alarm2Config::triggers`].
+///
+/// The iterator ends if the configuration is invalid or chrono's date range is exhausted.
+#[derive(Clone, Debug)]
+pub struct AlarmTriggers {
+    pattern: Option<Pattern>,
+    after: NaiveDateTime,
+}
+
+impl Iterator for AlarmTriggers {
+    type Item = NaiveDateTime;
+
+    fn next(&mut self) -> Option<NaiveDateTime> {
+        let next = self.pattern?.next_after(&self.after);
+        match next {
+            Some(next) => self.after = next,
+            None => self.pattern = None,
+        }
+        next
+    }
+}
+
+impl Alarm1Config {
+    /// Returns `true` if the alarm fires at `datetime`, mirroring the hardware comparison.
+    ///
+    /// Returns `false` for an invalid configuration.
+    #[must_use]
+    pub fn matches(&self, datetime: &NaiveDateTime) -> bool {
+        self.pattern().is_some_and(|p| p.matches(datetime))
+    }
+
+    /// Gets the first time after `after` at which the alarm fires.
+    ///
+    /// Returns `None` for an invalid configuration.
+    #[must_use]
+    pub fn next_trigger(&self, after: &NaiveDateTime) -> Option<NaiveDateTime> {
+        self.pattern()?.next_after(after)
+    }
+
+    /// Gets an iterator over the times after `after` at which the alarm fires.
+    #[must_use]
+    pub fn triggers(&self, after: &NaiveDateTime) -> AlarmTriggers {
+        AlarmTriggers {
+            pattern: self.pattern(),
+            after: *after,
+        }
+    }
+
+    fn pattern(&self) -> Option<Pattern> {
+        self.validate().ok()?;
+        let (second, minute, hour, day) = match *self {
+            Alarm1Config::Raw(alarm) => return Pattern::from_match(alarm.alarm_match()),
+            Alarm1Config::EverySecond => (None, None, None, DayMatch::Any),
+            Alarm1Config::AtSeconds { seconds } => (Some(seconds), None, None, DayMatch::Any),
+            Alarm1Config::AtMinutesSeconds { minutes, seconds } => {
+                (Some(seconds), Some(minutes), None, DayMatch::Any)
+            }
+            Alarm1Config::AtTime {
+                hours,
+                minutes,
+                seconds,
+                is_pm,
+            } => (
+                Some(seconds),
+                Some(minutes),
+                Some(to_24_hour(hours, is_pm)),
+                DayMatch::Any,
+            ),
+            Alarm1Config::AtTimeOnDate {
+                hours,
+                minutes,
+                seconds,
+                date,
+                is_pm,
+            } => (
+                Some(seconds),
+                Some(minutes),
+                Some(to_24_hour(hours, is_pm)),
+                DayMatch::Date(date),
+            ),
+            Alarm1Config::AtTimeOnDay {
+                hours,
+                minutes,
+                seconds,
+                day,
+                is_pm,
+            } => (
+                Some(seconds),
+                Some(minutes),
+                Some(to_24_hour(hours, is_pm)),
+                DayMatch::Weekday(day),
+            ),
+        };
+        Some(Pattern {
+            second,
+            minute,
+            hour,
+            day,
+        })
+    }
+}
+
+impl Alarm2Config {
+    /// Returns `true` if the alarm fires at `datetime`, mirroring the hardware comparison.
+    ///
+    /// Alarm 2 only fires at second 00. Returns `false` for an invalid configuration.
+    #[must_use]
+    pub fn matches(&self, datetime: &NaiveDateTime) -> bool {
+        self.pattern().is_some_and(|p| p.matches(datetime))
+    }
+
+    /// Gets the first time after `after` at which the alarm fires.
+    ///
+    /// Returns `None` for an invalid configuration.
+    #[must_use]
+    pub fn next_trigger(&self, after: &NaiveDateTime) -> Option<NaiveDateTime> {
+        self.pattern()?.next_after(after)
+    }
+
+    /// Gets an iterator over the times after `after` at which the alarm fires.
+    #[must_use]
+    pub fn triggers(&self, after: &NaiveDateTime) -> AlarmTriggers {
+        AlarmTriggers {
+            pattern: self.pattern(),
+            after: *after,
+        }
+    }
+
+    fn pattern(&self) -> Option<Pattern> {
+        self.validate().ok()?;
+        let (minute, hour, day) = match *self {
+            Alarm2Config::Raw(alarm) => return Pattern::from_match(alarm.alarm_match()),
+            Alarm2Config::EveryMinute => (None, None, DayMatch::Any),
+            Alarm2Config::AtMinutes { minutes } => (Some(minutes), None, DayMatch::Any),
+            Alarm2Config::AtTime {
+                hours,
+                minutes,
+                is_pm,
+            } => (Some(minutes), Some(to_24_hour(hours, is_pm)), DayMatch::Any),
+            Alarm2Config::AtTimeOnDate {
+                hours,
+                minutes,
+                date,
+                is_pm,
+            } => (
+                Some(minutes),
+                Some(to_24_hour(hours, is_pm)),
+                DayMatch::Date(date),
+            ),
+            Alarm2Config::AtTimeOnDay {
+                hours,
+                minutes,
+                day,
+                is_pm,
+            } => (
+                Some(minutes),
+                Some(to_24_hour(hours, is_pm)),
+                DayMatch::Weekday(day),
+            ),
+        };
+        Some(Pattern {
+            second: Some(0),
+            minute,
+            hour,
+            day,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::{
+        AlarmDayDate, AlarmHours, AlarmMinutes, AlarmSeconds, DS3231Alarm1, DS3231Alarm2,
+        WeekdayNumbering,
+    };
+
+    fn datetime(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, mi, s)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_alarm1_next_trigger() {
+        let now = datetime(2024, 3, 14, 15, 30, 20);
+        let next = |alarm: Alarm1Config| alarm.next_trigger(&now).unwrap();
+
+        assert_eq!(
+            next(Alarm1Config::EverySecond),
+            datetime(2024, 3, 14, 15, 30, 21)
+        );
+        assert_eq!(
+            next(Alarm1Config::AtSeconds { seconds: 10 }),
+            datetime(2024, 3, 14, 15, 31, 10)
+        );
+        assert_eq!(
+            next(Alarm1Config::AtMinutesSeconds {
+                minutes: 30,
+                seconds: 20
+            }),
+            datetime(2024, 3, 14, 16, 30, 20)
+        );
+        assert_eq!(
+            next(Alarm1Config::AtTime {
+                hours: 23,
+                minutes: 59,
+                seconds: 59,
+                is_pm: None
+            }),
+            datetime(2024, 3, 14, 23, 59, 59)
+        );
+        assert_eq!(
+            next(Alarm1Config::AtTimeOnDay {
+                hours: 7,
+                minutes: 0,
+                seconds: 0,
+                day: 2, // Monday
+                is_pm: None
+            }),
+            datetime(2024, 3, 18, 7, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_next_trigger_skips_short_months() {
+        let alarm = Alarm1Config::AtTimeOnDate {
+            hours: 6,
+            minutes: 0,
+            seconds: 0,
+            date: 31,
+            is_pm: None,
+        };
+        let triggers: Vec<_> = alarm
+            .triggers(&datetime(2024, 1, 31, 6, 0, 0))
+            .take(3)
+            .collect();
+        assert_eq!(
+            triggers,
+            [
+                datetime(2024, 3, 31, 6, 0, 0),
+                datetime(2024, 5, 31, 6, 0, 0),
+                datetime(2024, 7, 31, 6, 0, 0),
+            ]
+        );
+
+        let leap_day = Alarm2Config::AtTimeOnDate {
+            hours: 12,
+            minutes: 0,
+            date: 29,
+            is_pm: None,
+        };
+        assert_eq!(
+            leap_day.next_trigger(&datetime(2023, 1, 30, 0, 0, 0)),
+            Some(datetime(2023, 3, 29, 12, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_twelve_hour_forms() {
+        let midnight = Alarm1Config::AtTime {
+            hours: 12,
+            minutes: 0,
+            seconds: 0,
+            is_pm: Some(false),
+        };
+        assert!(midnight.matches(&datetime(2024, 3, 14, 0, 0, 0)));
+        assert!(!midnight.matches(&datetime(2024, 3, 14, 12, 0, 0)));
+
+        let afternoon = Alarm2Config::AtTimeOnDay {
+            hours: 3,
+            minutes: 15,
+            day: 1, // Sunday
+            is_pm: Some(true),
+        };
+        assert_eq!(
+            afternoon.next_trigger(&datetime(2024, 3, 14, 0, 0, 0)),
+            Some(datetime(2024, 3, 17, 15, 15, 0))
+        );
+    }
+
+    #[test]
+    fn test_alarm2_fires_at_second_zero() {
+        let now = datetime(2024, 3, 14, 15, 30, 0);
+        assert_eq!(
+            Alarm2Config::EveryMinute.next_trigger(&now),
+            Some(datetime(2024, 3, 14, 15, 31, 0))
+        );
+        assert!(Alarm2Config::EveryMinute.matches(&now));
+        assert!(!Alarm2Config::EveryMinute.matches(&datetime(2024, 3, 14, 15, 30, 1)));
+        assert_eq!(
+            Alarm2Config::AtMinutes { minutes: 0 }.next_trigger(&now),
+            Some(datetime(2024, 3, 14, 16, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_next_trigger_is_strictly_after() {
+        let alarm = Alarm1Config::AtSeconds { seconds: 30 };
+        let at = datetime(2024, 3, 14, 15, 30, 30);
+        assert!(alarm.matches(&at));
+        assert_eq!(
+            alarm.next_trigger(&at),
+            Some(datetime(2024, 3, 14, 15, 31, 30))
+        );
+        let later = at.with_nanosecond(500_000_000).unwrap();
+        assert_eq!(
+            alarm.next_trigger(&later),
+            Some(datetime(2024, 3, 14, 15, 31, 30))
+        );
+    }
+
+    #[test]
+    fn test_triggers_match() {
+        let alarm = Alarm1Config::AtTimeOnDay {
+            hours: 11,
+            minutes: 45,
+            seconds: 5,
+            day: 7,
+            is_pm: Some(true),
+        };
+        for trigger in alarm.triggers(&datetime(2024, 12, 30, 0, 0, 0)).take(10) {
+            assert!(alarm.matches(&trigger));
+            assert_eq!(trigger.weekday(), chrono::Weekday::Sat);
+        }
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let now = datetime(2024, 3, 14, 15, 30, 0);
+        let alarm = Alarm1Config::AtSeconds { seconds: 60 };
+        assert!(!alarm.matches(&now));
+        assert_eq!(alarm.next_trigger(&now), None);
+        assert_eq!(alarm.triggers(&now).next(), None);
+        let alarm = Alarm2Config::AtTimeOnDay {
+            hours: 1,
+            minutes: 0,
+            day: 8,
+            is_pm: None,
+        };
+        assert_eq!(alarm.next_trigger(&now), None);
+    }
+
+    #[test]
+    fn test_raw_alarms() {
+        // Seconds 15 matched, minutes masked, hours 7: every minute from 07:00:15 to 07:59:15
+        let alarm = Alarm1Config::Raw(DS3231Alarm1::from_registers(
+            AlarmSeconds(0x15),
+            AlarmMinutes(0x80),
+            AlarmHours(0x07),
+            AlarmDayDate(0x80),
+        ));
+        let now = datetime(2024, 3, 14, 7, 30, 20);
+        assert_eq!(
+            alarm.next_trigger(&now),
+            Some(datetime(2024, 3, 14, 7, 31, 15))
+        );
+        assert_eq!(
+            alarm.next_trigger(&datetime(2024, 3, 14, 7, 59, 15)),
+            Some(datetime(2024, 3, 15, 7, 0, 15))
+        );
+
+        // Minutes hold an invalid BCD value, so the alarm never fires
+        let never = Alarm2Config::Raw(DS3231Alarm2::from_registers(
+            AlarmMinutes(0x5A),
+            AlarmHours(0x80),
+            AlarmDayDate(0x01),
+        ));
+        assert_eq!(never.next_trigger(&now), None);
+        assert!(!never.matches(&datetime(2024, 3, 14, 7, 30, 0)));
+    }
+
+    #[test]
+    fn test_raw_alarm_weekday_numbering() {
+        // Minutes masked, hours 7, Day 1: Monday when the driver uses 1 = Monday
+        let alarm =
+            DS3231Alarm2::from_registers(AlarmMinutes(0x80), AlarmHours(0x07), AlarmDayDate(0x41));
+        // Sunday 2024-03-10
+        let now = datetime(2024, 3, 10, 12, 0, 0);
+        let monday = alarm.decode(WeekdayNumbering::MondayIsOne);
+        assert!(matches!(monday, Alarm2Config::Raw(_)));
+        assert_eq!(
+            monday.next_trigger(&now),
+            Some(datetime(2024, 3, 11, 7, 0, 0))
+        );
+        assert!(!monday.matches(&datetime(2024, 3, 17, 7, 0, 0)));
+
+        let sunday = alarm.decode(WeekdayNumbering::SundayIsOne);
+        assert_eq!(
+            sunday.next_trigger(&now),
+            Some(datetime(2024, 3, 17, 7, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_one_shot_time() {
+        let now = datetime(2024, 1, 31, 23, 59, 30);
+        let after = |seconds, millis, whole_minute| {
+            let duration = Duration::from_secs(seconds) + Duration::from_millis(millis);
+            one_shot_time(&now, duration, whole_minute).unwrap()
+        };
+        assert_eq!(after(0, 0, false), datetime(2024, 1, 31, 23, 59, 31));
+        assert_eq!(after(90, 0, false), datetime(2024, 2, 1, 0, 1, 1));
+        // A fraction of a second rounds up
+        assert_eq!(after(90, 1, false), datetime(2024, 2, 1, 0, 1, 2));
+        assert_eq!(after(29, 0, true), datetime(2024, 2, 1, 0, 0, 0));
+        assert_eq!(after(29, 1, true), datetime(2024, 2, 1, 0, 1, 0));
+        assert_eq!(after(6 * 60 * 60, 0, true), datetime(2024, 2, 1, 6, 0, 0));
+        assert_eq!(one_shot_time(&now, Duration::MAX, false), None);
+    }
+}
@@ -0,0 +1,903 @@
+//! Time zones and daylight saving time on top of a clock kept in UTC.
+//!
+//! The DS3231 has no notion of time zones, and keeping it in UTC avoids having to touch it
+//! when daylight saving time starts or ends. A [`TimeZone`] is parsed from a POSIX `TZ`
+//! string and converts between UTC and local time without allocating:
+//!
+//! ```rust,ignore
+//! use ds3231::tz::TimeZone;
+//!
+//! let tz = TimeZone::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+//! rtc.set_local_datetime(&tz, &local)?; // stored as UTC
+//! let local = rtc.local_datetime(&tz)?;
+//! ```
+//!
+//! # POSIX TZ strings
+//!
+//! The format is `std offset [dst [offset] ,start[/time],end[/time]]`:
+//!
+//! - `std`/`dst` - Zone abbreviations of at least three letters, or `<...>` quoted (e.g.
+//!   `<+0530>`)
+//! - `offset` - `[+|-]hh[:mm[:ss]]` to add to local time to get UTC, so zones east of
+//!   Greenwich are negative (`CET-1`). The DST offset defaults to one hour ahead of standard
+//!   time.
+//! - `start`/`end` - When DST starts and ends: `Mm.w.d` (day `d` (0 = Sunday) of week `w`
+//!   (1-5, 5 = last) of month `m`), `Jn` (day 1-365, never counting February 29) or `n` (day
+//!   0-365, counting February 29)
+//! - `time` - Local time of the transition, `[+|-]hh[:mm[:ss]]` with hours up to 167
+//!   (default 02:00:00)
+//!
+//! # Alarms
+//!
+//! The alarm registers match against the UTC time in the device, so an alarm for 07:00 local
+//! time must be programmed for a UTC time that changes with DST. [`TimeZone::alarm1_to_utc`]
+//! and [`TimeZone::alarm2_to_utc`] convert a local alarm for its next occurrence; recompute
+//! it after the alarm fires (or at least after each DST transition).
+
+use core::fmt;
+use core::str::FromStr;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike};
+
+use crate::alarm::{Alarm1Config, Alarm2Config, AlarmError};
+use crate::datetime::DS3231DateTimeError;
+use crate::trigger::{field, from_24_hour};
+
+/// Longest supported zone abbreviation.
+const MAX_NAME_LEN: usize = 16;
+
+/// Default local time of a DST transition (02:00:00), in seconds.
+const DEFAULT_TRANSITION_TIME: i32 = 2 * 3600;
+
+/// Error parsing a POSIX TZ string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TzError {
+    /// A zone abbreviation is missing, too short, too long or has invalid characters
+    InvalidName,
+    /// A UTC offset is missing or out of range
+    InvalidOffset,
+    /// The DST rule is missing or malformed
+    InvalidRule,
+    /// Unexpected characters after the end of the TZ string
+    TrailingCharacters,
+}
+
+/// A zone abbreviation such as `CEST`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+struct Name {
+    bytes: [u8; MAX_NAME_LEN],
+    len: u8,
+}
+
+impl Name {
+    const UTC: Self = Self {
+        bytes: *b"UTC\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        len: 3,
+    };
+
+    fn as_str(&self) -> &str {
+        // Only ASCII is ever stored
+        core::str::from_utf8(&self.bytes[..usize::from(self.len)]).unwrap_or("")
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// The day of a DST transition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum RuleDate {
+    /// `Jn`: day 1-365, February 29 is never counted
+    Julian(u16),
+    /// `n`: day 0-365, February 29 is counted
+    Ordinal(u16),
+    /// `Mm.w.d`: weekday `d` (0 = Sunday) of week `w` (5 = last) of month `m`
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+impl RuleDate {
+    fn in_year(self, year: i32) -> Option<NaiveDate> {
+        match self {
+            RuleDate::Julian(day) => {
+                let leap = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+                let skip = u64::from(leap && day >= 60);
+                NaiveDate::from_ymd_opt(year, 1, 1)?
+                    .checked_add_days(chrono::Days::new(u64::from(day) - 1 + skip))
+            }
+            RuleDate::Ordinal(day) => NaiveDate::from_ymd_opt(year, 1, 1)?
+                .checked_add_days(chrono::Days::new(u64::from(day))),
+            RuleDate::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = NaiveDate::from_ymd_opt(year, u32::from(month), 1)?;
+                let first_weekday = first.weekday().num_days_from_sunday();
+                let mut day =
+                    1 + (u32::from(weekday) + 7 - first_weekday) % 7 + 7 * (u32::from(week) - 1);
+                // Week 5 means the last such weekday of the month
+                while NaiveDate::from_ymd_opt(year, u32::from(month), day).is_none() {
+                    day -= 7;
+                }
+                NaiveDate::from_ymd_opt(year, u32::from(month), day)
+            }
+        }
+    }
+}
+
+/// A DST transition: a day and the local time on it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Rule {
+    date: RuleDate,
+    /// Local time of the transition in seconds, may be negative or beyond one day
+    time: i32,
+}
+
+impl Rule {
+    /// Returns the UTC time of the transition in `year`, given the UTC offset in effect
+    /// before it.
+    fn utc_in_year(self, year: i32, offset_before: i32) -> Option<NaiveDateTime> {
+        let midnight = self.date.in_year(year)?.and_time(NaiveTime::MIN);
+        midnight.checked_add_signed(TimeDelta::seconds(i64::from(self.time - offset_before)))
+    }
+}
+
+/// Daylight saving time of a zone.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Dst {
+    name: Name,
+    /// Offset east of UTC, in seconds
+    offset: i32,
+    start: Rule,
+    end: Rule,
+}
+
+/// A time zone with optional daylight saving time, parsed from a POSIX TZ string.
+///
+/// See the [module documentation](self) for the format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TimeZone {
+    std_name: Name,
+    /// Standard offset east of UTC, in seconds
+    std_offset: i32,
+    dst: Option<Dst>,
+}
+
+impl TimeZone {
+    /// UTC, without daylight saving time.
+    pub const UTC: Self = Self {
+        std_name: Name::UTC,
+        std_offset: 0,
+        dst: None,
+    };
+
+    /// Parses a POSIX TZ string such as `CET-1CEST,M3.5.0,M10.5.0/3`.
+    ///
+    /// # Errors
+    /// Returns a `TzError` describing the first part of the string that is invalid.
+    pub fn parse(tz: &str) -> Result<Self, TzError> {
+        let mut parser = Parser {
+            bytes: tz.as_bytes(),
+            pos: 0,
+        };
+        let std_name = parser.name()?;
+        let std_offset = -parser.offset(24)?;
+        if parser.at_end() {
+            return Ok(Self {
+                std_name,
+                std_offset,
+                dst: None,
+            });
+        }
+
+        let dst_name = parser.name()?;
+        let dst_offset = if parser.at_end() || parser.peek() == Some(b',') {
+            std_offset + 3600
+        } else {
+            -parser.offset(24)?
+        };
+        if !parser.eat(b',') {
+            return Err(TzError::InvalidRule);
+        }
+        let start = parser.rule()?;
+        if !parser.eat(b',') {
+            return Err(TzError::InvalidRule);
+        }
+        let end = parser.rule()?;
+        if !parser.at_end() {
+            return Err(TzError::TrailingCharacters);
+        }
+
+        Ok(Self {
+            std_name,
+            std_offset,
+            dst: Some(Dst {
+                name: dst_name,
+                offset: dst_offset,
+                start,
+                end,
+            }),
+        })
+    }
+
+    /// Gets the standard time abbreviation, e.g. `CET`.
+    #[must_use]
+    pub fn std_name(&self) -> &str {
+        self.std_name.as_str()
+    }
+
+    /// Gets the daylight saving time abbreviation, e.g. `CEST`, if the zone has DST.
+    #[must_use]
+    pub fn dst_name(&self) -> Option<&str> {
+        self.dst.as_ref().map(|dst| dst.name.as_str())
+    }
+
+    /// Gets the standard time offset east of UTC, in seconds.
+    #[must_use]
+    pub fn std_offset(&self) -> i32 {
+        self.std_offset
+    }
+
+    /// Gets the daylight saving time offset east of UTC in seconds, if the zone has DST.
+    #[must_use]
+    pub fn dst_offset(&self) -> Option<i32> {
+        self.dst.map(|dst| dst.offset)
+    }
+
+    /// Returns `true` if daylight saving time is in effect at the UTC time `utc`.
+    #[must_use]
+    pub fn is_dst(&self, utc: &NaiveDateTime) -> bool {
+        let Some(dst) = self.dst else {
+            return false;
+        };
+        let local_year = (*utc + TimeDelta::seconds(i64::from(self.std_offset))).year();
+        let (Some(start), Some(end)) = (
+            dst.start.utc_in_year(local_year, self.std_offset),
+            dst.end.utc_in_year(local_year, dst.offset),
+        ) else {
+            return false;
+        };
+        if start < end {
+            start <= *utc && *utc < end
+        } else {
+            // Southern hemisphere: DST spans the new year
+            !(end <= *utc && *utc < start)
+        }
+    }
+
+    /// Gets the offset east of UTC in effect at the UTC time `utc`, in seconds.
+    #[must_use]
+    pub fn utc_offset(&self, utc: &NaiveDateTime) -> i32 {
+        match self.dst {
+            Some(dst) if self.is_dst(utc) => dst.offset,
+            _ => self.std_offset,
+        }
+    }
+
+    /// Gets the zone abbreviation in effect at the UTC time `utc`.
+    #[must_use]
+    pub fn name_at(&self, utc: &NaiveDateTime) -> &str {
+        match &self.dst {
+            Some(dst) if self.is_dst(utc) => dst.name.as_str(),
+            _ => self.std_name.as_str(),
+        }
+    }
+
+    /// Converts a UTC time to local time.
+    #[must_use]
+    pub fn to_local(&self, utc: &NaiveDateTime) -> NaiveDateTime {
+        *utc + TimeDelta::seconds(i64::from(self.utc_offset(utc)))
+    }
+
+    /// Converts a local time to UTC.
+    ///
+    /// A local time that occurs twice when DST ends resolves to the first occurrence (still
+    /// in DST). A local time skipped when DST starts is interpreted with the offset before
+    /// the transition, so 02:30 on the day clocks jump from 02:00 to 03:00 becomes 03:30.
+    #[must_use]
+    pub fn to_utc(&self, local: &NaiveDateTime) -> NaiveDateTime {
+        let Some(dst) = self.dst else {
+            return *local - TimeDelta::seconds(i64::from(self.std_offset));
+        };
+        let as_std = *local - TimeDelta::seconds(i64::from(self.std_offset));
+        let as_dst = *local - TimeDelta::seconds(i64::from(dst.offset));
+        let std_valid = !self.is_dst(&as_std);
+        let dst_valid = self.is_dst(&as_dst);
+        match (std_valid, dst_valid) {
+            (true, false) => as_std,
+            (false, true) => as_dst,
+            // Repeated local time: the earlier instant
+            (true, true) => as_std.min(as_dst),
+            // Skipped local time: the offset before the transition, i.e. the later instant
+            (false, false) => as_std.max(as_dst),
+        }
+    }
+
+    /// Converts a local alarm 1 configuration to the UTC configuration for its next
+    /// occurrence after `now`.
+    ///
+    /// Hour, minute and second fields (and the day or date, which can move when the offset
+    /// crosses midnight) are converted; `is_pm` is kept as 12-hour or 24-hour. The result is
+    /// only valid for the next occurrence when DST changes in between, so recompute it after
+    /// the alarm fires.
+    ///
+    /// # Arguments
+    /// * `local` - The alarm in local time (days of the week: 1 = Sunday)
+    /// * `now` - The current UTC time
+    ///
+    /// # Errors
+    /// Returns `AlarmError` if the local configuration is invalid or `Raw`.
+    pub fn alarm1_to_utc(
+        &self,
+        local: &Alarm1Config,
+        now: &NaiveDateTime,
+    ) -> Result<Alarm1Config, AlarmError> {
+        local.validate()?;
+        let utc = self.utc_trigger(local.next_trigger(&self.to_local(now)))?;
+        Ok(match *local {
+            Alarm1Config::Raw(_) => {
+                return Err(AlarmError::InvalidTime("raw alarms cannot be converted"));
+            }
+            Alarm1Config::EverySecond => Alarm1Config::EverySecond,
+            Alarm1Config::AtSeconds { .. } => Alarm1Config::AtSeconds {
+                seconds: field(utc.second()),
+            },
+            Alarm1Config::AtMinutesSeconds { .. } => Alarm1Config::AtMinutesSeconds {
+                minutes: field(utc.minute()),
+                seconds: field(utc.second()),
+            },
+            Alarm1Config::AtTime { is_pm, .. } => {
+                let (hours, is_pm) = from_24_hour(utc.hour(), is_pm.is_some());
+                Alarm1Config::AtTime {
+                    hours,
+                    minutes: field(utc.minute()),
+                    seconds: field(utc.second()),
+                    is_pm,
+                }
+            }
+            Alarm1Config::AtTimeOnDate { is_pm, .. } => {
+                let (hours, is_pm) = from_24_hour(utc.hour(), is_pm.is_some());
+                Alarm1Config::AtTimeOnDate {
+                    hours,
+                    minutes: field(utc.minute()),
+                    seconds: field(utc.second()),
+                    date: field(utc.day()),
+                    is_pm,
+                }
+            }
+            Alarm1Config::AtTimeOnDay { is_pm, .. } => {
+                let (hours, is_pm) = from_24_hour(utc.hour(), is_pm.is_some());
+                Alarm1Config::AtTimeOnDay {
+                    hours,
+                    minutes: field(utc.minute()),
+                    seconds: field(utc.second()),
+                    day: weekday(&utc),
+                    is_pm,
+                }
+            }
+        })
+    }
+
+    /// Converts a local alarm 2 configuration to the UTC configuration for its next
+    /// occurrence after `now`.
+    ///
+    /// See [`alarm1_to_utc`](Self::alarm1_to_utc).
+    ///
+    /// # Arguments
+    /// * `local` - The alarm in local time (days of the week: 1 = Sunday)
+    /// * `now` - The current UTC time
+    ///
+    /// # Errors
+    /// Returns `AlarmError` if the local configuration is invalid or `Raw`.
+    pub fn alarm2_to_utc(
+        &self,
+        local: &Alarm2Config,
+        now: &NaiveDateTime,
+    ) -> Result<Alarm2Config, AlarmError> {
+        local.validate()?;
+        let utc = self.utc_trigger(local.next_trigger(&self.to_local(now)))?;
+        Ok(match *local {
+            Alarm2Config::Raw(_) => {
+                return Err(AlarmError::InvalidTime("raw alarms cannot be converted"));
+            }
+            Alarm2Config::EveryMinute => Alarm2Config::EveryMinute,
+            Alarm2Config::AtMinutes { .. } => Alarm2Config::AtMinutes {
+                minutes: field(utc.minute()),
+            },
+            Alarm2Config::AtTime { is_pm, .. } => {
+                let (hours, is_pm) = from_24_hour(utc.hour(), is_pm.is_some());
+                Alarm2Config::AtTime {
+                    hours,
+                    minutes: field(utc.minute()),
+                    is_pm,
+                }
+            }
+            Alarm2Config::AtTimeOnDate { is_pm, .. } => {
+                let (hours, is_pm) = from_24_hour(utc.hour(), is_pm.is_some());
+                Alarm2Config::AtTimeOnDate {
+                    hours,
+                    minutes: field(utc.minute()),
+                    date: field(utc.day()),
+                    is_pm,
+                }
+            }
+            Alarm2Config::AtTimeOnDay { is_pm, .. } => {
+                let (hours, is_pm) = from_24_hour(utc.hour(), is_pm.is_some());
+                Alarm2Config::AtTimeOnDay {
+                    hours,
+                    minutes: field(utc.minute()),
+                    day: weekday(&utc),
+                    is_pm,
+                }
+            }
+        })
+    }
+
+    /// Converts the next local trigger time of an alarm to UTC.
+    fn utc_trigger(&self, local: Option<NaiveDateTime>) -> Result<NaiveDateTime, AlarmError> {
+        local
+            .map(|local| self.to_utc(&local))
+            .ok_or(AlarmError::DateTime(DS3231DateTimeError::InvalidDateTime))
+    }
+}
+
+impl FromStr for TimeZone {
+    type Err = TzError;
+
+    fn from_str(tz: &str) -> Result<Self, TzError> {
+        Self::parse(tz)
+    }
+}
+
+/// Gets the day of the week of `datetime` (1 = Sunday).
+fn weekday(datetime: &NaiveDateTime) -> u8 {
+    field(datetime.weekday().num_days_from_sunday() + 1)
+}
+
+/// Cursor over a TZ string.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn at_end(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn name(&mut self) -> Result<Name, TzError> {
+        let quoted = self.eat(b'<');
+        let start = self.pos;
+        while let Some(byte) = self.peek() {
+            let valid = if quoted {
+                byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'-'
+            } else {
+                byte.is_ascii_alphabetic()
+            };
+            if !valid {
+                break;
+            }
+            self.pos += 1;
+        }
+        let name = &self.bytes[start..self.pos];
+        if quoted && !self.eat(b'>') {
+            return Err(TzError::InvalidName);
+        }
+        if name.len() < 3 || name.len() > MAX_NAME_LEN {
+            return Err(TzError::InvalidName);
+        }
+        let mut bytes = [0; MAX_NAME_LEN];
+        bytes[..name.len()].copy_from_slice(name);
+        Ok(Name {
+            bytes,
+            len: u8::try_from(name.len()).map_err(|_| TzError::InvalidName)?,
+        })
+    }
+
+    fn number(&mut self, max_digits: usize) -> Option<u32> {
+        let start = self.pos;
+        let mut value = 0;
+        while self.pos - start < max_digits {
+            match self.peek() {
+                Some(byte @ b'0'..=b'9') => {
+                    value = value * 10 + u32::from(byte - b'0');
+                    self.pos += 1;
+                }
+                _ => break,
+            }
+        }
+        (self.pos > start).then_some(value)
+    }
+
+    /// Parses `[+|-]hh[:mm[:ss]]` into seconds, with the hours up to `max_hours`.
+    fn duration(&mut self, max_hours: u32) -> Option<i32> {
+        let negative = self.eat(b'-');
+        if !negative {
+            self.eat(b'+');
+        }
+        let hours = self.number(3).filter(|h| *h <= max_hours)?;
+        let mut seconds = hours * 3600;
+        if self.eat(b':') {
+            seconds += self.number(2).filter(|m| *m < 60)? * 60;
+            if self.eat(b':') {
+                seconds += self.number(2).filter(|s| *s < 60)?;
+            }
+        }
+        let seconds = i32::try_from(seconds).ok()?;
+        Some(if negative { -seconds } else { seconds })
+    }
+
+    /// Parses a UTC offset in POSIX form (seconds west of UTC).
+    fn offset(&mut self, max_hours: u32) -> Result<i32, TzError> {
+        self.duration(max_hours).ok_or(TzError::InvalidOffset)
+    }
+
+    fn rule(&mut self) -> Result<Rule, TzError> {
+        let date = if self.eat(b'J') {
+            let day = self.number(3).filter(|d| (1..=365).contains(d));
+            RuleDate::Julian(
+                day.and_then(|d| u16::try_from(d).ok())
+                    .ok_or(TzError::InvalidRule)?,
+            )
+        } else if self.eat(b'M') {
+            let month = self.number(2).filter(|m| (1..=12).contains(m));
+            let week = self.eat(b'.').then(|| self.number(1)).flatten();
+            let week = week.filter(|w| (1..=5).contains(w));
+            let weekday = self.eat(b'.').then(|| self.number(1)).flatten();
+            let weekday = weekday.filter(|d| *d <= 6);
+            match (month, week, weekday) {
+                (Some(month), Some(week), Some(weekday)) => RuleDate::MonthWeekDay {
+                    month: field(month),
+                    week: field(week),
+                    weekday: field(weekday),
+                },
+                _ => return Err(TzError::InvalidRule),
+            }
+        } else {
+            let day = self.number(3).filter(|d| *d <= 365);
+            RuleDate::Ordinal(
+                day.and_then(|d| u16::try_from(d).ok())
+                    .ok_or(TzError::InvalidRule)?,
+            )
+        };
+        let time = if self.eat(b'/') {
+            self.duration(167).ok_or(TzError::InvalidRule)?
+        } else {
+            DEFAULT_TRANSITION_TIME
+        };
+        Ok(Rule { date, time })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, mi, s)
+            .unwrap()
+    }
+
+    const CET: &str = "CET-1CEST,M3.5.0,M10.5.0/3";
+
+    #[test]
+    fn test_parse_fixed_offset() {
+        let tz = TimeZone::parse("UTC0").unwrap();
+        assert_eq!(tz.std_name(), "UTC");
+        assert_eq!(tz.std_offset(), 0);
+        assert_eq!(tz.dst_name(), None);
+
+        let tz: TimeZone = "<+0530>-5:30".parse().unwrap();
+        assert_eq!(tz.std_name(), "+0530");
+        assert_eq!(tz.std_offset(), 5 * 3600 + 30 * 60);
+    }
+
+    #[test]
+    fn test_parse_dst() {
+        let tz = TimeZone::parse(CET).unwrap();
+        assert_eq!(tz.std_name(), "CET");
+        assert_eq!(tz.std_offset(), 3600);
+        assert_eq!(tz.dst_name(), Some("CEST"));
+        assert_eq!(tz.dst_offset(), Some(7200));
+
+        let tz = TimeZone::parse("EST5EDT4,M3.2.0/2:00:00,M11.1.0/2:00:00").unwrap();
+        assert_eq!(tz.std_offset(), -5 * 3600);
+        assert_eq!(tz.dst_offset(), Some(-4 * 3600));
+
+        let tz = TimeZone::parse("<-03>3<-02>,J60/-1,300/167").unwrap();
+        assert_eq!(tz.dst_offset(), Some(-2 * 3600));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(TimeZone::parse(""), Err(TzError::InvalidName));
+        assert_eq!(TimeZone::parse("AB1"), Err(TzError::InvalidName));
+        assert_eq!(TimeZone::parse("<CET-1"), Err(TzError::InvalidName));
+        assert_eq!(TimeZone::parse("CET"), Err(TzError::InvalidOffset));
+        assert_eq!(TimeZone::parse("CET25"), Err(TzError::InvalidOffset));
+        assert_eq!(TimeZone::parse("CET-1CEST"), Err(TzError::InvalidRule));
+        assert_eq!(
+            TimeZone::parse("CET-1CEST,M3.5.0"),
+            Err(TzError::InvalidRule)
+        );
+        assert_eq!(
+            TimeZone::parse("CET-1CEST,M13.5.0,M10.5.0"),
+            Err(TzError::InvalidRule)
+        );
+        assert_eq!(
+            TimeZone::parse("CET-1CEST,M3.6.0,M10.5.0"),
+            Err(TzError::InvalidRule)
+        );
+        assert_eq!(
+            TimeZone::parse("CET-1CEST,J0,M10.5.0"),
+            Err(TzError::InvalidRule)
+        );
+        assert_eq!(
+            TimeZone::parse("CET-1CEST,M3.5.0,M10.5.0/3x"),
+            Err(TzError::TrailingCharacters)
+        );
+    }
+
+    #[test]
+    fn test_northern_transitions() {
+        let tz = TimeZone::parse(CET).unwrap();
+        // DST starts 2024-03-31 01:00 UTC and ends 2024-10-27 01:00 UTC
+        assert!(!tz.is_dst(&datetime(2024, 3, 31, 0, 59, 59)));
+        assert!(tz.is_dst(&datetime(2024, 3, 31, 1, 0, 0)));
+        assert!(tz.is_dst(&datetime(2024, 10, 27, 0, 59, 59)));
+        assert!(!tz.is_dst(&datetime(2024, 10, 27, 1, 0, 0)));
+        assert_eq!(tz.name_at(&datetime(2024, 7, 1, 0, 0, 0)), "CEST");
+        assert_eq!(tz.name_at(&datetime(2024, 1, 1, 0, 0, 0)), "CET");
+
+        assert_eq!(
+            tz.to_local(&datetime(2024, 7, 1, 12, 0, 0)),
+            datetime(2024, 7, 1, 14, 0, 0)
+        );
+        assert_eq!(
+            tz.to_local(&datetime(2024, 12, 31, 23, 30, 0)),
+            datetime(2025, 1, 1, 0, 30, 0)
+        );
+    }
+
+    #[test]
+    fn test_southern_transitions() {
+        // Australia/Sydney: DST from the first Sunday in October to the first Sunday in April
+        let tz = TimeZone::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        assert!(tz.is_dst(&datetime(2024, 1, 15, 0, 0, 0)));
+        assert!(!tz.is_dst(&datetime(2024, 7, 15, 0, 0, 0)));
+        // 2024-10-06 02:00 AEST is 2024-10-05 16:00 UTC
+        assert!(!tz.is_dst(&datetime(2024, 10, 5, 15, 59, 59)));
+        assert!(tz.is_dst(&datetime(2024, 10, 5, 16, 0, 0)));
+    }
+
+    #[test]
+    fn test_julian_rules() {
+        // J60 is March 1 in every year, 60 is March 1 only in leap years
+        let tz = TimeZone::parse("AAA0BBB,J60/0,J300/0").unwrap();
+        assert!(!tz.is_dst(&datetime(2024, 2, 29, 23, 59, 59)));
+        assert!(tz.is_dst(&datetime(2024, 3, 1, 0, 0, 0)));
+        let tz = TimeZone::parse("AAA0BBB,59/0,300/0").unwrap();
+        assert!(tz.is_dst(&datetime(2024, 2, 29, 0, 0, 0)));
+        assert!(!tz.is_dst(&datetime(2023, 2, 28, 23, 59, 59)));
+        assert!(tz.is_dst(&datetime(2023, 3, 1, 0, 0, 0)));
+    }
+
+    #[test]
+    fn test_to_utc() {
+        let tz = TimeZone::parse(CET).unwrap();
+        assert_eq!(
+            tz.to_utc(&datetime(2024, 7, 1, 14, 0, 0)),
+            datetime(2024, 7, 1, 12, 0, 0)
+        );
+        assert_eq!(
+            tz.to_utc(&datetime(2024, 1, 1, 14, 0, 0)),
+            datetime(2024, 1, 1, 13, 0, 0)
+        );
+        // Skipped: 02:30 does not exist on 2024-03-31, read as 03:30 CEST
+        assert_eq!(
+            tz.to_utc(&datetime(2024, 3, 31, 2, 30, 0)),
+            datetime(2024, 3, 31, 1, 30, 0)
+        );
+        // Repeated: 02:30 happens twice on 2024-10-27, the first time in CEST
+        assert_eq!(
+            tz.to_utc(&datetime(2024, 10, 27, 2, 30, 0)),
+            datetime(2024, 10, 27, 0, 30, 0)
+        );
+        assert_eq!(
+            TimeZone::UTC.to_utc(&datetime(2024, 10, 27, 2, 30, 0)),
+            datetime(2024, 10, 27, 2, 30, 0)
+        );
+    }
+
+    #[test]
+    fn test_alarm1_to_utc_follows_dst() {
+        let tz = TimeZone::parse(CET).unwrap();
+        let local = Alarm1Config::AtTime {
+            hours: 7,
+            minutes: 0,
+            seconds: 0,
+            is_pm: None,
+        };
+        let winter = tz
+            .alarm1_to_utc(&local, &datetime(2024, 3, 29, 12, 0, 0))
+            .unwrap();
+        assert_eq!(
+            winter,
+            Alarm1Config::AtTime {
+                hours: 6,
+                minutes: 0,
+                seconds: 0,
+                is_pm: None
+            }
+        );
+        // The next 07:00 after the night of the transition is in CEST
+        let summer = tz
+            .alarm1_to_utc(&local, &datetime(2024, 3, 30, 12, 0, 0))
+            .unwrap();
+        assert_eq!(
+            summer,
+            Alarm1Config::AtTime {
+                hours: 5,
+                minutes: 0,
+                seconds: 0,
+                is_pm: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_alarm1_to_utc_moves_day_and_date() {
+        let tz = TimeZone::parse(CET).unwrap();
+        let now = datetime(2024, 1, 10, 12, 0, 0); // a Wednesday
+                                                   // Monday 00:30 local is Sunday 23:30 UTC
+        let local = Alarm1Config::AtTimeOnDay {
+            hours: 12,
+            minutes: 30,
+            seconds: 15,
+            day: 2,
+            is_pm: Some(false),
+        };
+        assert_eq!(
+            tz.alarm1_to_utc(&local, &now).unwrap(),
+            Alarm1Config::AtTimeOnDay {
+                hours: 11,
+                minutes: 30,
+                seconds: 15,
+                day: 1,
+                is_pm: Some(true),
+            }
+        );
+        // The 1st at 00:30 local is the last day of the previous month in UTC
+        let local = Alarm1Config::AtTimeOnDate {
+            hours: 0,
+            minutes: 30,
+            seconds: 0,
+            date: 1,
+            is_pm: None,
+        };
+        assert_eq!(
+            tz.alarm1_to_utc(&local, &now).unwrap(),
+            Alarm1Config::AtTimeOnDate {
+                hours: 23,
+                minutes: 30,
+                seconds: 0,
+                date: 31,
+                is_pm: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_alarm_to_utc_sub_hour_offset() {
+        let tz = TimeZone::parse("IST-5:30").unwrap();
+        let now = datetime(2024, 1, 10, 12, 0, 0);
+        assert_eq!(
+            tz.alarm1_to_utc(
+                &Alarm1Config::AtMinutesSeconds {
+                    minutes: 15,
+                    seconds: 5
+                },
+                &now
+            )
+            .unwrap(),
+            Alarm1Config::AtMinutesSeconds {
+                minutes: 45,
+                seconds: 5
+            }
+        );
+        assert_eq!(
+            tz.alarm2_to_utc(&Alarm2Config::AtMinutes { minutes: 15 }, &now)
+                .unwrap(),
+            Alarm2Config::AtMinutes { minutes: 45 }
+        );
+        assert_eq!(
+            tz.alarm1_to_utc(&Alarm1Config::AtSeconds { seconds: 5 }, &now)
+                .unwrap(),
+            Alarm1Config::AtSeconds { seconds: 5 }
+        );
+        assert_eq!(
+            tz.alarm2_to_utc(&Alarm2Config::EveryMinute, &now).unwrap(),
+            Alarm2Config::EveryMinute
+        );
+    }
+
+    #[test]
+    fn test_alarm2_to_utc() {
+        let tz = TimeZone::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        let now = datetime(2024, 7, 4, 12, 0, 0);
+        assert_eq!(
+            tz.alarm2_to_utc(
+                &Alarm2Config::AtTime {
+                    hours: 9,
+                    minutes: 0,
+                    is_pm: Some(true)
+                },
+                &now
+            )
+            .unwrap(),
+            Alarm2Config::AtTime {
+                hours: 1,
+                minutes: 0,
+                is_pm: Some(false)
+            }
+        );
+        assert_eq!(
+            tz.alarm2_to_utc(
+                &Alarm2Config::AtTimeOnDate {
+                    hours: 20,
+                    minutes: 0,
+                    date: 31,
+                    is_pm: None
+                },
+                &now
+            )
+            .unwrap(),
+            Alarm2Config::AtTimeOnDate {
+                hours: 0,
+                minutes: 0,
+                date: 1,
+                is_pm: None
+            }
+        );
+        assert_eq!(
+            tz.alarm2_to_utc(
+                &Alarm2Config::AtTimeOnDay {
+                    hours: 21,
+                    minutes: 0,
+                    day: 7,
+                    is_pm: None
+                },
+                &now
+            )
+            .unwrap(),
+            Alarm2Config::AtTimeOnDay {
+                hours: 1,
+                minutes: 0,
+                day: 1,
+                is_pm: None
+            }
+        );
+        assert!(tz
+            .alarm2_to_utc(&Alarm2Config::AtMinutes { minutes: 60 }, &now)
+            .is_err());
+    }
+}